@@ -1,15 +1,46 @@
 pub mod search {
+    pub mod bfs;
     pub mod bfs_graph;
     pub mod bfs_grid;
+    pub mod budget;
+    pub mod dfs;
     pub mod dfs_graph;
     pub mod dfs_grid;
+    pub mod iterative_deepening_dfs;
     pub mod iterative_deepening_dfs_graph;
     pub mod iterative_deepening_dfs_grid;
+    pub mod observer;
+    pub mod outcome;
+    pub mod search_problem;
+    pub mod stats;
 }
 
 pub mod pathfinding {
+    pub mod a_star;
     pub mod a_star_graph;
     pub mod a_star_grid;
+    pub mod a_star_weighted_grid;
+    pub mod ara_star;
+    pub mod ara_star_graph;
+    pub mod ara_star_grid;
+    pub mod bidirectional_search_graph;
+    pub mod bidirectional_search_grid;
+    pub mod cost;
+    pub mod d_star_lite_grid;
+    pub mod djikstra_shortest_path_graph;
+    pub mod djikstra_shortest_path_grid;
+    pub mod flow_field_grid;
+    pub mod greedy_best_first_search;
+    pub mod greedy_best_first_search_graph;
+    pub mod greedy_best_first_search_grid;
+    pub mod grid_movement;
+    pub mod ida_star;
+    pub mod ida_star_graph;
+    pub mod ida_star_grid;
+    pub mod jump_point_search_grid;
+    pub mod uniform_cost_search;
     pub mod uniform_cost_search_graph;
     pub mod uniform_cost_search_grid;
-}
\ No newline at end of file
+}
+
+pub mod visualize;
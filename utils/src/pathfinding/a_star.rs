@@ -0,0 +1,336 @@
+use crate::pathfinding::cost::{is_better, is_worse, Cost};
+use crate::search::budget::BestSoFar;
+use crate::search::observer::{NoopObserver, SearchObserver};
+use crate::search::outcome::SearchOutcome;
+use crate::search::search_problem::{reconstruct_path, SearchProblem};
+use std::cmp::Ordering;
+use std::collections::binary_heap::PeekMut;
+use std::collections::{BinaryHeap, HashMap};
+
+/// How A* orders open-set entries with equal `f = g + h`.
+///
+/// Every policy falls back to insertion order, so the expansion order depends
+/// only on the problem and never on the heap's internals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TieBreak {
+    /// Deepest entry first, which heads straight for the goal on open maps.
+    /// Remaining ties go first-in, first-out.
+    #[default]
+    HigherG,
+    /// Entry with the lowest heuristic first, then first-in, first-out. With
+    /// equal `f` this matches `HigherG` unless the heuristic is weighted or
+    /// `g + h` saturates.
+    LowerH,
+    /// Oldest entry first.
+    Fifo,
+    /// Newest entry first.
+    Lifo,
+}
+
+/// Open-set priority shared by the A* implementations. Greater means expanded
+/// sooner.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Priority<C> {
+    f: C,
+    g: C,
+    h: C,
+    sequence: u64,
+    tie_break: TieBreak,
+}
+
+impl<C: Cost> Priority<C> {
+    /// Priority of the `sequence`th entry pushed, reached at cost `g`.
+    pub(crate) fn new(g: C, h: C, sequence: u64, tie_break: TieBreak) -> Self {
+        Priority {
+            f: g.saturating_add(h),
+            g,
+            h,
+            sequence,
+            tie_break,
+        }
+    }
+
+    pub(crate) fn f(&self) -> C {
+        self.f
+    }
+}
+
+impl<C: Cost> PartialEq for Priority<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<C: Cost> Eq for Priority<C> {}
+
+impl<C: Cost> Ord for Priority<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let older_first = other.sequence.cmp(&self.sequence);
+        other
+            .f
+            .total_cmp(&self.f)
+            .then_with(|| match self.tie_break {
+                TieBreak::HigherG => self.g.total_cmp(&other.g).then(older_first),
+                TieBreak::LowerH => other.h.total_cmp(&self.h).then(older_first),
+                TieBreak::Fifo => older_first,
+                TieBreak::Lifo => older_first.reverse(),
+            })
+    }
+}
+
+impl<C: Cost> PartialOrd for Priority<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// An open-set entry: a state, its g-score and where it stands in the queue.
+pub(crate) struct NodeCost<S, C> {
+    pub(crate) state: S,
+    pub(crate) cost: C,
+    pub(crate) priority: Priority<C>,
+}
+
+impl<S, C: Cost> PartialEq for NodeCost<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S, C: Cost> Eq for NodeCost<S, C> {}
+
+impl<S, C: Cost> Ord for NodeCost<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.priority.cmp(&other.priority)
+    }
+}
+
+impl<S, C: Cost> PartialOrd for NodeCost<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// A* search over any `SearchProblem`, guided by `SearchProblem::heuristic`.
+///
+/// Finds a path to a goal, or ends `Exhausted` if no goal is reachable.
+/// The path is optimal whenever the heuristic never overestimates.
+pub fn a_star<P: SearchProblem>(problem: &P) -> SearchOutcome<P::State, P::Cost> {
+    a_star_observed(problem, &mut NoopObserver)
+}
+
+pub fn a_star_observed<P, O>(problem: &P, observer: &mut O) -> SearchOutcome<P::State, P::Cost>
+where
+    P: SearchProblem,
+    O: SearchObserver<P::State>,
+{
+    AStarSearch::new(problem).run_observed(observer)
+}
+
+/// Where an `AStarSearch` stands after a call to `step`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchProgress {
+    /// The step used up its expansions; call `step` again to continue.
+    Running,
+    /// The observer asked the search to stop. Stepping again resumes it.
+    Stopped,
+    /// A goal was found or every reachable state was expanded.
+    Finished,
+}
+
+/// A* that runs a bounded number of expansions at a time, so many searches can
+/// share a frame's time slice.
+///
+/// The searcher owns its open set, g-scores and `came_from` map between calls to
+/// `step`. Stepping with the same `SearchTrace` each time records the same
+/// events as `a_star_observed`, ready for `visualize::write_gif`.
+pub struct AStarSearch<P: SearchProblem> {
+    problem: P,
+    open_set: BinaryHeap<NodeCost<P::State, P::Cost>>,
+    g_scores: HashMap<P::State, P::Cost>,
+    came_from: HashMap<P::State, P::State>,
+    visited: Vec<P::State>,
+    best: BestSoFar<P::State, P::Cost>,
+    tie_break: TieBreak,
+    weight: f64,
+    pushed: u64,
+    started: bool,
+    outcome: Option<SearchOutcome<P::State, P::Cost>>,
+}
+
+impl<P: SearchProblem> AStarSearch<P> {
+    pub fn new(problem: P) -> Self {
+        let start = problem.initial_state();
+        let estimate = problem.heuristic(&start);
+        AStarSearch {
+            best: BestSoFar::new(start, estimate),
+            problem,
+            open_set: BinaryHeap::new(),
+            g_scores: HashMap::new(),
+            came_from: HashMap::new(),
+            visited: Vec::new(),
+            tie_break: TieBreak::default(),
+            weight: 1.0,
+            pushed: 0,
+            started: false,
+            outcome: None,
+        }
+    }
+
+    /// Orders entries with equal `f` by `tie_break` instead of the default
+    /// `TieBreak::HigherG`.
+    pub fn with_tie_break(mut self, tie_break: TieBreak) -> Self {
+        self.tie_break = tie_break;
+        self
+    }
+
+    /// Weighted A*: inflates the heuristic by `weight`, so `f = g + weight * h`.
+    ///
+    /// Larger weights head for the goal more greedily and expand fewer states.
+    /// With a heuristic that never overestimates, the path costs at most
+    /// `weight` times the optimum. Weights below 1 count as 1.
+    pub fn with_weight(mut self, weight: f64) -> Self {
+        self.weight = weight.max(1.0);
+        self
+    }
+
+    /// Runs at most `max_expansions` expansions.
+    pub fn step(&mut self, max_expansions: usize) -> SearchProgress {
+        self.step_observed(max_expansions, &mut NoopObserver)
+    }
+
+    pub fn step_observed<O>(&mut self, max_expansions: usize, observer: &mut O) -> SearchProgress
+    where
+        O: SearchObserver<P::State>,
+    {
+        if self.outcome.is_some() {
+            return SearchProgress::Finished;
+        }
+
+        if !self.started {
+            self.started = true;
+            let start = self.problem.initial_state();
+            self.g_scores.insert(start.clone(), P::Cost::ZERO);
+            observer.on_push(&start);
+            let priority = self.priority(P::Cost::ZERO, self.problem.heuristic(&start));
+            self.open_set.push(NodeCost {
+                state: start,
+                cost: P::Cost::ZERO,
+                priority,
+            });
+        }
+
+        let mut expansions = 0;
+        while expansions < max_expansions {
+            let Some(top) = self.open_set.peek_mut() else {
+                // Goal not reachable
+                self.outcome = Some(SearchOutcome::Exhausted {
+                    visited: std::mem::take(&mut self.visited),
+                });
+                return SearchProgress::Finished;
+            };
+
+            // Skip stale entries left behind by a later improvement.
+            if is_worse(top.cost, self.g_scores.get(&top.state)) {
+                observer.on_discard(&PeekMut::pop(top).state);
+                continue;
+            }
+
+            // Leave the entry on the open set so stepping again resumes here.
+            if observer.should_stop() {
+                return SearchProgress::Stopped;
+            }
+
+            let NodeCost { state, cost, .. } = PeekMut::pop(top);
+            expansions += 1;
+            observer.on_expand(&state);
+            self.visited.push(state.clone());
+            self.best.offer(&state, self.problem.heuristic(&state));
+
+            if self.problem.is_goal(&state) {
+                let path = reconstruct_path(&self.came_from, state.clone());
+                observer.on_path_found(&path);
+                self.outcome = Some(SearchOutcome::Found {
+                    goal: state,
+                    path,
+                    cost,
+                    visited: std::mem::take(&mut self.visited),
+                });
+                return SearchProgress::Finished;
+            }
+
+            for (next, step_cost) in self.problem.successors(&state) {
+                let tentative_g_score = cost.saturating_add(step_cost);
+
+                if is_better(tentative_g_score, self.g_scores.get(&next)) {
+                    self.came_from.insert(next.clone(), state.clone());
+                    self.g_scores.insert(next.clone(), tentative_g_score);
+                    observer.on_push(&next);
+                    let priority = self.priority(tentative_g_score, self.problem.heuristic(&next));
+                    self.open_set.push(NodeCost {
+                        state: next,
+                        cost: tentative_g_score,
+                        priority,
+                    });
+                }
+            }
+
+            observer.on_close(&state);
+        }
+
+        SearchProgress::Running
+    }
+
+    /// Steps until the search finishes or the observer stops it.
+    pub fn run(self) -> SearchOutcome<P::State, P::Cost> {
+        self.run_observed(&mut NoopObserver)
+    }
+
+    pub fn run_observed<O>(mut self, observer: &mut O) -> SearchOutcome<P::State, P::Cost>
+    where
+        O: SearchObserver<P::State>,
+    {
+        self.step_observed(usize::MAX, observer);
+        self.into_outcome()
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.outcome.is_some()
+    }
+
+    /// The outcome, once the search has finished.
+    pub fn outcome(&self) -> Option<&SearchOutcome<P::State, P::Cost>> {
+        self.outcome.as_ref()
+    }
+
+    /// States expanded so far, in order.
+    pub fn visited(&self) -> &[P::State] {
+        match &self.outcome {
+            Some(outcome) => outcome.visited(),
+            None => &self.visited,
+        }
+    }
+
+    /// Entries waiting on the open set, stale ones included.
+    pub fn frontier_len(&self) -> usize {
+        self.open_set.len()
+    }
+
+    /// The outcome if the search has finished, otherwise `BudgetExhausted`
+    /// with the path to the most promising state expanded so far.
+    pub fn into_outcome(self) -> SearchOutcome<P::State, P::Cost> {
+        match self.outcome {
+            Some(outcome) => outcome,
+            None => SearchOutcome::BudgetExhausted {
+                best_path: reconstruct_path(&self.came_from, self.best.into_state()),
+                visited: self.visited,
+            },
+        }
+    }
+
+    /// Priority of the next entry pushed, which is numbered in push order.
+    fn priority(&mut self, g: P::Cost, h: P::Cost) -> Priority<P::Cost> {
+        self.pushed += 1;
+        Priority::new(g, h.scale(self.weight), self.pushed, self.tie_break)
+    }
+}
@@ -1,93 +1,123 @@
-use petgraph::graph::{Graph, NodeIndex};
-use petgraph::visit::{EdgeRef};
-use std::collections::{BinaryHeap, HashMap};
-use std::cmp::Ordering;
+use crate::pathfinding::a_star::AStarSearch;
+use crate::pathfinding::cost::Cost;
+use crate::search::observer::{NoopObserver, SearchObserver};
+use crate::search::outcome::{validate_node, ContainsNode, SearchError, SearchOutcome};
+use crate::search::search_problem::{GraphProblem, SearchProblem};
+use petgraph::visit::IntoEdges;
+use std::hash::Hash;
 
-#[derive(Debug, PartialEq, Eq)]
-struct NodeCost<T>
+/// A* over any petgraph graph or view that implements `IntoEdges`, such as
+/// `Graph`, `StableGraph`, `GraphMap`, `EdgeFiltered` or `Reversed`. Edge weights
+/// are the step costs.
+///
+/// Works with any `Cost`, including `f64` distances.
+pub fn astar_graph<G, F>(
+    graph: G,
+    start: G::NodeId,
+    goal: G::NodeId,
+    heuristic: F,
+) -> Result<SearchOutcome<G::NodeId, G::EdgeWeight>, SearchError>
 where
-    T: PartialEq + Eq + Clone + std::hash::Hash,
+    G: IntoEdges + ContainsNode,
+    G::NodeId: Eq + Hash,
+    G::EdgeWeight: Cost,
+    F: Fn(G::NodeId, G::NodeId) -> G::EdgeWeight,
 {
-    cost: usize,
-    index: NodeIndex,
-    estimated_cost: usize,
-    marker: std::marker::PhantomData<T>,
+    astar_graph_observed(graph, start, goal, heuristic, &mut NoopObserver)
 }
 
-impl<T> Ord for NodeCost<T>
+pub fn astar_graph_observed<G, F, O>(
+    graph: G,
+    start: G::NodeId,
+    goal: G::NodeId,
+    heuristic: F,
+    observer: &mut O,
+) -> Result<SearchOutcome<G::NodeId, G::EdgeWeight>, SearchError>
 where
-    T: PartialEq + Eq + Clone + std::hash::Hash,
+    G: IntoEdges + ContainsNode,
+    G::NodeId: Eq + Hash,
+    G::EdgeWeight: Cost,
+    F: Fn(G::NodeId, G::NodeId) -> G::EdgeWeight,
+    O: SearchObserver<G::NodeId>,
 {
-    fn cmp(&self, other: &Self) -> Ordering {
-        other
-            .estimated_cost
-            .cmp(&self.estimated_cost)
-            .then_with(|| self.cost.cmp(&other.cost))
-    }
+    Ok(astar_graph_search(graph, start, goal, heuristic)?.run_observed(observer))
 }
 
-impl<T> PartialOrd for NodeCost<T>
+/// A* from `start` to whichever of `goals` is cheapest to reach, taking the
+/// same heuristic closures as `astar_graph`. `SearchOutcome::goal` tells which
+/// one was reached.
+///
+/// Each node is estimated by its nearest goal under `heuristic`, so the path
+/// stays optimal whenever `heuristic` never overestimates. Without goals the
+/// search ends `Exhausted`.
+pub fn astar_graph_to_any<G, F>(
+    graph: G,
+    start: G::NodeId,
+    goals: &[G::NodeId],
+    heuristic: F,
+) -> Result<SearchOutcome<G::NodeId, G::EdgeWeight>, SearchError>
 where
-    T: PartialEq + Eq + Clone + std::hash::Hash,
+    G: IntoEdges + ContainsNode,
+    G::NodeId: Eq + Hash,
+    G::EdgeWeight: Cost,
+    F: Fn(G::NodeId, G::NodeId) -> G::EdgeWeight,
 {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
+    astar_graph_to_any_observed(graph, start, goals, heuristic, &mut NoopObserver)
 }
 
-pub fn astar_graph<T, F>(
-    graph: &Graph<T, usize>,
-    start: NodeIndex,
-    goal: NodeIndex,
+pub fn astar_graph_to_any_observed<G, F, O>(
+    graph: G,
+    start: G::NodeId,
+    goals: &[G::NodeId],
     heuristic: F,
-) -> Vec<NodeIndex>
+    observer: &mut O,
+) -> Result<SearchOutcome<G::NodeId, G::EdgeWeight>, SearchError>
 where
-    T: PartialEq + Eq + Clone + std::hash::Hash,
-    F: Fn(NodeIndex, NodeIndex) -> usize,
+    G: IntoEdges + ContainsNode,
+    G::NodeId: Eq + Hash,
+    G::EdgeWeight: Cost,
+    F: Fn(G::NodeId, G::NodeId) -> G::EdgeWeight,
+    O: SearchObserver<G::NodeId>,
 {
-    let mut open_set: BinaryHeap<NodeCost<T>> = BinaryHeap::new();
-    let mut g_scores = HashMap::new();
-    let mut came_from = HashMap::new();
-
-    g_scores.insert(start, 0);
-    open_set.push(NodeCost {
-        cost: 0,
-        index: start,
-        estimated_cost: heuristic(start, goal),
-        marker: std::marker::PhantomData,
-    });
+    validate_node(graph, start)?;
 
-    while let Some(NodeCost { index, cost, .. }) = open_set.pop() {
-        if index == goal {
-            let mut path = Vec::new();
-            let mut current = Some(index);
-            while let Some(node) = current {
-                path.push(node);
-                current = came_from.get(&node).copied();
-            }
-            path.reverse();
-            return path;
-        }
+    let problem = GraphProblem {
+        graph,
+        start,
+        edge_cost: |weight: &G::EdgeWeight| *weight,
+        is_goal: |node: G::NodeId| goals.contains(&node),
+        heuristic: |node: G::NodeId| {
+            goals
+                .iter()
+                .map(|&goal| heuristic(node, goal))
+                .min_by(Cost::total_cmp)
+                .unwrap_or(G::EdgeWeight::ZERO)
+        },
+    };
 
-        if cost > *g_scores.get(&index).unwrap_or(&usize::MAX) {
-            continue;
-        }
+    Ok(AStarSearch::new(problem).run_observed(observer))
+}
 
-        for edge in graph.edges(index) {
-            let neighbor = edge.target();
-            let tentative_g_score = cost + edge.weight();
-            if tentative_g_score < *g_scores.get(&neighbor).unwrap_or(&usize::MAX) {
-                came_from.insert(neighbor, index);
-                g_scores.insert(neighbor, tentative_g_score);
-                open_set.push(NodeCost {
-                    cost: tentative_g_score,
-                    index: neighbor,
-                    estimated_cost: tentative_g_score + heuristic(neighbor, goal),
-                    marker: std::marker::PhantomData,
-                });
-            }
-        }
-    }
+/// A step-wise `astar_graph`; see `AStarSearch`.
+pub fn astar_graph_search<G, F>(
+    graph: G,
+    start: G::NodeId,
+    goal: G::NodeId,
+    heuristic: F,
+) -> Result<AStarSearch<impl SearchProblem<State = G::NodeId, Cost = G::EdgeWeight>>, SearchError>
+where
+    G: IntoEdges + ContainsNode,
+    G::NodeId: Eq + Hash,
+    G::EdgeWeight: Cost,
+    F: Fn(G::NodeId, G::NodeId) -> G::EdgeWeight,
+{
+    validate_node(graph, start)?;
 
-    Vec::new() // Return an empty path if no solution exists.
+    Ok(AStarSearch::new(GraphProblem {
+        graph,
+        start,
+        edge_cost: |weight: &G::EdgeWeight| *weight,
+        is_goal: move |node: G::NodeId| node == goal,
+        heuristic: move |node: G::NodeId| heuristic(node, goal),
+    }))
 }
@@ -1,88 +1,493 @@
+use crate::pathfinding::a_star::{AStarSearch, Priority, TieBreak};
+use crate::pathfinding::cost::Cost;
+use crate::pathfinding::grid_movement::{DistanceHeuristic, GridHeuristic, Movement};
+use crate::search::budget::BestSoFar;
+use crate::search::observer::{NoopObserver, SearchObserver};
+use crate::search::outcome::{validate_grid, SearchError, SearchOutcome};
+use crate::search::search_problem::{GridProblem, SearchProblem};
 use std::cmp::Ordering;
 use std::collections::BinaryHeap;
-use std::collections::HashMap;
 
-#[derive(Copy, Clone, Eq, PartialEq)]
-struct Node {
-    position: (usize, usize),
-    cost: usize, // The cost to reach this node (g(n))
+pub fn a_star_grid(
+    grid: &[Vec<bool>],
+    start: (usize, usize),
+    goal: (usize, usize),
+) -> Result<SearchOutcome<(usize, usize)>, SearchError> {
+    a_star_grid_observed(grid, start, goal, &mut NoopObserver)
 }
 
-impl Ord for Node {
-    fn cmp(&self, other: &Self) -> Ordering {
-        // Reverse ordering to make BinaryHeap a min-heap
-        other.cost.cmp(&self.cost)
-    }
+pub fn a_star_grid_observed<O>(
+    grid: &[Vec<bool>],
+    start: (usize, usize),
+    goal: (usize, usize),
+    observer: &mut O,
+) -> Result<SearchOutcome<(usize, usize)>, SearchError>
+where
+    O: SearchObserver<(usize, usize)>,
+{
+    a_star_grid_with_observed(
+        grid,
+        start,
+        goal,
+        Movement::four_way(),
+        DistanceHeuristic::Manhattan,
+        observer,
+    )
 }
 
-impl PartialOrd for Node {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
+/// A* over an obstacle grid (`true` is a wall) with a configurable `movement`
+/// model and `heuristic`.
+///
+/// Pass a `DistanceHeuristic` to have it scaled by the movement's step costs, or
+/// any `Fn((usize, usize), (usize, usize)) -> usize` closure. The path is
+/// optimal whenever the heuristic never overestimates under `movement`.
+pub fn a_star_grid_with<H>(
+    grid: &[Vec<bool>],
+    start: (usize, usize),
+    goal: (usize, usize),
+    movement: Movement,
+    heuristic: H,
+) -> Result<SearchOutcome<(usize, usize)>, SearchError>
+where
+    H: GridHeuristic,
+{
+    a_star_grid_with_observed(grid, start, goal, movement, heuristic, &mut NoopObserver)
 }
 
-/// Heuristic function: Manhattan distance
-fn heuristic(a: (usize, usize), b: (usize, usize)) -> usize {
-    let (x1, y1) = a;
-    let (x2, y2) = b;
-    (x1 as isize - x2 as isize).abs() as usize + (y1 as isize - y2 as isize).abs() as usize
+pub fn a_star_grid_with_observed<H, O>(
+    grid: &[Vec<bool>],
+    start: (usize, usize),
+    goal: (usize, usize),
+    movement: Movement,
+    heuristic: H,
+    observer: &mut O,
+) -> Result<SearchOutcome<(usize, usize)>, SearchError>
+where
+    H: GridHeuristic,
+    O: SearchObserver<(usize, usize)>,
+{
+    SearchContext::new().a_star_grid_with_observed(grid, start, goal, movement, heuristic, observer)
 }
 
-pub fn a_star_grid(
+/// A* from `start` to whichever of `goals` is cheapest to reach, with the same
+/// moves as `a_star_grid`. `SearchOutcome::goal` tells which one was reached.
+///
+/// The heuristic is the smallest Manhattan distance to any goal, so the path
+/// stays optimal. Without goals the search ends `Exhausted`.
+pub fn a_star_grid_to_any(
+    grid: &[Vec<bool>],
+    start: (usize, usize),
+    goals: &[(usize, usize)],
+) -> Result<SearchOutcome<(usize, usize)>, SearchError> {
+    a_star_grid_to_any_observed(grid, start, goals, &mut NoopObserver)
+}
+
+pub fn a_star_grid_to_any_observed<O>(
+    grid: &[Vec<bool>],
+    start: (usize, usize),
+    goals: &[(usize, usize)],
+    observer: &mut O,
+) -> Result<SearchOutcome<(usize, usize)>, SearchError>
+where
+    O: SearchObserver<(usize, usize)>,
+{
+    a_star_grid_to_any_with_observed(
+        grid,
+        start,
+        goals,
+        Movement::four_way(),
+        DistanceHeuristic::Manhattan,
+        observer,
+    )
+}
+
+/// `a_star_grid_to_any` with a configurable `movement` model and `heuristic`,
+/// like `a_star_grid_with`. Each cell is estimated by its nearest goal under
+/// `heuristic`.
+pub fn a_star_grid_to_any_with<H>(
+    grid: &[Vec<bool>],
+    start: (usize, usize),
+    goals: &[(usize, usize)],
+    movement: Movement,
+    heuristic: H,
+) -> Result<SearchOutcome<(usize, usize)>, SearchError>
+where
+    H: GridHeuristic,
+{
+    a_star_grid_to_any_with_observed(grid, start, goals, movement, heuristic, &mut NoopObserver)
+}
+
+pub fn a_star_grid_to_any_with_observed<H, O>(
+    grid: &[Vec<bool>],
+    start: (usize, usize),
+    goals: &[(usize, usize)],
+    movement: Movement,
+    heuristic: H,
+    observer: &mut O,
+) -> Result<SearchOutcome<(usize, usize)>, SearchError>
+where
+    H: GridHeuristic,
+    O: SearchObserver<(usize, usize)>,
+{
+    validate_grid(grid, start)?;
+
+    let problem = GridProblem {
+        grid,
+        start,
+        movement,
+        step_cost: |&wall: &bool| (!wall).then_some(1),
+        is_goal: |position: (usize, usize)| goals.contains(&position),
+        heuristic: |position: (usize, usize)| {
+            goals
+                .iter()
+                .map(|&goal| heuristic.estimate(position, goal, &movement))
+                .min()
+                .unwrap_or(0)
+        },
+    };
+
+    Ok(SearchContext::new().run(&problem, observer))
+}
+
+/// A step-wise `a_star_grid`; see `AStarSearch`.
+pub fn a_star_grid_search(
     grid: &[Vec<bool>],
     start: (usize, usize),
     goal: (usize, usize),
-) -> Vec<(usize, usize)> {
-    let mut open_set = BinaryHeap::new();
-    let mut came_from: HashMap<(usize, usize), (usize, usize)> = HashMap::new();
-    let mut g_score: HashMap<(usize, usize), usize> = HashMap::new();
-
-    open_set.push(Node {
-        position: start,
-        cost: heuristic(start, goal),
-    });
-    g_score.insert(start, 0);
-
-    while let Some(Node { position, .. }) = open_set.pop() {
-        if position == goal {
-            // Reconstruct the path
-            let mut current = position;
-            let mut path = vec![current];
-            while let Some(&prev) = came_from.get(&current) {
-                current = prev;
-                path.push(current);
-            }
-            path.reverse();
-            return path;
+) -> Result<AStarSearch<impl SearchProblem<State = (usize, usize), Cost = usize> + '_>, SearchError>
+{
+    a_star_grid_with_search(
+        grid,
+        start,
+        goal,
+        Movement::four_way(),
+        DistanceHeuristic::Manhattan,
+    )
+}
+
+/// A step-wise `a_star_grid_with`; see `AStarSearch`.
+pub fn a_star_grid_with_search<'a, H>(
+    grid: &'a [Vec<bool>],
+    start: (usize, usize),
+    goal: (usize, usize),
+    movement: Movement,
+    heuristic: H,
+) -> Result<AStarSearch<impl SearchProblem<State = (usize, usize), Cost = usize> + 'a>, SearchError>
+where
+    H: GridHeuristic + 'a,
+{
+    validate_grid(grid, start)?;
+
+    Ok(AStarSearch::new(GridProblem {
+        grid,
+        start,
+        movement,
+        step_cost: |&wall: &bool| (!wall).then_some(1),
+        is_goal: move |position: (usize, usize)| position == goal,
+        heuristic: move |position: (usize, usize)| heuristic.estimate(position, goal, &movement),
+    }))
+}
+
+/// Reusable scratch space for grid A*, for running many queries on large maps.
+///
+/// Scores live in flat arrays indexed by `row * cols + col` that only grow.
+/// Instead of clearing them, every query starts a new generation and ignores
+/// entries stamped with an older one. Results are identical to `a_star_grid`
+/// and `a_star_grid_with`, which run on a fresh context.
+#[derive(Debug)]
+pub struct SearchContext {
+    scores: Scores,
+    open_set: BinaryHeap<OpenCell>,
+    moves: Vec<((usize, usize), usize)>,
+    tie_break: TieBreak,
+    weight: f64,
+}
+
+impl Default for SearchContext {
+    fn default() -> Self {
+        SearchContext {
+            scores: Scores::default(),
+            open_set: BinaryHeap::new(),
+            moves: Vec::new(),
+            tie_break: TieBreak::default(),
+            weight: 1.0,
         }
+    }
+}
+
+impl SearchContext {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Orders entries with equal `f` by `tie_break`, as
+    /// `AStarSearch::with_tie_break` does.
+    pub fn with_tie_break(mut self, tie_break: TieBreak) -> Self {
+        self.tie_break = tie_break;
+        self
+    }
+
+    /// Inflates the heuristic by `weight`, as `AStarSearch::with_weight` does.
+    pub fn with_weight(mut self, weight: f64) -> Self {
+        self.weight = weight.max(1.0);
+        self
+    }
 
-        let (row, col) = position;
-
-        // Neighbors: Up, Down, Left, Right
-        let neighbors = [
-            (row.wrapping_sub(1), col),
-            (row + 1, col),
-            (row, col.wrapping_sub(1)),
-            (row, col + 1),
-        ];
-
-        for &(next_row, next_col) in &neighbors {
-            if next_row < grid.len() && next_col < grid[0].len() && !grid[next_row][next_col] {
-                let tentative_g_score = g_score.get(&position).unwrap_or(&usize::MAX) + 1;
-
-                if tentative_g_score < *g_score.get(&(next_row, next_col)).unwrap_or(&usize::MAX) {
-                    g_score.insert((next_row, next_col), tentative_g_score);
-                    came_from.insert((next_row, next_col), position);
-                    let f_score = tentative_g_score + heuristic((next_row, next_col), goal);
-                    open_set.push(Node {
-                        position: (next_row, next_col),
-                        cost: f_score,
+    /// `a_star_grid` using this context's arrays.
+    pub fn a_star_grid(
+        &mut self,
+        grid: &[Vec<bool>],
+        start: (usize, usize),
+        goal: (usize, usize),
+    ) -> Result<SearchOutcome<(usize, usize)>, SearchError> {
+        self.a_star_grid_observed(grid, start, goal, &mut NoopObserver)
+    }
+
+    /// `a_star_grid_observed` using this context's arrays.
+    pub fn a_star_grid_observed<O>(
+        &mut self,
+        grid: &[Vec<bool>],
+        start: (usize, usize),
+        goal: (usize, usize),
+        observer: &mut O,
+    ) -> Result<SearchOutcome<(usize, usize)>, SearchError>
+    where
+        O: SearchObserver<(usize, usize)>,
+    {
+        self.a_star_grid_with_observed(
+            grid,
+            start,
+            goal,
+            Movement::four_way(),
+            DistanceHeuristic::Manhattan,
+            observer,
+        )
+    }
+
+    /// `a_star_grid_with` using this context's arrays.
+    pub fn a_star_grid_with<H>(
+        &mut self,
+        grid: &[Vec<bool>],
+        start: (usize, usize),
+        goal: (usize, usize),
+        movement: Movement,
+        heuristic: H,
+    ) -> Result<SearchOutcome<(usize, usize)>, SearchError>
+    where
+        H: GridHeuristic,
+    {
+        self.a_star_grid_with_observed(grid, start, goal, movement, heuristic, &mut NoopObserver)
+    }
+
+    /// `a_star_grid_with_observed` using this context's arrays.
+    pub fn a_star_grid_with_observed<H, O>(
+        &mut self,
+        grid: &[Vec<bool>],
+        start: (usize, usize),
+        goal: (usize, usize),
+        movement: Movement,
+        heuristic: H,
+        observer: &mut O,
+    ) -> Result<SearchOutcome<(usize, usize)>, SearchError>
+    where
+        H: GridHeuristic,
+        O: SearchObserver<(usize, usize)>,
+    {
+        validate_grid(grid, start)?;
+
+        let problem = GridProblem {
+            grid,
+            start,
+            movement,
+            step_cost: |&wall: &bool| (!wall).then_some(1),
+            is_goal: |position: (usize, usize)| position == goal,
+            heuristic: |position: (usize, usize)| heuristic.estimate(position, goal, &movement),
+        };
+
+        Ok(self.run(&problem, observer))
+    }
+
+    /// The A* loop of `a_star_observed`, with the hash maps replaced by the
+    /// context's arrays.
+    fn run<T, C, G, H, O>(
+        &mut self,
+        problem: &GridProblem<'_, T, C, G, H>,
+        observer: &mut O,
+    ) -> SearchOutcome<(usize, usize)>
+    where
+        C: Fn(&T) -> Option<usize>,
+        G: Fn((usize, usize)) -> bool,
+        H: Fn((usize, usize)) -> usize,
+        O: SearchObserver<(usize, usize)>,
+    {
+        let SearchContext {
+            scores,
+            open_set,
+            moves,
+            tie_break,
+            weight,
+        } = self;
+        let cols = problem.grid.iter().map(Vec::len).max().unwrap_or(0);
+        let index = |(row, col): (usize, usize)| row * cols + col;
+
+        scores.begin(problem.grid.len() * cols);
+        open_set.clear();
+
+        let mut visited = Vec::new();
+        let start = problem.start;
+        let mut best = BestSoFar::new(start, (problem.heuristic)(start));
+        let mut pushed = 0;
+        let mut priority = |g: usize, h: usize| {
+            pushed += 1;
+            Priority::new(g, h.scale(*weight), pushed, *tie_break)
+        };
+        scores.set(index(start), 0, index(start));
+        observer.on_push(&start);
+        open_set.push(OpenCell {
+            cost: 0,
+            index: index(start),
+            priority: priority(0, (problem.heuristic)(start)),
+        });
+
+        while let Some(OpenCell {
+            cost,
+            index: current,
+            ..
+        }) = open_set.pop()
+        {
+            let position = (current / cols, current % cols);
+
+            if scores.get(current).is_some_and(|best| cost > best) {
+                observer.on_discard(&position);
+                continue;
+            }
+
+            if observer.should_stop() {
+                return SearchOutcome::BudgetExhausted {
+                    best_path: scores.path_to(index(best.into_state()), cols),
+                    visited,
+                };
+            }
+
+            observer.on_expand(&position);
+            visited.push(position);
+            best.offer(&position, (problem.heuristic)(position));
+
+            if (problem.is_goal)(position) {
+                let path = scores.path_to(current, cols);
+                observer.on_path_found(&path);
+                return SearchOutcome::Found {
+                    goal: position,
+                    path,
+                    cost,
+                    visited,
+                };
+            }
+
+            moves.clear();
+            problem
+                .movement
+                .moves_into(problem.grid, position, &problem.step_cost, moves);
+
+            for &(next, step_cost) in moves.iter() {
+                let tentative_g_score = cost.saturating_add(step_cost);
+                let next_index = index(next);
+
+                if scores
+                    .get(next_index)
+                    .is_none_or(|g_score| tentative_g_score < g_score)
+                {
+                    scores.set(next_index, tentative_g_score, current);
+                    observer.on_push(&next);
+                    open_set.push(OpenCell {
+                        cost: tentative_g_score,
+                        index: next_index,
+                        priority: priority(tentative_g_score, (problem.heuristic)(next)),
                     });
                 }
             }
+
+            observer.on_close(&position);
+        }
+
+        SearchOutcome::Exhausted { visited } // Goal not reachable
+    }
+}
+
+/// Generation-stamped g-scores and parents, indexed by `row * cols + col`.
+#[derive(Debug, Default)]
+struct Scores {
+    generation: u32,
+    stamps: Vec<u32>,
+    g_scores: Vec<usize>,
+    came_from: Vec<usize>,
+}
+
+impl Scores {
+    /// Makes room for `cells` cells and invalidates every existing entry.
+    fn begin(&mut self, cells: usize) {
+        if self.stamps.len() < cells {
+            self.stamps.resize(cells, 0);
+            self.g_scores.resize(cells, 0);
+            self.came_from.resize(cells, 0);
+        }
+
+        self.generation = self.generation.wrapping_add(1);
+        if self.generation == 0 {
+            // The counter wrapped, so old stamps could look current again.
+            self.stamps.fill(0);
+            self.generation = 1;
+        }
+    }
+
+    fn get(&self, index: usize) -> Option<usize> {
+        (self.stamps[index] == self.generation).then(|| self.g_scores[index])
+    }
+
+    /// Records a g-score and parent. The start is its own parent.
+    fn set(&mut self, index: usize, g_score: usize, parent: usize) {
+        self.stamps[index] = self.generation;
+        self.g_scores[index] = g_score;
+        self.came_from[index] = parent;
+    }
+
+    /// Walks the parents back from `index`, returning the path from the start.
+    fn path_to(&self, index: usize, cols: usize) -> Vec<(usize, usize)> {
+        let mut path = vec![index];
+        let mut current = index;
+        while self.came_from[current] != current {
+            current = self.came_from[current];
+            path.push(current);
         }
+        path.iter().rev().map(|&i| (i / cols, i % cols)).collect()
     }
+}
 
-    // If we exhaust the open set without finding the goal, return an empty path.
-    Vec::new()
+/// An open-set entry, ordered by the same `Priority` as `a_star`'s so ties
+/// break the same way.
+#[derive(Debug)]
+struct OpenCell {
+    cost: usize,
+    index: usize,
+    priority: Priority<usize>,
+}
+
+impl PartialEq for OpenCell {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl Eq for OpenCell {}
+
+impl Ord for OpenCell {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.priority.cmp(&other.priority)
+    }
+}
+
+impl PartialOrd for OpenCell {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
 }
@@ -0,0 +1,94 @@
+use crate::pathfinding::a_star::a_star_observed;
+use crate::pathfinding::grid_movement::{DistanceHeuristic, GridHeuristic, Movement};
+use crate::search::observer::{NoopObserver, SearchObserver};
+use crate::search::outcome::{validate_grid, SearchError, SearchOutcome};
+use crate::search::search_problem::GridProblem;
+
+/// A* over a weighted grid.
+///
+/// Costs follow `uniform_cost_search_grid`: entering a cell costs its weight and
+/// `usize::MAX` cells are impassable. The Manhattan distance is scaled by the
+/// cheapest passable cell, so it never overestimates. The outcome carries the
+/// path and its total cost.
+pub fn a_star_weighted_grid(
+    grid: &[Vec<usize>],
+    start: (usize, usize),
+    goal: (usize, usize),
+) -> Result<SearchOutcome<(usize, usize)>, SearchError> {
+    a_star_weighted_grid_observed(grid, start, goal, &mut NoopObserver)
+}
+
+pub fn a_star_weighted_grid_observed<O>(
+    grid: &[Vec<usize>],
+    start: (usize, usize),
+    goal: (usize, usize),
+    observer: &mut O,
+) -> Result<SearchOutcome<(usize, usize)>, SearchError>
+where
+    O: SearchObserver<(usize, usize)>,
+{
+    a_star_weighted_grid_with_observed(
+        grid,
+        start,
+        goal,
+        Movement::four_way(),
+        DistanceHeuristic::Manhattan,
+        observer,
+    )
+}
+
+/// `a_star_weighted_grid` with a configurable `movement` model and `heuristic`.
+///
+/// The heuristic's estimate is multiplied by the cheapest passable cell, so an
+/// estimate that is admissible on a unit-cost grid stays admissible here.
+pub fn a_star_weighted_grid_with<H>(
+    grid: &[Vec<usize>],
+    start: (usize, usize),
+    goal: (usize, usize),
+    movement: Movement,
+    heuristic: H,
+) -> Result<SearchOutcome<(usize, usize)>, SearchError>
+where
+    H: GridHeuristic,
+{
+    a_star_weighted_grid_with_observed(grid, start, goal, movement, heuristic, &mut NoopObserver)
+}
+
+pub fn a_star_weighted_grid_with_observed<H, O>(
+    grid: &[Vec<usize>],
+    start: (usize, usize),
+    goal: (usize, usize),
+    movement: Movement,
+    heuristic: H,
+    observer: &mut O,
+) -> Result<SearchOutcome<(usize, usize)>, SearchError>
+where
+    H: GridHeuristic,
+    O: SearchObserver<(usize, usize)>,
+{
+    validate_grid(grid, start)?;
+
+    let min_cost = grid
+        .iter()
+        .flatten()
+        .copied()
+        .filter(|&weight| weight != usize::MAX)
+        .min()
+        .unwrap_or(0);
+
+    let problem = GridProblem {
+        grid,
+        start,
+        movement,
+        // Skip impassable cells
+        step_cost: |&weight: &usize| (weight != usize::MAX).then_some(weight),
+        is_goal: |position: (usize, usize)| position == goal,
+        heuristic: |position: (usize, usize)| {
+            heuristic
+                .estimate(position, goal, &movement)
+                .saturating_mul(min_cost)
+        },
+    };
+
+    Ok(a_star_observed(&problem, observer))
+}
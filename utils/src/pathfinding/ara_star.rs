@@ -0,0 +1,276 @@
+use crate::pathfinding::a_star::{NodeCost, Priority, TieBreak};
+use crate::pathfinding::cost::{is_better, Cost};
+use crate::search::budget::SearchBudget;
+use crate::search::observer::{NoopObserver, SearchObserver};
+use crate::search::search_problem::{reconstruct_path, SearchProblem};
+use std::collections::binary_heap::PeekMut;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::time::Instant;
+
+/// A path found by `AraStar`, together with its guarantee.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnytimeSolution<S, C> {
+    pub path: Vec<S>,
+    pub cost: C,
+    /// The path costs at most `bound` times the optimum, provided the heuristic
+    /// is consistent. A bound of 1 means the path is optimal.
+    pub bound: f64,
+}
+
+/// Anytime Repairing A* (ARA*) over any `SearchProblem`.
+///
+/// The first pass is a weighted A* that finds a path quickly. Every later pass
+/// lowers the weight by the weight step and improves the path, reusing the
+/// g-scores of earlier passes and only re-expanding states whose scores
+/// changed. Each finished pass publishes an `AnytimeSolution` whose bound is
+/// the weight it ran with, until a pass with weight 1 proves the path optimal.
+pub struct AraStar<P: SearchProblem> {
+    problem: P,
+    weight: f64,
+    weight_step: f64,
+    open_set: BinaryHeap<NodeCost<P::State, P::Cost>>,
+    open: HashSet<P::State>,
+    closed: HashSet<P::State>,
+    /// States improved after being closed in the current pass. They return to
+    /// the open set when the next pass starts.
+    inconsistent: Vec<P::State>,
+    g_scores: HashMap<P::State, P::Cost>,
+    came_from: HashMap<P::State, P::State>,
+    /// The cheapest goal reached so far.
+    incumbent: Option<(P::State, P::Cost)>,
+    solution: Option<AnytimeSolution<P::State, P::Cost>>,
+    pass_finished: bool,
+    finished: bool,
+    pushed: u64,
+}
+
+impl<P: SearchProblem> AraStar<P> {
+    pub const DEFAULT_WEIGHT_STEP: f64 = 0.5;
+
+    /// An ARA* search whose first pass inflates the heuristic by `weight`.
+    /// Weights below 1 count as 1.
+    pub fn new(problem: P, weight: f64) -> Self {
+        let start = problem.initial_state();
+        let incumbent = problem
+            .is_goal(&start)
+            .then(|| (start.clone(), P::Cost::ZERO));
+
+        let mut search = AraStar {
+            problem,
+            weight: weight.max(1.0),
+            weight_step: Self::DEFAULT_WEIGHT_STEP,
+            open_set: BinaryHeap::new(),
+            open: HashSet::new(),
+            closed: HashSet::new(),
+            inconsistent: Vec::new(),
+            g_scores: HashMap::new(),
+            came_from: HashMap::new(),
+            incumbent,
+            solution: None,
+            pass_finished: false,
+            finished: false,
+            pushed: 0,
+        };
+        search.g_scores.insert(start.clone(), P::Cost::ZERO);
+        search.push(start, P::Cost::ZERO);
+        search
+    }
+
+    /// How much each pass lowers the weight. Steps of 0 or less make the
+    /// second pass run with weight 1.
+    pub fn with_weight_step(mut self, weight_step: f64) -> Self {
+        self.weight_step = weight_step;
+        self
+    }
+
+    /// The weight of the pass that is running or will run next.
+    pub fn weight(&self) -> f64 {
+        self.weight
+    }
+
+    /// The best path published so far.
+    pub fn solution(&self) -> Option<&AnytimeSolution<P::State, P::Cost>> {
+        self.solution.as_ref()
+    }
+
+    /// Whether the published path is optimal, or no goal is reachable.
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Runs one pass and returns the best path so far.
+    pub fn improve(&mut self) -> Option<&AnytimeSolution<P::State, P::Cost>> {
+        self.improve_observed(&mut NoopObserver)
+    }
+
+    /// If the observer stops the pass, the current solution is returned
+    /// unchanged, and the next call carries on with the same pass.
+    pub fn improve_observed<O>(
+        &mut self,
+        observer: &mut O,
+    ) -> Option<&AnytimeSolution<P::State, P::Cost>>
+    where
+        O: SearchObserver<P::State>,
+    {
+        if !self.finished {
+            self.run_pass(observer);
+        }
+        self.solution.as_ref()
+    }
+
+    /// Runs passes until `deadline` or until the path is proven optimal, and
+    /// returns the best path found. A pass cut short by the deadline publishes
+    /// nothing, so this is `None` if even the first pass did not finish.
+    pub fn run_until(&mut self, deadline: Instant) -> Option<&AnytimeSolution<P::State, P::Cost>> {
+        self.run_observed(&mut SearchBudget::unlimited().with_deadline(deadline))
+    }
+
+    /// Runs passes until the path is proven optimal.
+    pub fn run(&mut self) -> Option<&AnytimeSolution<P::State, P::Cost>> {
+        self.run_observed(&mut NoopObserver)
+    }
+
+    /// Returns the best path so far if the observer stops the search first.
+    pub fn run_observed<O>(
+        &mut self,
+        observer: &mut O,
+    ) -> Option<&AnytimeSolution<P::State, P::Cost>>
+    where
+        O: SearchObserver<P::State>,
+    {
+        while !self.finished && self.run_pass(observer) {}
+        self.solution.as_ref()
+    }
+
+    /// Runs or resumes a pass and publishes its path. Returns `false` if the
+    /// observer stopped it first.
+    fn run_pass<O>(&mut self, observer: &mut O) -> bool
+    where
+        O: SearchObserver<P::State>,
+    {
+        if self.pass_finished {
+            self.start_pass();
+        }
+
+        if !self.improve_path(observer) {
+            return false;
+        }
+
+        self.pass_finished = true;
+        let exhausted = self.open.is_empty() && self.inconsistent.is_empty();
+        // With nothing left to expand, no cheaper path can exist.
+        let bound = if exhausted { 1.0 } else { self.weight };
+        if let Some((goal, cost)) = &self.incumbent {
+            self.solution = Some(AnytimeSolution {
+                path: reconstruct_path(&self.came_from, goal.clone()),
+                cost: *cost,
+                bound,
+            });
+        }
+        self.finished = bound == 1.0;
+        true
+    }
+
+    /// Lowers the weight and reopens every state left open or inconsistent by
+    /// the previous pass, with priorities under the new weight.
+    fn start_pass(&mut self) {
+        self.pass_finished = false;
+        self.weight = (self.weight - self.weight_step).max(1.0);
+        if self.weight_step <= 0.0 {
+            self.weight = 1.0;
+        }
+
+        // Re-queue in the old queue's order so ties stay deterministic.
+        let open = std::mem::take(&mut self.open_set).into_sorted_vec();
+        let reopened: Vec<_> = open
+            .into_iter()
+            .rev()
+            .map(|entry| entry.state)
+            .filter(|state| self.open.contains(state))
+            .chain(std::mem::take(&mut self.inconsistent))
+            .collect();
+
+        self.open.clear();
+        self.closed.clear();
+        for state in reopened {
+            if !self.open.contains(&state) {
+                let cost = self.g_scores[&state];
+                self.push(state, cost);
+            }
+        }
+    }
+
+    /// Expands states until none in the open set could lead to a cheaper goal.
+    /// Returns `false` if the observer stopped it first.
+    fn improve_path<O>(&mut self, observer: &mut O) -> bool
+    where
+        O: SearchObserver<P::State>,
+    {
+        loop {
+            let Some(top) = self.open_set.peek_mut() else {
+                return true;
+            };
+
+            // Skip entries for states closed or improved since they were queued.
+            if !self.open.contains(&top.state) || self.g_scores[&top.state] != top.cost {
+                let entry = PeekMut::pop(top);
+                observer.on_discard(&entry.state);
+                continue;
+            }
+
+            if !is_better(
+                top.priority.f(),
+                self.incumbent.as_ref().map(|(_, cost)| cost),
+            ) {
+                return true;
+            }
+
+            if observer.should_stop() {
+                return false;
+            }
+
+            let NodeCost { state, cost, .. } = PeekMut::pop(top);
+            self.open.remove(&state);
+            self.closed.insert(state.clone());
+            observer.on_expand(&state);
+
+            for (next, step_cost) in self.problem.successors(&state) {
+                let tentative_g_score = cost.saturating_add(step_cost);
+                if !is_better(tentative_g_score, self.g_scores.get(&next)) {
+                    continue;
+                }
+
+                self.came_from.insert(next.clone(), state.clone());
+                self.g_scores.insert(next.clone(), tentative_g_score);
+                if self.problem.is_goal(&next)
+                    && is_better(
+                        tentative_g_score,
+                        self.incumbent.as_ref().map(|(_, cost)| cost),
+                    )
+                {
+                    self.incumbent = Some((next.clone(), tentative_g_score));
+                }
+
+                if self.closed.contains(&next) {
+                    self.inconsistent.push(next);
+                } else {
+                    observer.on_push(&next);
+                    self.push(next, tentative_g_score);
+                }
+            }
+
+            observer.on_close(&state);
+        }
+    }
+
+    fn push(&mut self, state: P::State, cost: P::Cost) {
+        self.pushed += 1;
+        let estimate = self.problem.heuristic(&state).scale(self.weight);
+        self.open.insert(state.clone());
+        self.open_set.push(NodeCost {
+            state,
+            cost,
+            priority: Priority::new(cost, estimate, self.pushed, TieBreak::default()),
+        });
+    }
+}
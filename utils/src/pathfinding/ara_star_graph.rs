@@ -0,0 +1,59 @@
+use crate::pathfinding::ara_star::AraStar;
+use crate::pathfinding::cost::Cost;
+use crate::search::observer::SearchObserver;
+use crate::search::outcome::{validate_node, ContainsNode, SearchError};
+use crate::search::search_problem::{GraphProblem, SearchProblem};
+use petgraph::visit::IntoEdges;
+use std::hash::Hash;
+
+/// ARA* over any petgraph graph or view that implements `IntoEdges`, taking the
+/// same heuristic closures as `astar_graph`; see `AraStar`. The first pass
+/// inflates the heuristic by `weight`.
+pub fn ara_star_graph<G, F>(
+    graph: G,
+    start: G::NodeId,
+    goal: G::NodeId,
+    heuristic: F,
+    weight: f64,
+) -> Result<AraStar<impl SearchProblem<State = G::NodeId, Cost = G::EdgeWeight>>, SearchError>
+where
+    G: IntoEdges + ContainsNode,
+    G::NodeId: Eq + Hash,
+    G::EdgeWeight: Cost,
+    F: Fn(G::NodeId, G::NodeId) -> G::EdgeWeight,
+{
+    validate_node(graph, start)?;
+
+    let problem = GraphProblem {
+        graph,
+        start,
+        edge_cost: |weight: &G::EdgeWeight| *weight,
+        is_goal: move |node: G::NodeId| node == goal,
+        heuristic: move |node: G::NodeId| heuristic(node, goal),
+    };
+
+    Ok(AraStar::new(problem, weight))
+}
+
+/// `ara_star_graph` that runs passes until the path is proven optimal, reporting
+/// its progress to `observer`. If the observer stops it first, the returned
+/// search holds the best path so far and can be resumed.
+pub fn ara_star_graph_observed<G, F, O>(
+    graph: G,
+    start: G::NodeId,
+    goal: G::NodeId,
+    heuristic: F,
+    weight: f64,
+    observer: &mut O,
+) -> Result<AraStar<impl SearchProblem<State = G::NodeId, Cost = G::EdgeWeight>>, SearchError>
+where
+    G: IntoEdges + ContainsNode,
+    G::NodeId: Eq + Hash,
+    G::EdgeWeight: Cost,
+    F: Fn(G::NodeId, G::NodeId) -> G::EdgeWeight,
+    O: SearchObserver<G::NodeId>,
+{
+    let mut search = ara_star_graph(graph, start, goal, heuristic, weight)?;
+    search.run_observed(observer);
+    Ok(search)
+}
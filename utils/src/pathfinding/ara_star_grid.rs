@@ -0,0 +1,95 @@
+use crate::pathfinding::ara_star::AraStar;
+use crate::pathfinding::grid_movement::{DistanceHeuristic, GridHeuristic, Movement};
+use crate::search::observer::SearchObserver;
+use crate::search::outcome::{validate_grid, SearchError};
+use crate::search::search_problem::{GridProblem, SearchProblem};
+
+/// ARA* over an obstacle grid (`true` is a wall) with 4-connected moves that
+/// each cost 1, like `a_star_grid`; see `AraStar`. The first pass inflates the
+/// heuristic by `weight`.
+pub fn ara_star_grid(
+    grid: &[Vec<bool>],
+    start: (usize, usize),
+    goal: (usize, usize),
+    weight: f64,
+) -> Result<AraStar<impl SearchProblem<State = (usize, usize), Cost = usize> + '_>, SearchError> {
+    ara_star_grid_with(
+        grid,
+        start,
+        goal,
+        Movement::four_way(),
+        DistanceHeuristic::Manhattan,
+        weight,
+    )
+}
+
+/// `ara_star_grid` that runs passes until the path is proven optimal, reporting
+/// its progress to `observer`. If the observer stops it first, the returned
+/// search holds the best path so far and can be resumed.
+pub fn ara_star_grid_observed<'a, O>(
+    grid: &'a [Vec<bool>],
+    start: (usize, usize),
+    goal: (usize, usize),
+    weight: f64,
+    observer: &mut O,
+) -> Result<AraStar<impl SearchProblem<State = (usize, usize), Cost = usize> + 'a>, SearchError>
+where
+    O: SearchObserver<(usize, usize)>,
+{
+    ara_star_grid_with_observed(
+        grid,
+        start,
+        goal,
+        Movement::four_way(),
+        DistanceHeuristic::Manhattan,
+        weight,
+        observer,
+    )
+}
+
+/// `ara_star_grid` with a configurable `movement` model and `heuristic`, like
+/// `a_star_grid_with`.
+pub fn ara_star_grid_with<'a, H>(
+    grid: &'a [Vec<bool>],
+    start: (usize, usize),
+    goal: (usize, usize),
+    movement: Movement,
+    heuristic: H,
+    weight: f64,
+) -> Result<AraStar<impl SearchProblem<State = (usize, usize), Cost = usize> + 'a>, SearchError>
+where
+    H: GridHeuristic + 'a,
+{
+    validate_grid(grid, start)?;
+
+    let problem = GridProblem {
+        grid,
+        start,
+        movement,
+        step_cost: |&wall: &bool| (!wall).then_some(1),
+        is_goal: move |position: (usize, usize)| position == goal,
+        heuristic: move |position: (usize, usize)| heuristic.estimate(position, goal, &movement),
+    };
+
+    Ok(AraStar::new(problem, weight))
+}
+
+/// `ara_star_grid_with` that runs passes until the path is proven optimal,
+/// like `ara_star_grid_observed`.
+pub fn ara_star_grid_with_observed<'a, H, O>(
+    grid: &'a [Vec<bool>],
+    start: (usize, usize),
+    goal: (usize, usize),
+    movement: Movement,
+    heuristic: H,
+    weight: f64,
+    observer: &mut O,
+) -> Result<AraStar<impl SearchProblem<State = (usize, usize), Cost = usize> + 'a>, SearchError>
+where
+    H: GridHeuristic + 'a,
+    O: SearchObserver<(usize, usize)>,
+{
+    let mut search = ara_star_grid_with(grid, start, goal, movement, heuristic, weight)?;
+    search.run_observed(observer);
+    Ok(search)
+}
@@ -0,0 +1,340 @@
+use crate::search::observer::{NoopObserver, SearchObserver, VisitRecorder};
+use crate::search::outcome::{validate_node, SearchError, SearchOutcome};
+use petgraph::graph::{Graph, NodeIndex};
+use petgraph::visit::EdgeRef;
+use petgraph::Direction::{Incoming, Outgoing};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// Bidirectional breadth-first search, ignoring edge weights.
+///
+/// The backward search follows incoming edges from `goal`, so directed graphs
+/// are searched on their reverse. Finds the path with the fewest edges.
+pub fn bidirectional_search_graph<T, E>(
+    graph: &Graph<T, E>,
+    start: NodeIndex,
+    goal: NodeIndex,
+) -> Result<SearchOutcome<NodeIndex>, SearchError> {
+    bidirectional_search_graph_observed(graph, start, goal, &mut NoopObserver)
+}
+
+/// Both directions report to the same observer.
+pub fn bidirectional_search_graph_observed<T, E, O>(
+    graph: &Graph<T, E>,
+    start: NodeIndex,
+    goal: NodeIndex,
+    observer: &mut O,
+) -> Result<SearchOutcome<NodeIndex>, SearchError>
+where
+    O: SearchObserver<NodeIndex>,
+{
+    validate_node(graph, start)?;
+    validate_node(graph, goal)?;
+
+    let mut observer = VisitRecorder::new(observer);
+
+    observer.on_push(&start);
+    if start == goal {
+        observer.on_expand(&start);
+        observer.on_path_found(&[start]);
+        return Ok(SearchOutcome::Found {
+            goal,
+            path: vec![start],
+            cost: 0,
+            visited: observer.into_visited(),
+        });
+    }
+    observer.on_push(&goal);
+
+    let mut forward_parent = vec![None; graph.node_count()];
+    let mut backward_parent = vec![None; graph.node_count()];
+    let mut forward_seen = vec![false; graph.node_count()];
+    let mut backward_seen = vec![false; graph.node_count()];
+    let mut forward_frontier = vec![start];
+    let mut backward_frontier = vec![goal];
+
+    forward_seen[start.index()] = true;
+    backward_seen[goal.index()] = true;
+
+    while !forward_frontier.is_empty() && !backward_frontier.is_empty() {
+        // Expand one full layer of whichever side has the smaller frontier. The
+        // first node reached by both sides then lies on a shortest path.
+        let layer = if forward_frontier.len() <= backward_frontier.len() {
+            expand_layer(
+                graph,
+                &mut forward_frontier,
+                &mut forward_seen,
+                &mut forward_parent,
+                &backward_seen,
+                Outgoing,
+                &mut observer,
+            )
+        } else {
+            expand_layer(
+                graph,
+                &mut backward_frontier,
+                &mut backward_seen,
+                &mut backward_parent,
+                &forward_seen,
+                Incoming,
+                &mut observer,
+            )
+        };
+
+        match layer {
+            Layer::Met(meeting) => {
+                let path = stitch_path(meeting, &forward_parent, &backward_parent);
+                observer.on_path_found(&path);
+                return Ok(SearchOutcome::Found {
+                    goal,
+                    cost: path.len() - 1,
+                    path,
+                    visited: observer.into_visited(),
+                });
+            }
+            Layer::Stopped => {
+                let deepest = forward_frontier.first().copied().unwrap_or(start);
+                return Ok(SearchOutcome::BudgetExhausted {
+                    best_path: trace_back(deepest, &forward_parent),
+                    visited: observer.into_visited(),
+                });
+            }
+            Layer::Expanded => {}
+        }
+    }
+
+    Ok(SearchOutcome::Exhausted {
+        visited: observer.into_visited(),
+    })
+}
+
+/// Bidirectional Dijkstra over non-negative edge weights.
+///
+/// Finds the cheapest path and its cost.
+pub fn bidirectional_dijkstra_graph<T>(
+    graph: &Graph<T, usize>,
+    start: NodeIndex,
+    goal: NodeIndex,
+) -> Result<SearchOutcome<NodeIndex>, SearchError> {
+    bidirectional_dijkstra_graph_observed(graph, start, goal, &mut NoopObserver)
+}
+
+pub fn bidirectional_dijkstra_graph_observed<T, O>(
+    graph: &Graph<T, usize>,
+    start: NodeIndex,
+    goal: NodeIndex,
+    observer: &mut O,
+) -> Result<SearchOutcome<NodeIndex>, SearchError>
+where
+    O: SearchObserver<NodeIndex>,
+{
+    validate_node(graph, start)?;
+    validate_node(graph, goal)?;
+
+    let mut observer = VisitRecorder::new(observer);
+
+    observer.on_push(&start);
+    if start == goal {
+        observer.on_expand(&start);
+        observer.on_path_found(&[start]);
+        return Ok(SearchOutcome::Found {
+            goal,
+            path: vec![start],
+            cost: 0,
+            visited: observer.into_visited(),
+        });
+    }
+    observer.on_push(&goal);
+
+    let mut forward_cost = vec![usize::MAX; graph.node_count()];
+    let mut backward_cost = vec![usize::MAX; graph.node_count()];
+    let mut forward_parent = vec![None; graph.node_count()];
+    let mut backward_parent = vec![None; graph.node_count()];
+    let mut forward_frontier: BinaryHeap<Reverse<(usize, NodeIndex)>> = BinaryHeap::new();
+    let mut backward_frontier: BinaryHeap<Reverse<(usize, NodeIndex)>> = BinaryHeap::new();
+
+    forward_cost[start.index()] = 0;
+    backward_cost[goal.index()] = 0;
+    forward_frontier.push(Reverse((0, start)));
+    backward_frontier.push(Reverse((0, goal)));
+
+    // Cheapest start -> goal cost seen so far and the node where it was found.
+    let mut best_cost = usize::MAX;
+    let mut meeting = None;
+    // Farthest node settled by the forward search, for a partial result.
+    let mut farthest = start;
+
+    while let (Some(&Reverse((forward_top, _))), Some(&Reverse((backward_top, _)))) =
+        (forward_frontier.peek(), backward_frontier.peek())
+    {
+        // No unsettled node can improve on the best meeting any more.
+        if forward_top.saturating_add(backward_top) >= best_cost {
+            break;
+        }
+
+        let (frontier, cost, parent, other_cost, direction) = if forward_top <= backward_top {
+            (
+                &mut forward_frontier,
+                &mut forward_cost,
+                &mut forward_parent,
+                &backward_cost,
+                Outgoing,
+            )
+        } else {
+            (
+                &mut backward_frontier,
+                &mut backward_cost,
+                &mut backward_parent,
+                &forward_cost,
+                Incoming,
+            )
+        };
+
+        let Some(Reverse((current_cost, current))) = frontier.pop() else {
+            break;
+        };
+
+        if current_cost > cost[current.index()] {
+            observer.on_discard(&current);
+            continue;
+        }
+
+        if observer.should_stop() {
+            return Ok(SearchOutcome::BudgetExhausted {
+                best_path: trace_back(farthest, &forward_parent),
+                visited: observer.into_visited(),
+            });
+        }
+
+        observer.on_expand(&current);
+        if direction == Outgoing {
+            farthest = current;
+        }
+
+        for edge in graph.edges_directed(current, direction) {
+            let next = if direction == Outgoing {
+                edge.target()
+            } else {
+                edge.source()
+            };
+            let new_cost = current_cost.saturating_add(*edge.weight());
+
+            if new_cost < cost[next.index()] {
+                cost[next.index()] = new_cost;
+                parent[next.index()] = Some(current);
+                observer.on_push(&next);
+                frontier.push(Reverse((new_cost, next)));
+            }
+
+            let through = cost[next.index()].saturating_add(other_cost[next.index()]);
+            if through < best_cost {
+                best_cost = through;
+                meeting = Some(next);
+            }
+        }
+
+        observer.on_close(&current);
+    }
+
+    match meeting {
+        Some(meeting) => {
+            let path = stitch_path(meeting, &forward_parent, &backward_parent);
+            observer.on_path_found(&path);
+            Ok(SearchOutcome::Found {
+                goal,
+                path,
+                cost: best_cost,
+                visited: observer.into_visited(),
+            })
+        }
+        None => Ok(SearchOutcome::Exhausted {
+            visited: observer.into_visited(),
+        }),
+    }
+}
+
+/// How a call to `expand_layer` ended.
+enum Layer {
+    /// The whole layer was expanded without meeting the other search.
+    Expanded,
+    /// This node was reached by both searches.
+    Met(NodeIndex),
+    /// The observer stopped the search part-way through the layer.
+    Stopped,
+}
+
+/// Expands every node in `frontier` by one edge in `direction`, replacing it
+/// with the newly discovered layer. Stops at the first node already seen by
+/// the opposite search.
+fn expand_layer<T, E, O>(
+    graph: &Graph<T, E>,
+    frontier: &mut Vec<NodeIndex>,
+    seen: &mut [bool],
+    parent: &mut [Option<NodeIndex>],
+    other_seen: &[bool],
+    direction: petgraph::Direction,
+    observer: &mut O,
+) -> Layer
+where
+    O: SearchObserver<NodeIndex>,
+{
+    let mut next_layer = Vec::new();
+
+    for &node in frontier.iter() {
+        if observer.should_stop() {
+            return Layer::Stopped;
+        }
+
+        observer.on_expand(&node);
+
+        for neighbor in graph.neighbors_directed(node, direction) {
+            if seen[neighbor.index()] {
+                continue;
+            }
+
+            seen[neighbor.index()] = true;
+            parent[neighbor.index()] = Some(node);
+            observer.on_push(&neighbor);
+
+            if other_seen[neighbor.index()] {
+                return Layer::Met(neighbor);
+            }
+
+            next_layer.push(neighbor);
+        }
+
+        observer.on_close(&node);
+    }
+
+    *frontier = next_layer;
+    Layer::Expanded
+}
+
+/// Joins the forward path to `meeting` with the backward path from it.
+fn stitch_path(
+    meeting: NodeIndex,
+    forward_parent: &[Option<NodeIndex>],
+    backward_parent: &[Option<NodeIndex>],
+) -> Vec<NodeIndex> {
+    let mut path = trace_back(meeting, forward_parent);
+
+    let mut current = meeting;
+    while let Some(parent) = backward_parent[current.index()] {
+        path.push(parent);
+        current = parent;
+    }
+
+    path
+}
+
+/// The forward path from the start to `node`.
+fn trace_back(node: NodeIndex, forward_parent: &[Option<NodeIndex>]) -> Vec<NodeIndex> {
+    let mut path = vec![node];
+    let mut current = node;
+    while let Some(parent) = forward_parent[current.index()] {
+        path.push(parent);
+        current = parent;
+    }
+    path.reverse();
+    path
+}
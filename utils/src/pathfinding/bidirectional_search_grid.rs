@@ -0,0 +1,376 @@
+use crate::search::observer::{NoopObserver, SearchObserver, VisitRecorder};
+use crate::search::outcome::{validate_grid, SearchError, SearchOutcome};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+type Parents = Vec<Vec<Option<(usize, usize)>>>;
+
+/// Bidirectional breadth-first search over an obstacle grid (`true` is a wall).
+///
+/// Finds the shortest 4-connected path. A `goal` outside the grid or on a wall
+/// is unreachable.
+pub fn bidirectional_search_grid(
+    grid: &[Vec<bool>],
+    start: (usize, usize),
+    goal: (usize, usize),
+) -> Result<SearchOutcome<(usize, usize)>, SearchError> {
+    bidirectional_search_grid_observed(grid, start, goal, &mut NoopObserver)
+}
+
+/// Both directions report to the same observer.
+pub fn bidirectional_search_grid_observed<O>(
+    grid: &[Vec<bool>],
+    start: (usize, usize),
+    goal: (usize, usize),
+    observer: &mut O,
+) -> Result<SearchOutcome<(usize, usize)>, SearchError>
+where
+    O: SearchObserver<(usize, usize)>,
+{
+    validate_grid(grid, start)?;
+
+    let mut observer = VisitRecorder::new(observer);
+    // Standing on the goal counts as reaching it, wall or not.
+    if start != goal && (!in_bounds(grid, goal) || grid[goal.0][goal.1]) {
+        return Ok(SearchOutcome::Exhausted {
+            visited: observer.into_visited(),
+        });
+    }
+
+    observer.on_push(&start);
+    if start == goal {
+        observer.on_expand(&start);
+        observer.on_path_found(&[start]);
+        return Ok(SearchOutcome::Found {
+            goal,
+            path: vec![start],
+            cost: 0,
+            visited: observer.into_visited(),
+        });
+    }
+    observer.on_push(&goal);
+
+    let cols = grid.iter().map(Vec::len).max().unwrap_or(0);
+    let mut forward_parent = vec![vec![None; cols]; grid.len()];
+    let mut backward_parent = vec![vec![None; cols]; grid.len()];
+    let mut forward_seen = vec![vec![false; cols]; grid.len()];
+    let mut backward_seen = vec![vec![false; cols]; grid.len()];
+    let mut forward_frontier = vec![start];
+    let mut backward_frontier = vec![goal];
+
+    forward_seen[start.0][start.1] = true;
+    backward_seen[goal.0][goal.1] = true;
+
+    // The start cell is always enterable so the backward search can finish on it.
+    let passable = |(row, col): (usize, usize)| (row, col) == start || !grid[row][col];
+
+    while !forward_frontier.is_empty() && !backward_frontier.is_empty() {
+        // Expand one full layer of whichever side has the smaller frontier. The
+        // first cell reached by both sides then lies on a shortest path.
+        let layer = if forward_frontier.len() <= backward_frontier.len() {
+            expand_layer(
+                grid,
+                &mut forward_frontier,
+                &mut forward_seen,
+                &mut forward_parent,
+                &backward_seen,
+                passable,
+                &mut observer,
+            )
+        } else {
+            expand_layer(
+                grid,
+                &mut backward_frontier,
+                &mut backward_seen,
+                &mut backward_parent,
+                &forward_seen,
+                passable,
+                &mut observer,
+            )
+        };
+
+        match layer {
+            Layer::Met(meeting) => {
+                let path = stitch_path(meeting, &forward_parent, &backward_parent);
+                observer.on_path_found(&path);
+                return Ok(SearchOutcome::Found {
+                    goal,
+                    cost: path.len() - 1,
+                    path,
+                    visited: observer.into_visited(),
+                });
+            }
+            Layer::Stopped => {
+                let deepest = forward_frontier.first().copied().unwrap_or(start);
+                return Ok(SearchOutcome::BudgetExhausted {
+                    best_path: trace_back(deepest, &forward_parent),
+                    visited: observer.into_visited(),
+                });
+            }
+            Layer::Expanded => {}
+        }
+    }
+
+    Ok(SearchOutcome::Exhausted {
+        visited: observer.into_visited(),
+    })
+}
+
+/// Bidirectional Dijkstra over a weighted grid.
+///
+/// Costs follow `uniform_cost_search_grid`: entering a cell costs its weight and
+/// `usize::MAX` cells are impassable. The outcome carries the path and its total
+/// cost.
+pub fn bidirectional_dijkstra_grid(
+    grid: &[Vec<usize>],
+    start: (usize, usize),
+    goal: (usize, usize),
+) -> Result<SearchOutcome<(usize, usize)>, SearchError> {
+    bidirectional_dijkstra_grid_observed(grid, start, goal, &mut NoopObserver)
+}
+
+pub fn bidirectional_dijkstra_grid_observed<O>(
+    grid: &[Vec<usize>],
+    start: (usize, usize),
+    goal: (usize, usize),
+    observer: &mut O,
+) -> Result<SearchOutcome<(usize, usize)>, SearchError>
+where
+    O: SearchObserver<(usize, usize)>,
+{
+    validate_grid(grid, start)?;
+
+    let mut observer = VisitRecorder::new(observer);
+    // Standing on the goal counts as reaching it, wall or not.
+    if start != goal && (!in_bounds(grid, goal) || grid[goal.0][goal.1] == usize::MAX) {
+        return Ok(SearchOutcome::Exhausted {
+            visited: observer.into_visited(),
+        });
+    }
+
+    observer.on_push(&start);
+    if start == goal {
+        observer.on_expand(&start);
+        observer.on_path_found(&[start]);
+        return Ok(SearchOutcome::Found {
+            goal,
+            path: vec![start],
+            cost: 0,
+            visited: observer.into_visited(),
+        });
+    }
+    observer.on_push(&goal);
+
+    let cols = grid.iter().map(Vec::len).max().unwrap_or(0);
+    let mut forward_cost = vec![vec![usize::MAX; cols]; grid.len()];
+    let mut backward_cost = vec![vec![usize::MAX; cols]; grid.len()];
+    let mut forward_parent = vec![vec![None; cols]; grid.len()];
+    let mut backward_parent = vec![vec![None; cols]; grid.len()];
+    let mut forward_frontier: BinaryHeap<Reverse<(usize, (usize, usize))>> = BinaryHeap::new();
+    let mut backward_frontier: BinaryHeap<Reverse<(usize, (usize, usize))>> = BinaryHeap::new();
+
+    forward_cost[start.0][start.1] = 0;
+    backward_cost[goal.0][goal.1] = 0;
+    forward_frontier.push(Reverse((0, start)));
+    backward_frontier.push(Reverse((0, goal)));
+
+    // Cheapest start -> goal cost seen so far and the cell where it was found.
+    let mut best_cost = usize::MAX;
+    let mut meeting = None;
+    // Farthest cell settled by the forward search, for a partial result.
+    let mut farthest = start;
+
+    while let (Some(&Reverse((forward_top, _))), Some(&Reverse((backward_top, _)))) =
+        (forward_frontier.peek(), backward_frontier.peek())
+    {
+        // No unsettled cell can improve on the best meeting any more.
+        if forward_top.saturating_add(backward_top) >= best_cost {
+            break;
+        }
+
+        let is_forward = forward_top <= backward_top;
+        let (frontier, cost, parent, other_cost) = if is_forward {
+            (
+                &mut forward_frontier,
+                &mut forward_cost,
+                &mut forward_parent,
+                &backward_cost,
+            )
+        } else {
+            (
+                &mut backward_frontier,
+                &mut backward_cost,
+                &mut backward_parent,
+                &forward_cost,
+            )
+        };
+
+        let Some(Reverse((current_cost, (row, col)))) = frontier.pop() else {
+            break;
+        };
+
+        if current_cost > cost[row][col] {
+            observer.on_discard(&(row, col));
+            continue;
+        }
+
+        if observer.should_stop() {
+            return Ok(SearchOutcome::BudgetExhausted {
+                best_path: trace_back(farthest, &forward_parent),
+                visited: observer.into_visited(),
+            });
+        }
+
+        observer.on_expand(&(row, col));
+        if is_forward {
+            farthest = (row, col);
+        }
+
+        for (next_row, next_col) in neighbors(grid, (row, col)) {
+            let next_weight = grid[next_row][next_col];
+            if next_weight == usize::MAX && (next_row, next_col) != start {
+                continue;
+            }
+
+            // Forward steps pay for the cell being entered; backward steps pay
+            // for the cell being left, since that is the one entered going forward.
+            let step_cost = if is_forward {
+                next_weight
+            } else {
+                grid[row][col]
+            };
+            let new_cost = current_cost.saturating_add(step_cost);
+
+            if new_cost < cost[next_row][next_col] {
+                cost[next_row][next_col] = new_cost;
+                parent[next_row][next_col] = Some((row, col));
+                observer.on_push(&(next_row, next_col));
+                frontier.push(Reverse((new_cost, (next_row, next_col))));
+            }
+
+            let through = cost[next_row][next_col].saturating_add(other_cost[next_row][next_col]);
+            if through < best_cost {
+                best_cost = through;
+                meeting = Some((next_row, next_col));
+            }
+        }
+
+        observer.on_close(&(row, col));
+    }
+
+    match meeting {
+        Some(meeting) => {
+            let path = stitch_path(meeting, &forward_parent, &backward_parent);
+            observer.on_path_found(&path);
+            Ok(SearchOutcome::Found {
+                goal,
+                path,
+                cost: best_cost,
+                visited: observer.into_visited(),
+            })
+        }
+        None => Ok(SearchOutcome::Exhausted {
+            visited: observer.into_visited(),
+        }),
+    }
+}
+
+fn in_bounds<T>(grid: &[Vec<T>], (row, col): (usize, usize)) -> bool {
+    row < grid.len() && col < grid[row].len()
+}
+
+fn neighbors<T>(
+    grid: &[Vec<T>],
+    (row, col): (usize, usize),
+) -> impl Iterator<Item = (usize, usize)> + '_ {
+    [
+        (row.wrapping_sub(1), col), // Up
+        (row + 1, col),             // Down
+        (row, col.wrapping_sub(1)), // Left
+        (row, col + 1),             // Right
+    ]
+    .into_iter()
+    .filter(|&position| in_bounds(grid, position))
+}
+
+/// How a call to `expand_layer` ended.
+enum Layer {
+    /// The whole layer was expanded without meeting the other search.
+    Expanded,
+    /// This cell was reached by both searches.
+    Met((usize, usize)),
+    /// The observer stopped the search part-way through the layer.
+    Stopped,
+}
+
+/// Expands every cell in `frontier` by one step, replacing it with the newly
+/// discovered layer. Stops at the first cell already seen by the opposite search.
+fn expand_layer(
+    grid: &[Vec<bool>],
+    frontier: &mut Vec<(usize, usize)>,
+    seen: &mut [Vec<bool>],
+    parent: &mut Parents,
+    other_seen: &[Vec<bool>],
+    passable: impl Fn((usize, usize)) -> bool,
+    observer: &mut impl SearchObserver<(usize, usize)>,
+) -> Layer {
+    let mut next_layer = Vec::new();
+
+    for &position in frontier.iter() {
+        if observer.should_stop() {
+            return Layer::Stopped;
+        }
+
+        observer.on_expand(&position);
+
+        for (next_row, next_col) in neighbors(grid, position) {
+            if seen[next_row][next_col] || !passable((next_row, next_col)) {
+                continue;
+            }
+
+            seen[next_row][next_col] = true;
+            parent[next_row][next_col] = Some(position);
+            observer.on_push(&(next_row, next_col));
+
+            if other_seen[next_row][next_col] {
+                return Layer::Met((next_row, next_col));
+            }
+
+            next_layer.push((next_row, next_col));
+        }
+
+        observer.on_close(&position);
+    }
+
+    *frontier = next_layer;
+    Layer::Expanded
+}
+
+/// Joins the forward path to `meeting` with the backward path from it.
+fn stitch_path(
+    meeting: (usize, usize),
+    forward_parent: &Parents,
+    backward_parent: &Parents,
+) -> Vec<(usize, usize)> {
+    let mut path = trace_back(meeting, forward_parent);
+
+    let mut current = meeting;
+    while let Some(parent) = backward_parent[current.0][current.1] {
+        path.push(parent);
+        current = parent;
+    }
+
+    path
+}
+
+/// The forward path from the start to `position`.
+fn trace_back(position: (usize, usize), forward_parent: &Parents) -> Vec<(usize, usize)> {
+    let mut path = vec![position];
+    let mut current = position;
+    while let Some(parent) = forward_parent[current.0][current.1] {
+        path.push(parent);
+        current = parent;
+    }
+    path.reverse();
+    path
+}
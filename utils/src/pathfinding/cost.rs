@@ -0,0 +1,106 @@
+use std::cmp::Ordering;
+use std::fmt::Debug;
+
+/// A path or edge cost the weighted searches can accumulate and compare.
+///
+/// Implemented for every primitive integer and for `f32` and `f64`. Floats are
+/// ordered with `total_cmp`, so they can be used directly as edge weights.
+pub trait Cost: Copy + PartialOrd + Debug {
+    const ZERO: Self;
+
+    /// Adds two costs without wrapping: integers saturate at their maximum and
+    /// floats overflow to infinity.
+    fn saturating_add(self, other: Self) -> Self;
+
+    /// A total order over every value of the type, including NaN for floats.
+    fn total_cmp(&self, other: &Self) -> Ordering;
+
+    /// Multiplies the cost by `factor`, for inflating heuristics. Integers are
+    /// rounded down, so a scaled estimate never exceeds the exact product.
+    fn scale(self, factor: f64) -> Self;
+}
+
+macro_rules! impl_integer_cost {
+    ($($integer:ty),*) => {
+        $(
+            impl Cost for $integer {
+                const ZERO: Self = 0;
+
+                fn saturating_add(self, other: Self) -> Self {
+                    <$integer>::saturating_add(self, other)
+                }
+
+                fn total_cmp(&self, other: &Self) -> Ordering {
+                    self.cmp(other)
+                }
+
+                fn scale(self, factor: f64) -> Self {
+                    if factor == 1.0 {
+                        return self; // Large values would lose precision as floats.
+                    }
+                    (self as f64 * factor) as $integer
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_float_cost {
+    ($($float:ty),*) => {
+        $(
+            impl Cost for $float {
+                const ZERO: Self = 0.0;
+
+                fn saturating_add(self, other: Self) -> Self {
+                    self + other
+                }
+
+                fn total_cmp(&self, other: &Self) -> Ordering {
+                    <$float>::total_cmp(self, other)
+                }
+
+                fn scale(self, factor: f64) -> Self {
+                    (self as f64 * factor) as $float
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_cost!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_float_cost!(f32, f64);
+
+/// Whether `cost` improves on the best known cost, if there is one.
+pub(crate) fn is_better<C: Cost>(cost: C, best: Option<&C>) -> bool {
+    best.is_none_or(|best| cost.total_cmp(best).is_lt())
+}
+
+/// Whether `cost` is beaten by the best known cost, marking a stale heap entry.
+pub(crate) fn is_worse<C: Cost>(cost: C, best: Option<&C>) -> bool {
+    best.is_some_and(|best| cost.total_cmp(best).is_gt())
+}
+
+/// Orders a `Cost` by `Cost::total_cmp`, so floats can be stored in ordered
+/// collections such as `BinaryHeap` or `BTreeMap`.
+#[derive(Debug, Clone, Copy)]
+pub struct TotalOrder<C>(pub C);
+
+impl<C: Cost> PartialEq for TotalOrder<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<C: Cost> Eq for TotalOrder<C> {}
+
+impl<C: Cost> Ord for TotalOrder<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl<C: Cost> PartialOrd for TotalOrder<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
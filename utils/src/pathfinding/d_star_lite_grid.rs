@@ -0,0 +1,332 @@
+use crate::pathfinding::grid_movement::{DistanceHeuristic, GridHeuristic, Movement, Neighborhood};
+use crate::search::observer::{NoopObserver, SearchObserver};
+use crate::search::outcome::{validate_grid, SearchError, SearchOutcome};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// Priority of a queued cell: `(min(g, rhs) + h + km, min(g, rhs))`, smaller
+/// first.
+type Key = (usize, usize);
+
+/// D* Lite over an obstacle grid (`true` is a wall), for agents that discover
+/// walls as they move.
+///
+/// The planner searches backwards from the goal and keeps its g-values between
+/// plans. After `update_cell` or `move_start`, `plan` repairs only the part of
+/// the solution the change affects instead of searching from scratch, and
+/// finds a path as cheap as a fresh `a_star_grid_with` run on the current grid.
+#[derive(Debug, Clone)]
+pub struct DStarLite<H = DistanceHeuristic> {
+    grid: Vec<Vec<bool>>,
+    cols: usize,
+    movement: Movement,
+    heuristic: H,
+    start: (usize, usize),
+    goal: (usize, usize),
+    /// Where the start was when `km` was last brought up to date.
+    last_start: (usize, usize),
+    /// Sum of the heuristic distances the start has moved, which keeps old
+    /// keys in the queue valid lower bounds.
+    km: usize,
+    g_scores: Vec<usize>,
+    /// One-step lookahead: the cheapest cost through any successor.
+    rhs: Vec<usize>,
+    /// The current key of every cell in the queue. Heap entries with any other
+    /// key are stale.
+    queued: Vec<Option<Key>>,
+    queue: BinaryHeap<Reverse<(Key, usize)>>,
+    moves: Vec<((usize, usize), usize)>,
+}
+
+impl DStarLite {
+    /// A planner with 4-connected moves that each cost 1, like `a_star_grid`.
+    pub fn new(
+        grid: &[Vec<bool>],
+        start: (usize, usize),
+        goal: (usize, usize),
+    ) -> Result<Self, SearchError> {
+        DStarLite::with_movement(
+            grid,
+            start,
+            goal,
+            Movement::four_way(),
+            DistanceHeuristic::Manhattan,
+        )
+    }
+}
+
+impl<H: GridHeuristic> DStarLite<H> {
+    /// A planner with a configurable `movement` model and `heuristic`, like
+    /// `a_star_grid_with`. The heuristic must never overestimate and must give
+    /// the same estimate in both directions.
+    pub fn with_movement(
+        grid: &[Vec<bool>],
+        start: (usize, usize),
+        goal: (usize, usize),
+        movement: Movement,
+        heuristic: H,
+    ) -> Result<Self, SearchError> {
+        validate_grid(grid, start)?;
+
+        let cols = grid.iter().map(Vec::len).max().unwrap_or(0);
+        let cells = grid.len() * cols;
+        let mut planner = DStarLite {
+            grid: grid.to_vec(),
+            cols,
+            movement,
+            heuristic,
+            start,
+            goal,
+            last_start: start,
+            km: 0,
+            g_scores: vec![usize::MAX; cells],
+            rhs: vec![usize::MAX; cells],
+            queued: vec![None; cells],
+            queue: BinaryHeap::new(),
+            moves: Vec::new(),
+        };
+
+        if let Some(goal) = planner.index(goal) {
+            planner.rhs[goal] = 0;
+            planner.enqueue(goal, &mut NoopObserver);
+        }
+
+        Ok(planner)
+    }
+
+    pub fn start(&self) -> (usize, usize) {
+        self.start
+    }
+
+    pub fn goal(&self) -> (usize, usize) {
+        self.goal
+    }
+
+    /// The planner's copy of the grid, with every update applied.
+    pub fn grid(&self) -> &[Vec<bool>] {
+        &self.grid
+    }
+
+    /// Marks the cell at `position` as a wall or as free. Positions outside the
+    /// grid are ignored.
+    pub fn update_cell(&mut self, (row, col): (usize, usize), blocked: bool) {
+        let Some(cell) = self.grid.get_mut(row).and_then(|line| line.get_mut(col)) else {
+            return;
+        };
+        if *cell == blocked {
+            return;
+        }
+        *cell = blocked;
+
+        // Every move whose cost changed starts inside the 3x3 block around the
+        // cell: moves into it, and diagonals squeezing past it.
+        for next_row in row.saturating_sub(1)..=row + 1 {
+            for next_col in col.saturating_sub(1)..=col + 1 {
+                if let Some(index) = self.index((next_row, next_col)) {
+                    self.update_vertex(index, &mut NoopObserver);
+                }
+            }
+        }
+    }
+
+    /// Moves the agent to `position`; the next `plan` starts from there.
+    pub fn move_start(&mut self, position: (usize, usize)) -> Result<(), SearchError> {
+        validate_grid(&self.grid, position)?;
+
+        self.km = self
+            .km
+            .saturating_add(self.estimate(self.last_start, position));
+        self.last_start = position;
+        self.start = position;
+        Ok(())
+    }
+
+    /// Brings the solution up to date and returns the path from the start to
+    /// the goal. `visited` holds the cells expanded by this call only.
+    pub fn plan(&mut self) -> SearchOutcome<(usize, usize)> {
+        self.plan_observed(&mut NoopObserver)
+    }
+
+    /// If the observer stops the search, the outcome is `BudgetExhausted` with
+    /// only the start as its best path. The planner keeps its progress, so
+    /// planning again carries on where it stopped.
+    pub fn plan_observed<O>(&mut self, observer: &mut O) -> SearchOutcome<(usize, usize)>
+    where
+        O: SearchObserver<(usize, usize)>,
+    {
+        let mut visited = Vec::new();
+        let start = self.index(self.start).expect("The start lies on the grid.");
+
+        while let Some(&Reverse((old_key, current))) = self.queue.peek() {
+            let position = self.position(current);
+            if self.queued[current] != Some(old_key) {
+                self.queue.pop();
+                observer.on_discard(&position);
+                continue;
+            }
+
+            if old_key >= self.key(start) && self.rhs[start] == self.g_scores[start] {
+                break;
+            }
+
+            if observer.should_stop() {
+                return SearchOutcome::BudgetExhausted {
+                    best_path: vec![self.start],
+                    visited,
+                };
+            }
+
+            self.queue.pop();
+            let new_key = self.key(current);
+            if old_key < new_key {
+                // The start moved since this entry was queued.
+                observer.on_discard(&position);
+                self.enqueue(current, observer);
+                continue;
+            }
+
+            self.queued[current] = None;
+            observer.on_expand(&position);
+            visited.push(position);
+
+            if self.g_scores[current] > self.rhs[current] {
+                self.g_scores[current] = self.rhs[current];
+            } else {
+                self.g_scores[current] = usize::MAX;
+                self.update_vertex(current, observer);
+            }
+            for predecessor in self.predecessors(position) {
+                self.update_vertex(predecessor, observer);
+            }
+
+            observer.on_close(&position);
+        }
+
+        match self.extract_path() {
+            Some(path) => {
+                observer.on_path_found(&path);
+                SearchOutcome::Found {
+                    goal: self.goal,
+                    path,
+                    cost: self.g_scores[start],
+                    visited,
+                }
+            }
+            None => SearchOutcome::Exhausted { visited }, // Goal not reachable
+        }
+    }
+
+    /// Follows the cheapest successors from the start down to the goal.
+    fn extract_path(&mut self) -> Option<Vec<(usize, usize)>> {
+        let start = self.index(self.start)?;
+        if self.g_scores[start] == usize::MAX {
+            return None;
+        }
+
+        let mut path = vec![self.start];
+        let mut current = self.start;
+        while current != self.goal {
+            if path.len() > self.g_scores.len() {
+                return None; // Guards against a cycle in inconsistent scores.
+            }
+
+            self.load_moves(current);
+            let (next, _) = self
+                .moves
+                .iter()
+                .map(|&(next, cost)| (next, cost.saturating_add(self.g_score(next))))
+                .filter(|&(_, cost)| cost != usize::MAX)
+                .min_by_key(|&(_, cost)| cost)?;
+            path.push(next);
+            current = next;
+        }
+        Some(path)
+    }
+
+    /// Recomputes the lookahead of a cell and queues it if it is inconsistent.
+    fn update_vertex<O>(&mut self, index: usize, observer: &mut O)
+    where
+        O: SearchObserver<(usize, usize)>,
+    {
+        let position = self.position(index);
+        if position != self.goal {
+            self.load_moves(position);
+            self.rhs[index] = self
+                .moves
+                .iter()
+                .map(|&(next, cost)| cost.saturating_add(self.g_score(next)))
+                .min()
+                .unwrap_or(usize::MAX);
+        }
+
+        if self.g_scores[index] != self.rhs[index] {
+            self.enqueue(index, observer);
+        } else {
+            self.queued[index] = None;
+        }
+    }
+
+    fn enqueue<O>(&mut self, index: usize, observer: &mut O)
+    where
+        O: SearchObserver<(usize, usize)>,
+    {
+        let key = self.key(index);
+        self.queued[index] = Some(key);
+        self.queue.push(Reverse((key, index)));
+        observer.on_push(&self.position(index));
+    }
+
+    fn key(&self, index: usize) -> Key {
+        let score = self.g_scores[index].min(self.rhs[index]);
+        let estimate = self.estimate(self.start, self.position(index));
+        (
+            score.saturating_add(estimate).saturating_add(self.km),
+            score,
+        )
+    }
+
+    fn estimate(&self, from: (usize, usize), to: (usize, usize)) -> usize {
+        self.heuristic.estimate(from, to, &self.movement)
+    }
+
+    /// Fills `moves` with the free cells reachable from `position` in one step.
+    fn load_moves(&mut self, position: (usize, usize)) {
+        self.moves.clear();
+        self.movement.moves_into(
+            &self.grid,
+            position,
+            |&wall: &bool| (!wall).then_some(1),
+            &mut self.moves,
+        );
+    }
+
+    /// Every cell that might step into `position`. Walls are included, since a
+    /// move's cost depends only on the cell it enters.
+    fn predecessors(&self, (row, col): (usize, usize)) -> Vec<usize> {
+        let diagonals = matches!(self.movement.neighborhood, Neighborhood::EightWay { .. });
+        let mut predecessors = Vec::new();
+        for next_row in row.saturating_sub(1)..=row + 1 {
+            for next_col in col.saturating_sub(1)..=col + 1 {
+                let is_diagonal = next_row != row && next_col != col;
+                if (next_row, next_col) == (row, col) || (is_diagonal && !diagonals) {
+                    continue;
+                }
+                predecessors.extend(self.index((next_row, next_col)));
+            }
+        }
+        predecessors
+    }
+
+    fn g_score(&self, position: (usize, usize)) -> usize {
+        self.index(position)
+            .map_or(usize::MAX, |index| self.g_scores[index])
+    }
+
+    fn index(&self, (row, col): (usize, usize)) -> Option<usize> {
+        (row < self.grid.len() && col < self.grid[row].len()).then_some(row * self.cols + col)
+    }
+
+    fn position(&self, index: usize) -> (usize, usize) {
+        (index / self.cols, index % self.cols)
+    }
+}
@@ -0,0 +1,129 @@
+use crate::search::observer::{NoopObserver, SearchObserver};
+use crate::search::outcome::{validate_node, SearchError};
+use petgraph::graph::{Graph, NodeIndex};
+use petgraph::visit::EdgeRef;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// Single-source shortest-path tree produced by `djikstra_shortest_path_graph`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShortestPathTree {
+    source: NodeIndex,
+    distances: Vec<Option<usize>>,
+    predecessors: Vec<Option<NodeIndex>>,
+    complete: bool,
+}
+
+impl ShortestPathTree {
+    pub fn source(&self) -> NodeIndex {
+        self.source
+    }
+
+    /// Total edge weight from the source to `target`, or `None` if it is unreachable.
+    pub fn distance_to(&self, target: NodeIndex) -> Option<usize> {
+        self.distances.get(target.index()).copied().flatten()
+    }
+
+    /// The node preceding `target` on its shortest path from the source.
+    pub fn predecessor(&self, target: NodeIndex) -> Option<NodeIndex> {
+        self.predecessors.get(target.index()).copied().flatten()
+    }
+
+    /// `false` if the observer stopped the search early, in which case nodes
+    /// left on the frontier may have longer distances than their true ones.
+    pub fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    pub fn is_reachable(&self, target: NodeIndex) -> bool {
+        self.distance_to(target).is_some()
+    }
+
+    /// Walks the predecessor tree back from `target`, returning the path from the source.
+    pub fn path_to(&self, target: NodeIndex) -> Option<Vec<NodeIndex>> {
+        if !self.is_reachable(target) {
+            return None;
+        }
+
+        let mut path = vec![target];
+        let mut current = target;
+        while let Some(parent) = self.predecessor(current) {
+            path.push(parent);
+            current = parent;
+        }
+
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every reachable node paired with its distance from the source, in index order.
+    pub fn distances(&self) -> impl Iterator<Item = (NodeIndex, usize)> + '_ {
+        self.distances
+            .iter()
+            .enumerate()
+            .filter_map(|(index, distance)| distance.map(|d| (NodeIndex::new(index), d)))
+    }
+}
+
+/// Shortest paths from `start` to every reachable node.
+pub fn djikstra_shortest_path_graph<T>(
+    graph: &Graph<T, usize>,
+    start: NodeIndex,
+) -> Result<ShortestPathTree, SearchError> {
+    djikstra_shortest_path_graph_observed(graph, start, &mut NoopObserver)
+}
+
+pub fn djikstra_shortest_path_graph_observed<T, O>(
+    graph: &Graph<T, usize>,
+    start: NodeIndex,
+    observer: &mut O,
+) -> Result<ShortestPathTree, SearchError>
+where
+    O: SearchObserver<NodeIndex>,
+{
+    validate_node(graph, start)?;
+
+    let mut distances = vec![None; graph.node_count()];
+    let mut predecessors = vec![None; graph.node_count()];
+    let mut frontier: BinaryHeap<Reverse<(usize, NodeIndex)>> = BinaryHeap::new();
+
+    distances[start.index()] = Some(0);
+    observer.on_push(&start);
+    frontier.push(Reverse((0, start)));
+    let mut complete = true;
+
+    while let Some(Reverse((current_cost, current))) = frontier.pop() {
+        if distances[current.index()].is_some_and(|best| current_cost > best) {
+            observer.on_discard(&current);
+            continue;
+        }
+
+        if observer.should_stop() {
+            complete = false;
+            break;
+        }
+
+        observer.on_expand(&current);
+
+        for edge in graph.edges(current) {
+            let next = edge.target();
+            let new_cost = current_cost.saturating_add(*edge.weight());
+
+            if distances[next.index()].is_none_or(|best| new_cost < best) {
+                distances[next.index()] = Some(new_cost);
+                predecessors[next.index()] = Some(current);
+                observer.on_push(&next);
+                frontier.push(Reverse((new_cost, next)));
+            }
+        }
+
+        observer.on_close(&current);
+    }
+
+    Ok(ShortestPathTree {
+        source: start,
+        distances,
+        predecessors,
+        complete,
+    })
+}
@@ -0,0 +1,151 @@
+use crate::search::observer::{NoopObserver, SearchObserver};
+use crate::search::outcome::{validate_grid, SearchError};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// Single-source shortest-path tree produced by `djikstra_shortest_path_grid`.
+///
+/// Costs follow `uniform_cost_search_grid`: entering a cell costs its weight and
+/// `usize::MAX` cells are impassable.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GridShortestPathTree {
+    source: (usize, usize),
+    cost_so_far: Vec<Vec<Option<usize>>>,
+    came_from: Vec<Vec<Option<(usize, usize)>>>,
+    complete: bool,
+}
+
+impl GridShortestPathTree {
+    pub fn source(&self) -> (usize, usize) {
+        self.source
+    }
+
+    /// Total cost from the source to `target`, or `None` if it is unreachable.
+    pub fn distance_to(&self, target: (usize, usize)) -> Option<usize> {
+        self.cost_so_far
+            .get(target.0)
+            .and_then(|row| row.get(target.1))
+            .copied()
+            .flatten()
+    }
+
+    /// The cell preceding `target` on its shortest path from the source.
+    pub fn predecessor(&self, target: (usize, usize)) -> Option<(usize, usize)> {
+        self.came_from
+            .get(target.0)
+            .and_then(|row| row.get(target.1))
+            .copied()
+            .flatten()
+    }
+
+    /// `false` if the observer stopped the search early, in which case cells
+    /// left on the frontier may have higher costs than their true ones.
+    pub fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    pub fn is_reachable(&self, target: (usize, usize)) -> bool {
+        self.distance_to(target).is_some()
+    }
+
+    /// Walks the predecessor tree back from `target`, returning the path from the source.
+    pub fn path_to(&self, target: (usize, usize)) -> Option<Vec<(usize, usize)>> {
+        if !self.is_reachable(target) {
+            return None;
+        }
+
+        let mut path = vec![target];
+        let mut current = target;
+        while let Some(parent) = self.predecessor(current) {
+            path.push(parent);
+            current = parent;
+        }
+
+        path.reverse();
+        Some(path)
+    }
+
+    /// The full cost matrix, with `None` marking unreachable cells.
+    pub fn distances(&self) -> &[Vec<Option<usize>>] {
+        &self.cost_so_far
+    }
+}
+
+/// Shortest paths from `start` to every reachable cell.
+pub fn djikstra_shortest_path_grid(
+    grid: &[Vec<usize>],
+    start: (usize, usize),
+) -> Result<GridShortestPathTree, SearchError> {
+    djikstra_shortest_path_grid_observed(grid, start, &mut NoopObserver)
+}
+
+pub fn djikstra_shortest_path_grid_observed<O>(
+    grid: &[Vec<usize>],
+    start: (usize, usize),
+    observer: &mut O,
+) -> Result<GridShortestPathTree, SearchError>
+where
+    O: SearchObserver<(usize, usize)>,
+{
+    validate_grid(grid, start)?;
+
+    let cols = grid.iter().map(Vec::len).max().unwrap_or(0);
+    let mut cost_so_far = vec![vec![None; cols]; grid.len()];
+    let mut came_from = vec![vec![None; cols]; grid.len()];
+    let mut frontier: BinaryHeap<Reverse<(usize, (usize, usize))>> = BinaryHeap::new();
+
+    observer.on_push(&start);
+    frontier.push(Reverse((0, start)));
+    cost_so_far[start.0][start.1] = Some(0);
+    let mut complete = true;
+
+    while let Some(Reverse((current_cost, (row, col)))) = frontier.pop() {
+        if cost_so_far[row][col].is_some_and(|best| current_cost > best) {
+            observer.on_discard(&(row, col));
+            continue;
+        }
+
+        if observer.should_stop() {
+            complete = false;
+            break;
+        }
+
+        observer.on_expand(&(row, col));
+
+        let neighbors = [
+            (row.wrapping_sub(1), col), // Up
+            (row + 1, col),             // Down
+            (row, col.wrapping_sub(1)), // Left
+            (row, col + 1),             // Right
+        ];
+
+        for &(next_row, next_col) in &neighbors {
+            // Rows may differ in length, so look each cell up in its own row.
+            let Some(&weight) = grid.get(next_row).and_then(|line| line.get(next_col)) else {
+                continue;
+            };
+
+            // Skip impassable cells
+            if weight == usize::MAX {
+                continue;
+            }
+
+            let new_cost = current_cost.saturating_add(weight);
+            if cost_so_far[next_row][next_col].is_none_or(|cost| new_cost < cost) {
+                cost_so_far[next_row][next_col] = Some(new_cost);
+                came_from[next_row][next_col] = Some((row, col));
+                observer.on_push(&(next_row, next_col));
+                frontier.push(Reverse((new_cost, (next_row, next_col))));
+            }
+        }
+
+        observer.on_close(&(row, col));
+    }
+
+    Ok(GridShortestPathTree {
+        source: start,
+        cost_so_far,
+        came_from,
+        complete,
+    })
+}
@@ -0,0 +1,286 @@
+use crate::pathfinding::grid_movement::Movement;
+use crate::search::outcome::{validate_grid, SearchError};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// Distances from every cell of a weighted grid to its nearest source, and the
+/// step to take from each cell to get there.
+///
+/// The grid follows `uniform_cost_search_grid`: entering a cell costs its
+/// weight, and `usize::MAX` marks an impassable cell. One field answers every
+/// agent on the grid; following `next_step` from any cell walks a cheapest path
+/// to the nearest source, at O(1) per step.
+///
+/// Sources can be added, removed and moved. Each change only recomputes the
+/// cells it affects: adding a source touches the cells it is now nearest to,
+/// and removing one the cells that flowed to it.
+#[derive(Debug, Clone)]
+pub struct FlowField {
+    grid: Vec<Vec<usize>>,
+    cols: usize,
+    movement: Movement,
+    sources: Vec<(usize, usize)>,
+    /// Cost from each cell to its nearest source, `usize::MAX` if none is
+    /// reachable.
+    distances: Vec<usize>,
+    /// The cell to step into from each cell. `None` at sources and at cells
+    /// that reach no source.
+    next: Vec<Option<usize>>,
+    /// The source each cell flows to.
+    nearest: Vec<Option<usize>>,
+    queue: BinaryHeap<Reverse<(usize, usize)>>,
+    moves: Vec<((usize, usize), usize)>,
+}
+
+impl FlowField {
+    /// A field over 4-connected moves, like `uniform_cost_search_grid`.
+    pub fn new(grid: &[Vec<usize>], sources: &[(usize, usize)]) -> Result<Self, SearchError> {
+        FlowField::with_movement(grid, sources, Movement::four_way())
+    }
+
+    /// A field over a configurable `movement` model, where entering a cell
+    /// costs its weight times the step cost.
+    ///
+    /// Fails if `grid` is empty or a source lies outside it. Sources on
+    /// impassable cells are kept but reach nothing.
+    pub fn with_movement(
+        grid: &[Vec<usize>],
+        sources: &[(usize, usize)],
+        movement: Movement,
+    ) -> Result<Self, SearchError> {
+        // `validate_grid` also rejects an empty grid, but only runs per source.
+        if sources.is_empty() && (grid.is_empty() || grid[0].is_empty()) {
+            return Err(SearchError::EmptyGrid);
+        }
+        for &source in sources {
+            validate_grid(grid, source)?;
+        }
+
+        let cols = grid.iter().map(Vec::len).max().unwrap_or(0);
+        let cells = grid.len() * cols;
+        let mut field = FlowField {
+            grid: grid.to_vec(),
+            cols,
+            movement,
+            sources: sources.to_vec(),
+            distances: vec![usize::MAX; cells],
+            next: vec![None; cells],
+            nearest: vec![None; cells],
+            queue: BinaryHeap::new(),
+            moves: Vec::new(),
+        };
+
+        for &source in sources {
+            field.seed(source)?;
+        }
+        field.propagate();
+        Ok(field)
+    }
+
+    /// The grid the field was built on.
+    pub fn grid(&self) -> &[Vec<usize>] {
+        &self.grid
+    }
+
+    pub fn sources(&self) -> &[(usize, usize)] {
+        &self.sources
+    }
+
+    /// Cost of the cheapest path from `position` to any source, or `None` if no
+    /// source is reachable.
+    pub fn distance(&self, position: (usize, usize)) -> Option<usize> {
+        self.index(position)
+            .map(|index| self.distances[index])
+            .filter(|&distance| distance != usize::MAX)
+    }
+
+    /// The neighboring cell to step into from `position`. `None` at a source or
+    /// where no source is reachable.
+    pub fn next_step(&self, position: (usize, usize)) -> Option<(usize, usize)> {
+        self.index(position)
+            .and_then(|index| self.next[index])
+            .map(|next| self.position(next))
+    }
+
+    /// `next_step` as a `(row, col)` offset, such as `(-1, 0)` for Up.
+    pub fn direction(&self, position: (usize, usize)) -> Option<(isize, isize)> {
+        self.next_step(position).map(|(row, col)| {
+            (
+                row as isize - position.0 as isize,
+                col as isize - position.1 as isize,
+            )
+        })
+    }
+
+    /// The source that `position` flows to.
+    pub fn nearest_source(&self, position: (usize, usize)) -> Option<(usize, usize)> {
+        self.index(position)
+            .and_then(|index| self.nearest[index])
+            .map(|source| self.position(source))
+    }
+
+    /// The cells visited by following the field from `position` to its nearest
+    /// source, both ends included.
+    pub fn path_from(&self, position: (usize, usize)) -> Option<Vec<(usize, usize)>> {
+        self.distance(position)?;
+
+        let mut path = vec![position];
+        while let Some(next) = self.next_step(*path.last().unwrap()) {
+            path.push(next);
+        }
+        Some(path)
+    }
+
+    /// Adds a source and updates the cells it is now nearest to.
+    pub fn add_source(&mut self, source: (usize, usize)) -> Result<(), SearchError> {
+        self.seed(source)?;
+        self.sources.push(source);
+        self.propagate();
+        Ok(())
+    }
+
+    /// Removes one occurrence of `source` and reroutes the cells that flowed to
+    /// it. Returns `false` if it was not a source.
+    pub fn remove_source(&mut self, source: (usize, usize)) -> bool {
+        let Some(slot) = self.sources.iter().position(|&s| s == source) else {
+            return false;
+        };
+        self.sources.swap_remove(slot);
+        if self.sources.contains(&source) {
+            return true;
+        }
+
+        // An impassable source never reached anything.
+        let Some(removed) = self
+            .index(source)
+            .filter(|&index| self.nearest[index] == Some(index))
+        else {
+            return true;
+        };
+
+        // Forget every cell that flowed to the removed source, walking the
+        // steps backwards from it...
+        let mut orphans = vec![removed];
+        let mut walked = 0;
+        while let Some(&orphan) = orphans.get(walked) {
+            walked += 1;
+            for neighbor in self.neighbors(self.position(orphan)) {
+                if self.next[neighbor] == Some(orphan) {
+                    orphans.push(neighbor);
+                }
+            }
+        }
+        for &index in &orphans {
+            self.distances[index] = usize::MAX;
+            self.next[index] = None;
+            self.nearest[index] = None;
+        }
+
+        // ...then let the cells bordering them flow back in.
+        for &index in &orphans {
+            for neighbor in self.neighbors(self.position(index)) {
+                let distance = self.distances[neighbor];
+                if distance != usize::MAX {
+                    self.queue.push(Reverse((distance, neighbor)));
+                }
+            }
+        }
+        self.propagate();
+        true
+    }
+
+    /// Moves a source from `from` to `to`, as `remove_source` followed by
+    /// `add_source`. If `from` is not a source, `to` is simply added.
+    pub fn move_source(
+        &mut self,
+        from: (usize, usize),
+        to: (usize, usize),
+    ) -> Result<(), SearchError> {
+        validate_grid(&self.grid, to)?;
+
+        self.remove_source(from);
+        self.add_source(to)
+    }
+
+    /// Queues `source` at distance zero, unless it is impassable. Fails if it
+    /// lies outside the grid.
+    fn seed(&mut self, (row, col): (usize, usize)) -> Result<(), SearchError> {
+        let index = self
+            .index((row, col))
+            .ok_or(SearchError::StartOutOfBounds { row, col })?;
+        if self.grid[row][col] == usize::MAX || self.distances[index] == 0 {
+            return Ok(());
+        }
+
+        self.distances[index] = 0;
+        self.next[index] = None;
+        self.nearest[index] = Some(index);
+        self.queue.push(Reverse((0, index)));
+        Ok(())
+    }
+
+    /// Dijkstra from every queued cell, lowering distances until none can drop.
+    fn propagate(&mut self) {
+        let mut moves = std::mem::take(&mut self.moves);
+
+        while let Some(Reverse((distance, current))) = self.queue.pop() {
+            if distance != self.distances[current] {
+                continue;
+            }
+
+            let position = self.position(current);
+            let weight = self.grid[position.0][position.1];
+
+            // Walk the moves backwards: every passable neighbor can step into
+            // `position`, paying its weight.
+            moves.clear();
+            self.movement.moves_into(
+                &self.grid,
+                position,
+                |&cell: &usize| (cell != usize::MAX).then_some(cell),
+                &mut moves,
+            );
+
+            for &(neighbor, _) in &moves {
+                let is_diagonal = neighbor.0 != position.0 && neighbor.1 != position.1;
+                let step = if is_diagonal {
+                    self.movement.diagonal_cost
+                } else {
+                    self.movement.cardinal_cost
+                };
+                let tentative = distance.saturating_add(weight.saturating_mul(step));
+
+                let index = neighbor.0 * self.cols + neighbor.1;
+                if tentative < self.distances[index] {
+                    self.distances[index] = tentative;
+                    self.next[index] = Some(current);
+                    self.nearest[index] = self.nearest[current];
+                    self.queue.push(Reverse((tentative, index)));
+                }
+            }
+        }
+
+        self.moves = moves;
+    }
+
+    /// Cells that `position` could step into, or be stepped into from.
+    fn neighbors(&self, (row, col): (usize, usize)) -> Vec<usize> {
+        let mut neighbors = Vec::new();
+        for next_row in row.saturating_sub(1)..=row + 1 {
+            for next_col in col.saturating_sub(1)..=col + 1 {
+                if (next_row, next_col) != (row, col) {
+                    neighbors.extend(self.index((next_row, next_col)));
+                }
+            }
+        }
+        neighbors
+    }
+
+    fn index(&self, (row, col): (usize, usize)) -> Option<usize> {
+        (row < self.grid.len() && col < self.grid[row].len()).then_some(row * self.cols + col)
+    }
+
+    fn position(&self, index: usize) -> (usize, usize) {
+        (index / self.cols, index % self.cols)
+    }
+}
@@ -0,0 +1,93 @@
+use crate::pathfinding::a_star::{NodeCost, Priority, TieBreak};
+use crate::pathfinding::cost::Cost;
+use crate::search::budget::BestSoFar;
+use crate::search::observer::{NoopObserver, SearchObserver};
+use crate::search::outcome::SearchOutcome;
+use crate::search::search_problem::{reconstruct_path, SearchProblem};
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+/// Greedy best-first search over any `SearchProblem`: always expands the state
+/// with the lowest `SearchProblem::heuristic`, ignoring the cost so far.
+///
+/// Fast but not optimal. Among states with equal estimates the one reached last
+/// is expanded first, which keeps the search heading the way it was going. The
+/// outcome's cost is that of the path found.
+pub fn greedy_best_first_search<P: SearchProblem>(problem: &P) -> SearchOutcome<P::State, P::Cost> {
+    greedy_best_first_search_observed(problem, &mut NoopObserver)
+}
+
+pub fn greedy_best_first_search_observed<P, O>(
+    problem: &P,
+    observer: &mut O,
+) -> SearchOutcome<P::State, P::Cost>
+where
+    P: SearchProblem,
+    O: SearchObserver<P::State>,
+{
+    let mut visited = Vec::new();
+    let mut open_set = BinaryHeap::new();
+    let mut discovered = HashSet::new();
+    let mut came_from = HashMap::new();
+    let mut pushed = 0;
+    let mut priority = |estimate: P::Cost| {
+        pushed += 1;
+        Priority::new(P::Cost::ZERO, estimate, pushed, TieBreak::Lifo)
+    };
+
+    let start = problem.initial_state();
+    let estimate = problem.heuristic(&start);
+    let mut best = BestSoFar::new(start.clone(), estimate);
+    discovered.insert(start.clone());
+    observer.on_push(&start);
+    open_set.push(NodeCost {
+        state: start,
+        cost: P::Cost::ZERO,
+        priority: priority(estimate),
+    });
+
+    while let Some(NodeCost {
+        state,
+        cost,
+        priority: entry,
+    }) = open_set.pop()
+    {
+        if observer.should_stop() {
+            return SearchOutcome::BudgetExhausted {
+                best_path: reconstruct_path(&came_from, best.into_state()),
+                visited,
+            };
+        }
+
+        observer.on_expand(&state);
+        visited.push(state.clone());
+        best.offer(&state, entry.f());
+
+        if problem.is_goal(&state) {
+            let path = reconstruct_path(&came_from, state.clone());
+            observer.on_path_found(&path);
+            return SearchOutcome::Found {
+                goal: state,
+                path,
+                cost,
+                visited,
+            };
+        }
+
+        for (next, step_cost) in problem.successors(&state) {
+            if discovered.insert(next.clone()) {
+                came_from.insert(next.clone(), state.clone());
+                observer.on_push(&next);
+                let estimate = problem.heuristic(&next);
+                open_set.push(NodeCost {
+                    state: next,
+                    cost: cost.saturating_add(step_cost),
+                    priority: priority(estimate),
+                });
+            }
+        }
+
+        observer.on_close(&state);
+    }
+
+    SearchOutcome::Exhausted { visited } // Goal not reachable
+}
@@ -0,0 +1,92 @@
+use crate::pathfinding::greedy_best_first_search::greedy_best_first_search_observed;
+use crate::pathfinding::grid_movement::{GridHeuristic, Movement};
+use crate::search::observer::{NoopObserver, SearchObserver};
+use crate::search::outcome::{validate_grid, SearchError, SearchOutcome};
+use crate::search::search_problem::GridProblem;
+
+/// Greedy best-first search over an obstacle grid (`true` is a wall) with
+/// 4-connected moves that each cost 1.
+///
+/// Always expands the cell with the lowest heuristic, so it is fast but not
+/// optimal. The outcome carries the path found and every cell expanded along
+/// the way.
+pub fn greedy_best_first_search_grid<H>(
+    grid: &[Vec<bool>],
+    start: (usize, usize),
+    goal: (usize, usize),
+    heuristic: H,
+) -> Result<SearchOutcome<(usize, usize)>, SearchError>
+where
+    H: GridHeuristic,
+{
+    greedy_best_first_search_grid_observed(grid, start, goal, heuristic, &mut NoopObserver)
+}
+
+pub fn greedy_best_first_search_grid_observed<H, O>(
+    grid: &[Vec<bool>],
+    start: (usize, usize),
+    goal: (usize, usize),
+    heuristic: H,
+    observer: &mut O,
+) -> Result<SearchOutcome<(usize, usize)>, SearchError>
+where
+    H: GridHeuristic,
+    O: SearchObserver<(usize, usize)>,
+{
+    greedy_best_first_search_grid_with_observed(
+        grid,
+        start,
+        goal,
+        Movement::four_way(),
+        heuristic,
+        observer,
+    )
+}
+
+/// Greedy best-first search over an obstacle grid with a configurable
+/// `movement` model, like `a_star_grid_with`.
+pub fn greedy_best_first_search_grid_with<H>(
+    grid: &[Vec<bool>],
+    start: (usize, usize),
+    goal: (usize, usize),
+    movement: Movement,
+    heuristic: H,
+) -> Result<SearchOutcome<(usize, usize)>, SearchError>
+where
+    H: GridHeuristic,
+{
+    greedy_best_first_search_grid_with_observed(
+        grid,
+        start,
+        goal,
+        movement,
+        heuristic,
+        &mut NoopObserver,
+    )
+}
+
+pub fn greedy_best_first_search_grid_with_observed<H, O>(
+    grid: &[Vec<bool>],
+    start: (usize, usize),
+    goal: (usize, usize),
+    movement: Movement,
+    heuristic: H,
+    observer: &mut O,
+) -> Result<SearchOutcome<(usize, usize)>, SearchError>
+where
+    H: GridHeuristic,
+    O: SearchObserver<(usize, usize)>,
+{
+    validate_grid(grid, start)?;
+
+    let problem = GridProblem {
+        grid,
+        start,
+        movement,
+        step_cost: |&wall: &bool| (!wall).then_some(1),
+        is_goal: |position: (usize, usize)| position == goal,
+        heuristic: |position: (usize, usize)| heuristic.estimate(position, goal, &movement),
+    };
+
+    Ok(greedy_best_first_search_observed(&problem, observer))
+}
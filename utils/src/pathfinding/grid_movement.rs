@@ -0,0 +1,194 @@
+/// Which neighboring cells a single step can reach.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Neighborhood {
+    /// Up, Down, Left and Right.
+    FourWay,
+    /// The four cardinal moves plus the four diagonals. Without `corner_cutting`
+    /// a diagonal step is only allowed when both cells it passes between are free.
+    EightWay { corner_cutting: bool },
+}
+
+/// A neighborhood together with the base cost of straight and diagonal steps.
+///
+/// Costs are integers, so the eight-way models scale them: a straight step costs
+/// `STRAIGHT_COST` and a diagonal step `DIAGONAL_COST`, roughly `sqrt(2)` times as
+/// much. Entering a cell multiplies the step cost by that cell's own cost.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Movement {
+    pub neighborhood: Neighborhood,
+    pub cardinal_cost: usize,
+    pub diagonal_cost: usize,
+}
+
+impl Movement {
+    pub const STRAIGHT_COST: usize = 1000;
+    pub const DIAGONAL_COST: usize = 1414;
+
+    /// 4-connected moves that each cost 1.
+    pub const fn four_way() -> Self {
+        Movement {
+            neighborhood: Neighborhood::FourWay,
+            cardinal_cost: 1,
+            diagonal_cost: 1,
+        }
+    }
+
+    /// 8-connected moves that never squeeze diagonally past a wall.
+    pub const fn eight_way() -> Self {
+        Movement {
+            neighborhood: Neighborhood::EightWay {
+                corner_cutting: false,
+            },
+            cardinal_cost: Self::STRAIGHT_COST,
+            diagonal_cost: Self::DIAGONAL_COST,
+        }
+    }
+
+    /// 8-connected moves that may pass diagonally between two walls.
+    pub const fn eight_way_with_corner_cutting() -> Self {
+        Movement {
+            neighborhood: Neighborhood::EightWay {
+                corner_cutting: true,
+            },
+            cardinal_cost: Self::STRAIGHT_COST,
+            diagonal_cost: Self::DIAGONAL_COST,
+        }
+    }
+
+    /// Replaces the cost of a diagonal step.
+    pub const fn with_diagonal_cost(mut self, diagonal_cost: usize) -> Self {
+        self.diagonal_cost = diagonal_cost;
+        self
+    }
+
+    /// Every cell reachable from `position` in one step, with the cost of entering it.
+    ///
+    /// `step_cost` gives the cost of entering a cell, or `None` if it is impassable.
+    /// Cardinal moves come first, in Up, Down, Left, Right order, followed by the
+    /// diagonals.
+    pub fn moves<T>(
+        &self,
+        grid: &[Vec<T>],
+        position: (usize, usize),
+        step_cost: impl Fn(&T) -> Option<usize>,
+    ) -> Vec<((usize, usize), usize)> {
+        let mut moves = Vec::new();
+        self.moves_into(grid, position, step_cost, &mut moves);
+        moves
+    }
+
+    /// `moves` that appends to a caller-owned buffer instead of allocating.
+    pub(crate) fn moves_into<T>(
+        &self,
+        grid: &[Vec<T>],
+        (row, col): (usize, usize),
+        step_cost: impl Fn(&T) -> Option<usize>,
+        moves: &mut Vec<((usize, usize), usize)>,
+    ) {
+        let cell_cost = |(next_row, next_col): (usize, usize)| {
+            grid.get(next_row)
+                .and_then(|line| line.get(next_col))
+                .and_then(&step_cost)
+        };
+
+        let cardinals = [
+            (row.wrapping_sub(1), col), // Up
+            (row + 1, col),             // Down
+            (row, col.wrapping_sub(1)), // Left
+            (row, col + 1),             // Right
+        ];
+
+        moves.extend(cardinals.into_iter().filter_map(|next| {
+            cell_cost(next).map(|cost| (next, cost.saturating_mul(self.cardinal_cost)))
+        }));
+
+        if let Neighborhood::EightWay { corner_cutting } = self.neighborhood {
+            let diagonals = [
+                (row.wrapping_sub(1), col.wrapping_sub(1)), // Up-Left
+                (row.wrapping_sub(1), col + 1),             // Up-Right
+                (row + 1, col.wrapping_sub(1)),             // Down-Left
+                (row + 1, col + 1),                         // Down-Right
+            ];
+
+            for next in diagonals {
+                // The two cells the diagonal passes between.
+                let passes_walls =
+                    cell_cost((next.0, col)).is_none() || cell_cost((row, next.1)).is_none();
+                if passes_walls && !corner_cutting {
+                    continue;
+                }
+
+                if let Some(cost) = cell_cost(next) {
+                    moves.push((next, cost.saturating_mul(self.diagonal_cost)));
+                }
+            }
+        }
+    }
+}
+
+impl Default for Movement {
+    fn default() -> Self {
+        Self::four_way()
+    }
+}
+
+/// Estimates the remaining cost between two cells under a given `Movement`.
+///
+/// Implemented for `DistanceHeuristic` and for any closure of the form
+/// `Fn((usize, usize), (usize, usize)) -> usize`, which ignores the movement.
+pub trait GridHeuristic {
+    fn estimate(&self, from: (usize, usize), to: (usize, usize), movement: &Movement) -> usize;
+}
+
+/// The standard distance metrics, scaled by the movement's step costs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DistanceHeuristic {
+    /// Exact on open 4-connected grids; overestimates when diagonals are allowed.
+    Manhattan,
+    /// Straight-line distance, priced at the cheaper of a straight step and a
+    /// diagonal step per unit length. Never overestimates, but is the least
+    /// informed.
+    Euclidean,
+    /// Exact on open 8-connected grids where diagonals cost the same as straight
+    /// steps. Priced at the cheaper of the two, so it never overestimates.
+    Chebyshev,
+    /// Exact on open 8-connected grids with the movement's diagonal cost.
+    Octile,
+}
+
+impl GridHeuristic for DistanceHeuristic {
+    fn estimate(&self, from: (usize, usize), to: (usize, usize), movement: &Movement) -> usize {
+        let dx = from.0.abs_diff(to.0);
+        let dy = from.1.abs_diff(to.1);
+        let (short, long) = (dx.min(dy), dx.max(dy));
+        // A diagonal never costs more than the two straight steps it replaces.
+        let diagonal_cost = match movement.neighborhood {
+            Neighborhood::FourWay => 2 * movement.cardinal_cost,
+            Neighborhood::EightWay { .. } => movement.diagonal_cost.min(2 * movement.cardinal_cost),
+        };
+
+        match self {
+            DistanceHeuristic::Manhattan => (dx + dy) * movement.cardinal_cost,
+            DistanceHeuristic::Euclidean => {
+                let distance = ((dx * dx + dy * dy) as f64).sqrt();
+                let unit_cost = (movement.cardinal_cost as f64)
+                    .min(diagonal_cost as f64 / std::f64::consts::SQRT_2);
+                (distance * unit_cost) as usize
+            }
+            DistanceHeuristic::Chebyshev => long * movement.cardinal_cost.min(diagonal_cost),
+            DistanceHeuristic::Octile => {
+                (long - short) * movement.cardinal_cost
+                    + short * movement.diagonal_cost.min(2 * movement.cardinal_cost)
+            }
+        }
+    }
+}
+
+impl<F> GridHeuristic for F
+where
+    F: Fn((usize, usize), (usize, usize)) -> usize,
+{
+    fn estimate(&self, from: (usize, usize), to: (usize, usize), _movement: &Movement) -> usize {
+        self(from, to)
+    }
+}
@@ -0,0 +1,144 @@
+use crate::pathfinding::cost::{is_better, Cost};
+use crate::search::budget::BestSoFar;
+use crate::search::observer::{NoopObserver, SearchObserver};
+use crate::search::outcome::SearchOutcome;
+use crate::search::search_problem::SearchProblem;
+
+/// Iterative deepening A* over any `SearchProblem`, guided by
+/// `SearchProblem::heuristic`.
+///
+/// Runs depth-first searches that prune every state whose `f = g + h` exceeds a
+/// threshold, starting at the initial state's heuristic and raising it to the
+/// smallest pruned `f` after each pass. Memory grows only with the path length,
+/// at the price of expanding states again on every pass, so it suits problems
+/// whose A* frontier would not fit in memory. The path is optimal whenever the
+/// heuristic never overestimates. `visited` holds the expansion order of the
+/// last pass only; observe the search to see every pass.
+///
+/// Without a reachable goal the search only ends once every acyclic path has
+/// been pruned or explored, which on large spaces may never happen.
+pub fn ida_star<P: SearchProblem>(problem: &P) -> SearchOutcome<P::State, P::Cost> {
+    ida_star_observed(problem, &mut NoopObserver)
+}
+
+/// States are reported again on every pass that reaches them.
+pub fn ida_star_observed<P, O>(problem: &P, observer: &mut O) -> SearchOutcome<P::State, P::Cost>
+where
+    P: SearchProblem,
+    O: SearchObserver<P::State>,
+{
+    let start = problem.initial_state();
+    let estimate = problem.heuristic(&start);
+    let mut pass = Pass {
+        problem,
+        observer,
+        bound: estimate,
+        path: Vec::new(),
+        visited: Vec::new(),
+        best: BestSoFar::new(start.clone(), estimate),
+        best_path: vec![start.clone()],
+    };
+
+    loop {
+        pass.path.clear();
+        pass.visited.clear();
+        pass.observer.on_push(&start);
+
+        match pass.search(start.clone(), P::Cost::ZERO) {
+            Bounded::Found(cost) => {
+                pass.observer.on_path_found(&pass.path);
+                return SearchOutcome::Found {
+                    goal: pass.path.last().unwrap().clone(),
+                    path: pass.path,
+                    cost,
+                    visited: pass.visited,
+                };
+            }
+            Bounded::Stopped => {
+                return SearchOutcome::BudgetExhausted {
+                    best_path: pass.best_path,
+                    visited: pass.visited,
+                };
+            }
+            Bounded::Pruned(Some(next_bound)) => pass.bound = next_bound,
+            Bounded::Pruned(None) => {
+                return SearchOutcome::Exhausted {
+                    visited: pass.visited,
+                };
+            }
+        }
+    }
+}
+
+/// One cost-limited depth-first pass, along with what carries over between
+/// passes. `path` and `visited` start over on every pass.
+struct Pass<'a, P: SearchProblem, O> {
+    problem: &'a P,
+    observer: &'a mut O,
+    bound: P::Cost,
+    path: Vec<P::State>,
+    visited: Vec<P::State>,
+    best: BestSoFar<P::State, P::Cost>,
+    best_path: Vec<P::State>,
+}
+
+/// How a cost-limited search ended.
+enum Bounded<C> {
+    /// A goal was reached at this cost; the pass's `path` leads to it.
+    Found(C),
+    /// Nothing found within the bound. Holds the smallest `f` that was pruned,
+    /// if any was.
+    Pruned(Option<C>),
+    Stopped,
+}
+
+impl<P, O> Pass<'_, P, O>
+where
+    P: SearchProblem,
+    O: SearchObserver<P::State>,
+{
+    fn search(&mut self, state: P::State, cost: P::Cost) -> Bounded<P::Cost> {
+        let estimate = self.problem.heuristic(&state);
+        let f_score = cost.saturating_add(estimate);
+        if f_score.total_cmp(&self.bound).is_gt() {
+            self.observer.on_discard(&state);
+            return Bounded::Pruned(Some(f_score));
+        }
+
+        if self.observer.should_stop() {
+            return Bounded::Stopped;
+        }
+
+        self.observer.on_expand(&state);
+        self.visited.push(state.clone());
+        self.path.push(state.clone());
+        if self.best.offer(&state, estimate) {
+            self.best_path.clone_from(&self.path);
+        }
+
+        if self.problem.is_goal(&state) {
+            return Bounded::Found(cost);
+        }
+
+        let mut next_bound = None;
+        for (next, step_cost) in self.problem.successors(&state) {
+            // Never walk back into the current path.
+            if self.path.contains(&next) {
+                continue;
+            }
+
+            self.observer.on_push(&next);
+            match self.search(next, cost.saturating_add(step_cost)) {
+                Bounded::Pruned(Some(f_score)) if is_better(f_score, next_bound.as_ref()) => {
+                    next_bound = Some(f_score);
+                }
+                Bounded::Pruned(_) => {}
+                found_or_stopped => return found_or_stopped,
+            }
+        }
+
+        self.observer.on_close(&state);
+        self.path.pop();
+        Bounded::Pruned(next_bound)
+    }
+}
@@ -0,0 +1,51 @@
+use crate::pathfinding::cost::Cost;
+use crate::pathfinding::ida_star::ida_star_observed;
+use crate::search::observer::{NoopObserver, SearchObserver};
+use crate::search::outcome::{validate_node, ContainsNode, SearchError, SearchOutcome};
+use crate::search::search_problem::GraphProblem;
+use petgraph::visit::IntoEdges;
+use std::hash::Hash;
+
+/// IDA* over any petgraph graph or view that implements `IntoEdges`, taking the
+/// same heuristic closures as `astar_graph`. Edge weights are the step costs.
+pub fn ida_star_graph<G, F>(
+    graph: G,
+    start: G::NodeId,
+    goal: G::NodeId,
+    heuristic: F,
+) -> Result<SearchOutcome<G::NodeId, G::EdgeWeight>, SearchError>
+where
+    G: IntoEdges + ContainsNode,
+    G::NodeId: Eq + Hash,
+    G::EdgeWeight: Cost,
+    F: Fn(G::NodeId, G::NodeId) -> G::EdgeWeight,
+{
+    ida_star_graph_observed(graph, start, goal, heuristic, &mut NoopObserver)
+}
+
+pub fn ida_star_graph_observed<G, F, O>(
+    graph: G,
+    start: G::NodeId,
+    goal: G::NodeId,
+    heuristic: F,
+    observer: &mut O,
+) -> Result<SearchOutcome<G::NodeId, G::EdgeWeight>, SearchError>
+where
+    G: IntoEdges + ContainsNode,
+    G::NodeId: Eq + Hash,
+    G::EdgeWeight: Cost,
+    F: Fn(G::NodeId, G::NodeId) -> G::EdgeWeight,
+    O: SearchObserver<G::NodeId>,
+{
+    validate_node(graph, start)?;
+
+    let problem = GraphProblem {
+        graph,
+        start,
+        edge_cost: |weight: &G::EdgeWeight| *weight,
+        is_goal: |node: G::NodeId| node == goal,
+        heuristic: |node: G::NodeId| heuristic(node, goal),
+    };
+
+    Ok(ida_star_observed(&problem, observer))
+}
@@ -0,0 +1,93 @@
+use crate::pathfinding::grid_movement::{DistanceHeuristic, GridHeuristic, Movement};
+use crate::pathfinding::ida_star::ida_star_observed;
+use crate::search::observer::{NoopObserver, SearchObserver};
+use crate::search::outcome::{validate_grid, SearchError, SearchOutcome};
+use crate::search::search_problem::GridProblem;
+
+/// IDA* over an obstacle grid (`true` is a wall) with 4-connected moves that
+/// each cost 1, like `a_star_grid`.
+///
+/// Open areas hold many equally short paths, and IDA* walks each of them, so
+/// prefer `a_star_grid` unless memory is the constraint. A goal outside the
+/// grid or on a wall ends `Exhausted` at once, but a goal walled in by other
+/// cells is only given up on after every acyclic path around the start has
+/// been walked, which takes exponential time in open areas. Where that can
+/// happen, check the goal is reachable first, for instance with
+/// `bidirectional_search_grid`, or bound the search with a `SearchBudget`.
+pub fn ida_star_grid(
+    grid: &[Vec<bool>],
+    start: (usize, usize),
+    goal: (usize, usize),
+) -> Result<SearchOutcome<(usize, usize)>, SearchError> {
+    ida_star_grid_observed(grid, start, goal, &mut NoopObserver)
+}
+
+pub fn ida_star_grid_observed<O>(
+    grid: &[Vec<bool>],
+    start: (usize, usize),
+    goal: (usize, usize),
+    observer: &mut O,
+) -> Result<SearchOutcome<(usize, usize)>, SearchError>
+where
+    O: SearchObserver<(usize, usize)>,
+{
+    ida_star_grid_with_observed(
+        grid,
+        start,
+        goal,
+        Movement::four_way(),
+        DistanceHeuristic::Manhattan,
+        observer,
+    )
+}
+
+/// IDA* over an obstacle grid with a configurable `movement` model and
+/// `heuristic`, like `a_star_grid_with`.
+pub fn ida_star_grid_with<H>(
+    grid: &[Vec<bool>],
+    start: (usize, usize),
+    goal: (usize, usize),
+    movement: Movement,
+    heuristic: H,
+) -> Result<SearchOutcome<(usize, usize)>, SearchError>
+where
+    H: GridHeuristic,
+{
+    ida_star_grid_with_observed(grid, start, goal, movement, heuristic, &mut NoopObserver)
+}
+
+pub fn ida_star_grid_with_observed<H, O>(
+    grid: &[Vec<bool>],
+    start: (usize, usize),
+    goal: (usize, usize),
+    movement: Movement,
+    heuristic: H,
+    observer: &mut O,
+) -> Result<SearchOutcome<(usize, usize)>, SearchError>
+where
+    H: GridHeuristic,
+    O: SearchObserver<(usize, usize)>,
+{
+    validate_grid(grid, start)?;
+
+    let problem = GridProblem {
+        grid,
+        start,
+        movement,
+        step_cost: |&wall: &bool| (!wall).then_some(1),
+        is_goal: |position: (usize, usize)| position == goal,
+        heuristic: |position: (usize, usize)| heuristic.estimate(position, goal, &movement),
+    };
+
+    let goal_is_free = grid
+        .get(goal.0)
+        .and_then(|line| line.get(goal.1))
+        .is_some_and(|&wall| !wall);
+    if !goal_is_free && start != goal {
+        return Ok(SearchOutcome::Exhausted {
+            visited: Vec::new(),
+        });
+    }
+
+    Ok(ida_star_observed(&problem, observer))
+}
@@ -0,0 +1,431 @@
+use crate::pathfinding::a_star::{NodeCost, Priority, TieBreak};
+use crate::pathfinding::grid_movement::{DistanceHeuristic, GridHeuristic, Movement};
+use crate::search::budget::BestSoFar;
+use crate::search::observer::{NoopObserver, SearchObserver};
+use crate::search::outcome::{validate_grid, SearchError, SearchOutcome};
+use crate::search::search_problem::reconstruct_path;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+
+type Position = (usize, usize);
+type Direction = (isize, isize);
+
+/// The cardinals in Up, Down, Left, Right order, then the diagonals, as in
+/// `Movement::moves`.
+const DIRECTIONS: [Direction; 8] = [
+    (-1, 0),
+    (1, 0),
+    (0, -1),
+    (0, 1),
+    (-1, -1),
+    (-1, 1),
+    (1, -1),
+    (1, 1),
+];
+
+/// Jump Point Search over an obstacle grid (`true` is a wall).
+///
+/// Moves like `Movement::eight_way()`, so the path costs the same as
+/// `a_star_grid_with` under that movement, but long runs of symmetric cells are
+/// skipped instead of expanded. The outcome's path and visited states hold only
+/// jump points; `expand_jump_points` fills in the cells between them.
+pub fn jump_point_search_grid(
+    grid: &[Vec<bool>],
+    start: (usize, usize),
+    goal: (usize, usize),
+) -> Result<SearchOutcome<(usize, usize)>, SearchError> {
+    jump_point_search_grid_observed(grid, start, goal, &mut NoopObserver)
+}
+
+pub fn jump_point_search_grid_observed<O>(
+    grid: &[Vec<bool>],
+    start: (usize, usize),
+    goal: (usize, usize),
+    observer: &mut O,
+) -> Result<SearchOutcome<(usize, usize)>, SearchError>
+where
+    O: SearchObserver<(usize, usize)>,
+{
+    validate_grid(grid, start)?;
+
+    Ok(search(&GridJumps { grid }, start, goal, observer))
+}
+
+/// Turns a path of jump points into the full cell-by-cell path.
+///
+/// Consecutive jump points always lie on a straight or diagonal line. For any
+/// other pair the walk goes diagonally first, then straight.
+pub fn expand_jump_points(jump_points: &[(usize, usize)]) -> Vec<(usize, usize)> {
+    let toward = |from: usize, to: usize| match from.cmp(&to) {
+        Ordering::Less => from + 1,
+        Ordering::Equal => from,
+        Ordering::Greater => from - 1,
+    };
+
+    let mut path: Vec<_> = jump_points.first().copied().into_iter().collect();
+    for pair in jump_points.windows(2) {
+        let (mut current, to) = (pair[0], pair[1]);
+        while current != to {
+            current = (toward(current.0, to.0), toward(current.1, to.1));
+            path.push(current);
+        }
+    }
+    path
+}
+
+/// Precomputed jump distances for JPS+, for running many searches on a map that
+/// does not change.
+///
+/// For every cell and direction the table stores how far the next jump point
+/// lies, or how far the next wall does, so a search reads each jump instead of
+/// walking it. Rebuild the table whenever the grid changes.
+#[derive(Debug, Clone)]
+pub struct JumpPointTable {
+    rows: usize,
+    cols: usize,
+    open: Vec<bool>,
+    /// Per cell and direction: a jump point that many steps away if positive,
+    /// otherwise minus the number of free steps before a wall or the edge.
+    distances: Vec<[isize; 8]>,
+}
+
+impl JumpPointTable {
+    pub fn new(grid: &[Vec<bool>]) -> Self {
+        let cells = GridJumps { grid };
+        let rows = grid.len();
+        let cols = grid.iter().map(Vec::len).max().unwrap_or(0);
+        let open = (0..rows * cols)
+            .map(|index| cells.is_open((index / cols, index % cols)))
+            .collect();
+        let mut distances = vec![[0; 8]; rows * cols];
+
+        // Cardinals come first, so diagonals can look up the straight jumps.
+        for (slot, &(dr, dc)) in DIRECTIONS.iter().enumerate() {
+            // Fill in the next cell along the direction before the current one.
+            let row_order: Vec<_> = match dr {
+                1 => (0..rows).rev().collect(),
+                _ => (0..rows).collect(),
+            };
+            let col_order: Vec<_> = match dc {
+                1 => (0..cols).rev().collect(),
+                _ => (0..cols).collect(),
+            };
+
+            for &row in &row_order {
+                for &col in &col_order {
+                    let position = (row, col);
+                    if !cells.can_step(position, (dr, dc)) {
+                        continue; // Blocked right away, so the distance stays 0.
+                    }
+
+                    let next = offset(position, (dr, dc), 1).unwrap();
+                    let is_jump_point = if dr != 0 && dc != 0 {
+                        let straight =
+                            |direction| distances[next.0 * cols + next.1][slot_of(direction)];
+                        straight((dr, 0)) > 0 || straight((0, dc)) > 0
+                    } else {
+                        cells.is_forced(next, (dr, dc))
+                    };
+
+                    let ahead = distances[next.0 * cols + next.1][slot];
+                    distances[row * cols + col][slot] = if is_jump_point {
+                        1
+                    } else if ahead > 0 {
+                        ahead + 1
+                    } else {
+                        ahead - 1
+                    };
+                }
+            }
+        }
+
+        JumpPointTable {
+            rows,
+            cols,
+            open,
+            distances,
+        }
+    }
+
+    /// `jump_point_search_grid` on the grid this table was built from.
+    pub fn search(
+        &self,
+        start: (usize, usize),
+        goal: (usize, usize),
+    ) -> Result<SearchOutcome<(usize, usize)>, SearchError> {
+        self.search_observed(start, goal, &mut NoopObserver)
+    }
+
+    pub fn search_observed<O>(
+        &self,
+        start: (usize, usize),
+        goal: (usize, usize),
+        observer: &mut O,
+    ) -> Result<SearchOutcome<(usize, usize)>, SearchError>
+    where
+        O: SearchObserver<(usize, usize)>,
+    {
+        if self.rows == 0 || self.cols == 0 {
+            return Err(SearchError::EmptyGrid);
+        }
+
+        let (row, col) = start;
+        if row >= self.rows || col >= self.cols {
+            return Err(SearchError::StartOutOfBounds { row, col });
+        }
+
+        Ok(search(self, start, goal, observer))
+    }
+}
+
+/// How a search finds the next jump point, the one part JPS and JPS+ do
+/// differently.
+trait Jumps {
+    fn is_open(&self, position: Position) -> bool;
+
+    /// The first jump point reached from `from` by moving in `direction`, if
+    /// any. The goal always counts as a jump point.
+    fn jump(&self, from: Position, direction: Direction, goal: Position) -> Option<Position>;
+
+    /// Whether a single step in `direction` is allowed, never squeezing
+    /// diagonally past a wall.
+    fn can_step(&self, position: Position, (dr, dc): Direction) -> bool {
+        let free = |direction| offset(position, direction, 1).is_some_and(|p| self.is_open(p));
+        free((dr, dc)) && (dr == 0 || dc == 0 || (free((dr, 0)) && free((0, dc))))
+    }
+}
+
+/// Plain JPS, walking the grid for every jump.
+struct GridJumps<'a> {
+    grid: &'a [Vec<bool>],
+}
+
+impl GridJumps<'_> {
+    /// Whether a straight move into `position` has a forced neighbor: a free
+    /// cell beside it whose cell behind was a wall.
+    fn is_forced(&self, position: Position, (dr, dc): Direction) -> bool {
+        [(dc, dr), (-dc, -dr)].into_iter().any(|side| {
+            offset(position, side, 1).is_some_and(|beside| {
+                self.is_open(beside)
+                    && !offset(beside, (-dr, -dc), 1).is_some_and(|behind| self.is_open(behind))
+            })
+        })
+    }
+}
+
+impl Jumps for GridJumps<'_> {
+    fn is_open(&self, (row, col): Position) -> bool {
+        self.grid.get(row).and_then(|line| line.get(col)) == Some(&false)
+    }
+
+    fn jump(&self, from: Position, (dr, dc): Direction, goal: Position) -> Option<Position> {
+        let mut current = from;
+        loop {
+            current = offset(current, (dr, dc), 1).filter(|&next| self.is_open(next))?;
+            if current == goal {
+                return Some(current);
+            }
+
+            if dr != 0 && dc != 0 {
+                if self.jump(current, (dr, 0), goal).is_some()
+                    || self.jump(current, (0, dc), goal).is_some()
+                {
+                    return Some(current);
+                }
+
+                if !self.can_step(current, (dr, dc)) {
+                    return None;
+                }
+            } else if self.is_forced(current, (dr, dc)) {
+                return Some(current);
+            }
+        }
+    }
+}
+
+impl Jumps for JumpPointTable {
+    fn is_open(&self, (row, col): Position) -> bool {
+        row < self.rows && col < self.cols && self.open[row * self.cols + col]
+    }
+
+    fn jump(&self, from: Position, (dr, dc): Direction, goal: Position) -> Option<Position> {
+        let distance = self.distances[from.0 * self.cols + from.1][slot_of((dr, dc))];
+        let reach = distance.unsigned_abs();
+        let to_goal = (
+            goal.0 as isize - from.0 as isize,
+            goal.1 as isize - from.1 as isize,
+        );
+
+        if dr != 0 && dc != 0 {
+            // Stop where the diagonal lines up with the goal, so the straight
+            // jumps from there can reach it.
+            if to_goal.0.signum() == dr && to_goal.1.signum() == dc {
+                let steps = to_goal.0.unsigned_abs().min(to_goal.1.unsigned_abs());
+                if steps <= reach {
+                    return offset(from, (dr, dc), steps);
+                }
+            }
+        } else if (to_goal.0.signum(), to_goal.1.signum()) == (dr, dc) {
+            let steps = to_goal.0.unsigned_abs().max(to_goal.1.unsigned_abs());
+            if steps <= reach {
+                return Some(goal);
+            }
+        }
+
+        (distance > 0)
+            .then(|| offset(from, (dr, dc), reach))
+            .flatten()
+    }
+}
+
+/// A* over jump points. Successors come from the directions left after pruning
+/// by the direction the search arrived from.
+fn search<J, O>(
+    jumps: &J,
+    start: Position,
+    goal: Position,
+    observer: &mut O,
+) -> SearchOutcome<Position>
+where
+    J: Jumps,
+    O: SearchObserver<Position>,
+{
+    let movement = Movement::eight_way();
+    // Exact between two cells on a shared straight or diagonal line.
+    let octile = |from, to| DistanceHeuristic::Octile.estimate(from, to, &movement);
+
+    let mut open_set = BinaryHeap::new();
+    let mut g_scores = HashMap::new();
+    let mut came_from = HashMap::new();
+    let mut visited = Vec::new();
+    let mut best = BestSoFar::new(start, octile(start, goal));
+    let mut pushed = 0;
+    let mut priority = |g: usize, h: usize| {
+        pushed += 1;
+        Priority::new(g, h, pushed, TieBreak::default())
+    };
+
+    g_scores.insert(start, 0);
+    observer.on_push(&start);
+    open_set.push(NodeCost {
+        state: start,
+        cost: 0,
+        priority: priority(0, octile(start, goal)),
+    });
+
+    while let Some(NodeCost {
+        state: position,
+        cost,
+        ..
+    }) = open_set.pop()
+    {
+        if g_scores
+            .get(&position)
+            .is_some_and(|&g_score| cost > g_score)
+        {
+            observer.on_discard(&position);
+            continue;
+        }
+
+        if observer.should_stop() {
+            return SearchOutcome::BudgetExhausted {
+                best_path: reconstruct_path(&came_from, best.into_state()),
+                visited,
+            };
+        }
+
+        observer.on_expand(&position);
+        visited.push(position);
+        best.offer(&position, octile(position, goal));
+
+        if position == goal {
+            let path = reconstruct_path(&came_from, position);
+            observer.on_path_found(&path);
+            return SearchOutcome::Found {
+                goal,
+                path,
+                cost,
+                visited,
+            };
+        }
+
+        let arrived = came_from
+            .get(&position)
+            .map(|&parent| direction(parent, position));
+        for direction in pruned_directions(jumps, position, arrived) {
+            let Some(next) = jumps.jump(position, direction, goal) else {
+                continue;
+            };
+
+            let tentative_g_score = cost.saturating_add(octile(position, next));
+            if g_scores
+                .get(&next)
+                .is_none_or(|&g_score| tentative_g_score < g_score)
+            {
+                g_scores.insert(next, tentative_g_score);
+                came_from.insert(next, position);
+                observer.on_push(&next);
+                open_set.push(NodeCost {
+                    state: next,
+                    cost: tentative_g_score,
+                    priority: priority(tentative_g_score, octile(next, goal)),
+                });
+            }
+        }
+
+        observer.on_close(&position);
+    }
+
+    SearchOutcome::Exhausted { visited } // Goal not reachable
+}
+
+/// The directions worth jumping in after arriving at `position` moving in
+/// `arrived`, or every direction at the start.
+fn pruned_directions<J: Jumps>(
+    jumps: &J,
+    position: Position,
+    arrived: Option<Direction>,
+) -> Vec<Direction> {
+    let candidates = match arrived {
+        None => DIRECTIONS.to_vec(),
+        Some((dr, dc)) if dr != 0 && dc != 0 => vec![(dr, 0), (0, dc), (dr, dc)],
+        Some((dr, dc)) => {
+            // Straight ahead, the two sides, and the diagonals between them.
+            let (left, right) = ((dc, dr), (-dc, -dr));
+            vec![
+                (dr, dc),
+                (dr + left.0, dc + left.1),
+                (dr + right.0, dc + right.1),
+                left,
+                right,
+            ]
+        }
+    };
+
+    candidates
+        .into_iter()
+        .filter(|&direction| jumps.can_step(position, direction))
+        .collect()
+}
+
+/// The step direction from `from` toward `to`.
+fn direction(from: Position, to: Position) -> Direction {
+    (
+        (to.0 as isize - from.0 as isize).signum(),
+        (to.1 as isize - from.1 as isize).signum(),
+    )
+}
+
+/// `position` moved `steps` times in `direction`, if that stays on the grid's
+/// side of zero.
+fn offset(position: Position, (dr, dc): Direction, steps: usize) -> Option<Position> {
+    let steps = steps as isize;
+    Some((
+        position.0.checked_add_signed(dr * steps)?,
+        position.1.checked_add_signed(dc * steps)?,
+    ))
+}
+
+fn slot_of(direction: Direction) -> usize {
+    DIRECTIONS.iter().position(|&d| d == direction).unwrap()
+}
@@ -0,0 +1,109 @@
+use crate::pathfinding::cost::{is_better, is_worse, Cost};
+use crate::search::budget::BestSoFar;
+use crate::search::observer::{NoopObserver, SearchObserver};
+use crate::search::outcome::SearchOutcome;
+use crate::search::search_problem::{reconstruct_path, SearchProblem};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+
+struct NodeCost<S, C> {
+    state: S,
+    cost: C,
+}
+
+impl<S, C: Cost> PartialEq for NodeCost<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<S, C: Cost> Eq for NodeCost<S, C> {}
+
+impl<S, C: Cost> Ord for NodeCost<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.cost.total_cmp(&self.cost) // Reverse to make BinaryHeap a min-heap
+    }
+}
+
+impl<S, C: Cost> PartialOrd for NodeCost<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Uniform cost search over any `SearchProblem`.
+///
+/// Finds the cheapest path to a goal, or ends `Exhausted` if no goal is reachable.
+pub fn uniform_cost_search<P: SearchProblem>(problem: &P) -> SearchOutcome<P::State, P::Cost> {
+    uniform_cost_search_observed(problem, &mut NoopObserver)
+}
+
+pub fn uniform_cost_search_observed<P, O>(
+    problem: &P,
+    observer: &mut O,
+) -> SearchOutcome<P::State, P::Cost>
+where
+    P: SearchProblem,
+    O: SearchObserver<P::State>,
+{
+    let mut visited = Vec::new();
+    let mut open_set = BinaryHeap::new();
+    let mut came_from = HashMap::new();
+    let mut cost_so_far = HashMap::new();
+
+    let start = problem.initial_state();
+    let mut best = BestSoFar::new(start.clone(), problem.heuristic(&start));
+    cost_so_far.insert(start.clone(), P::Cost::ZERO);
+    observer.on_push(&start);
+    open_set.push(NodeCost {
+        state: start,
+        cost: P::Cost::ZERO,
+    });
+
+    while let Some(NodeCost { state, cost }) = open_set.pop() {
+        if is_worse(cost, cost_so_far.get(&state)) {
+            observer.on_discard(&state);
+            continue;
+        }
+
+        if observer.should_stop() {
+            return SearchOutcome::BudgetExhausted {
+                best_path: reconstruct_path(&came_from, best.into_state()),
+                visited,
+            };
+        }
+
+        observer.on_expand(&state);
+        visited.push(state.clone());
+        best.offer(&state, problem.heuristic(&state));
+
+        if problem.is_goal(&state) {
+            let path = reconstruct_path(&came_from, state.clone());
+            observer.on_path_found(&path);
+            return SearchOutcome::Found {
+                goal: state,
+                path,
+                cost,
+                visited,
+            };
+        }
+
+        for (next, step_cost) in problem.successors(&state) {
+            let new_cost = cost.saturating_add(step_cost);
+
+            if is_better(new_cost, cost_so_far.get(&next)) {
+                cost_so_far.insert(next.clone(), new_cost);
+                came_from.insert(next.clone(), state.clone());
+                observer.on_push(&next);
+                open_set.push(NodeCost {
+                    state: next,
+                    cost: new_cost,
+                });
+            }
+        }
+
+        observer.on_close(&state);
+    }
+
+    SearchOutcome::Exhausted { visited } // Goal not reachable
+}
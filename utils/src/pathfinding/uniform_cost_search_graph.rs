@@ -1,69 +1,83 @@
-use petgraph::graph::{Graph, NodeIndex};
-use petgraph::visit::EdgeRef;
-use std::collections::{BinaryHeap, HashMap};
-use std::cmp::Ordering;
+use crate::pathfinding::cost::Cost;
+use crate::pathfinding::uniform_cost_search::uniform_cost_search_observed;
+use crate::search::observer::{NoopObserver, SearchObserver};
+use crate::search::outcome::{validate_node, ContainsNode, SearchError, SearchOutcome};
+use crate::search::search_problem::GraphProblem;
+use petgraph::visit::IntoEdges;
+use std::hash::Hash;
 
-#[derive(Debug, PartialEq)]
-struct NodeCost<T> {
-    node: NodeIndex,
-    cost: T,
+/// Uniform cost search over any petgraph graph or view that implements
+/// `IntoEdges`, such as `Graph`, `StableGraph`, `GraphMap`, `EdgeFiltered` or
+/// `Reversed`. Edge weights are the step costs.
+///
+/// Works with any `Cost`, including `f64` distances.
+pub fn uniform_cost_search_graph<G>(
+    graph: G,
+    start: G::NodeId,
+    goal: G::NodeId,
+) -> Result<SearchOutcome<G::NodeId, G::EdgeWeight>, SearchError>
+where
+    G: IntoEdges + ContainsNode,
+    G::NodeId: Eq + Hash,
+    G::EdgeWeight: Cost,
+{
+    uniform_cost_search_graph_observed(graph, start, goal, &mut NoopObserver)
 }
 
-impl<T: PartialEq> Eq for NodeCost<T> {}
-
-impl<T: PartialOrd> PartialOrd for NodeCost<T> {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        other.cost.partial_cmp(&self.cost) // Reverse to make BinaryHeap a min-heap
-    }
+pub fn uniform_cost_search_graph_observed<G, O>(
+    graph: G,
+    start: G::NodeId,
+    goal: G::NodeId,
+    observer: &mut O,
+) -> Result<SearchOutcome<G::NodeId, G::EdgeWeight>, SearchError>
+where
+    G: IntoEdges + ContainsNode,
+    G::NodeId: Eq + Hash,
+    G::EdgeWeight: Cost,
+    O: SearchObserver<G::NodeId>,
+{
+    uniform_cost_search_graph_where_observed(graph, start, |node| node == goal, observer)
 }
 
-impl<T: Ord> Ord for NodeCost<T> {
-    fn cmp(&self, other: &Self) -> Ordering {
-        other.cost.cmp(&self.cost) // Reverse to make BinaryHeap a min-heap
-    }
+/// Uniform cost search for the cheapest node satisfying `is_goal`, such as the
+/// nearest of several targets. `SearchOutcome::goal` tells which one was
+/// reached.
+pub fn uniform_cost_search_graph_where<G, F>(
+    graph: G,
+    start: G::NodeId,
+    is_goal: F,
+) -> Result<SearchOutcome<G::NodeId, G::EdgeWeight>, SearchError>
+where
+    G: IntoEdges + ContainsNode,
+    G::NodeId: Eq + Hash,
+    G::EdgeWeight: Cost,
+    F: Fn(G::NodeId) -> bool,
+{
+    uniform_cost_search_graph_where_observed(graph, start, is_goal, &mut NoopObserver)
 }
 
-pub fn uniform_cost_search_graph<T>(
-    graph: &Graph<T, usize>,
-    start: NodeIndex,
-    goal: NodeIndex,
-) -> Option<(Vec<NodeIndex>, usize)>
+pub fn uniform_cost_search_graph_where_observed<G, F, O>(
+    graph: G,
+    start: G::NodeId,
+    is_goal: F,
+    observer: &mut O,
+) -> Result<SearchOutcome<G::NodeId, G::EdgeWeight>, SearchError>
 where
-    T: Clone,
+    G: IntoEdges + ContainsNode,
+    G::NodeId: Eq + Hash,
+    G::EdgeWeight: Cost,
+    F: Fn(G::NodeId) -> bool,
+    O: SearchObserver<G::NodeId>,
 {
-    let mut open_set = BinaryHeap::new();
-    let mut came_from: HashMap<NodeIndex, NodeIndex> = HashMap::new();
-    let mut cost_so_far: HashMap<NodeIndex, usize> = HashMap::new();
+    validate_node(graph, start)?;
 
-    open_set.push(NodeCost { node: start, cost: 0 });
-    cost_so_far.insert(start, 0);
+    let problem = GraphProblem {
+        graph,
+        start,
+        edge_cost: |weight: &G::EdgeWeight| *weight,
+        is_goal,
+        heuristic: |_| G::EdgeWeight::ZERO,
+    };
 
-    while let Some(NodeCost { node: current, cost: current_cost }) = open_set.pop() {
-        if current == goal {
-            let mut path = vec![current];
-            let mut current_node = current;
-
-            while let Some(&parent) = came_from.get(&current_node) {
-                path.push(parent);
-                current_node = parent;
-            }
-
-            path.reverse();
-            return Some((path, current_cost));
-        }
-
-        for edge in graph.edges(current) {
-            let next = edge.target();
-            let edge_cost = *edge.weight();
-            let new_cost = current_cost + edge_cost;
-
-            if !cost_so_far.contains_key(&next) || new_cost < cost_so_far[&next] {
-                cost_so_far.insert(next, new_cost);
-                came_from.insert(next, current);
-                open_set.push(NodeCost { node: next, cost: new_cost });
-            }
-        }
-    }
-
-    None // Goal not reachable
-}
\ No newline at end of file
+    Ok(uniform_cost_search_observed(&problem, observer))
+}
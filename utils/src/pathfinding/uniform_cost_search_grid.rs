@@ -1,61 +1,63 @@
-use std::collections::{BinaryHeap};
-use std::cmp::Reverse;
+use crate::pathfinding::grid_movement::Movement;
+use crate::pathfinding::uniform_cost_search::uniform_cost_search_observed;
+use crate::search::observer::{NoopObserver, SearchObserver};
+use crate::search::outcome::{validate_grid, SearchError, SearchOutcome};
+use crate::search::search_problem::GridProblem;
 
 pub fn uniform_cost_search_grid(
     grid: &[Vec<usize>],
     start: (usize, usize),
     goal: (usize, usize),
-) -> Vec<(usize, usize)> {
-    let mut cost_so_far = vec![vec![usize::MAX; grid[0].len()]; grid.len()];
-    let mut came_from = vec![vec![None; grid[0].len()]; grid.len()];
-    let mut frontier: BinaryHeap<Reverse<(usize, (usize, usize))>> = BinaryHeap::new(); // Specify type for frontier
-
-    frontier.push(Reverse((0, start)));
-    cost_so_far[start.0][start.1] = 0;
-
-    while let Some(Reverse((current_cost, (row, col)))) = frontier.pop() {
-        if (row, col) == goal {
-            break;
-        }
-
-        let neighbors = [
-            (row.wrapping_sub(1), col), // Up
-            (row + 1, col),             // Down
-            (row, col.wrapping_sub(1)), // Left
-            (row, col + 1),             // Right
-        ];
-
-        for &(next_row, next_col) in &neighbors {
-            if next_row < grid.len() && next_col < grid[0].len() {
-                // Skip impassable cells
-                if grid[next_row][next_col] == usize::MAX {
-                    continue;
-                }
-
-                let new_cost = current_cost.saturating_add(grid[next_row][next_col]);
-                if new_cost < cost_so_far[next_row][next_col] {
-                    cost_so_far[next_row][next_col] = new_cost;
-                    frontier.push(Reverse((new_cost, (next_row, next_col))));
-                    came_from[next_row][next_col] = Some((row, col));
-                }
-            }
-        }
-    }
-
-    // Reconstruct the path
-    let mut path = Vec::new();
-    let mut current = Some(goal);
+) -> Result<SearchOutcome<(usize, usize)>, SearchError> {
+    uniform_cost_search_grid_observed(grid, start, goal, &mut NoopObserver)
+}
 
-    while let Some(pos) = current {
-        path.push(pos);
-        current = came_from[pos.0][pos.1];
-    }
+pub fn uniform_cost_search_grid_observed<O>(
+    grid: &[Vec<usize>],
+    start: (usize, usize),
+    goal: (usize, usize),
+    observer: &mut O,
+) -> Result<SearchOutcome<(usize, usize)>, SearchError>
+where
+    O: SearchObserver<(usize, usize)>,
+{
+    uniform_cost_search_grid_where_observed(grid, start, |position| position == goal, observer)
+}
 
-    path.reverse();
+/// Uniform cost search for the cheapest cell satisfying `is_goal`, such as the
+/// nearest of several exits. `SearchOutcome::goal` tells which one was reached.
+pub fn uniform_cost_search_grid_where<G>(
+    grid: &[Vec<usize>],
+    start: (usize, usize),
+    is_goal: G,
+) -> Result<SearchOutcome<(usize, usize)>, SearchError>
+where
+    G: Fn((usize, usize)) -> bool,
+{
+    uniform_cost_search_grid_where_observed(grid, start, is_goal, &mut NoopObserver)
+}
 
-    if path.first() == Some(&start) && path.last() == Some(&goal) {
-        path
-    } else {
-        Vec::new()
-    }
+pub fn uniform_cost_search_grid_where_observed<G, O>(
+    grid: &[Vec<usize>],
+    start: (usize, usize),
+    is_goal: G,
+    observer: &mut O,
+) -> Result<SearchOutcome<(usize, usize)>, SearchError>
+where
+    G: Fn((usize, usize)) -> bool,
+    O: SearchObserver<(usize, usize)>,
+{
+    validate_grid(grid, start)?;
+
+    let problem = GridProblem {
+        grid,
+        start,
+        movement: Movement::four_way(),
+        // Skip impassable cells
+        step_cost: |&weight: &usize| (weight != usize::MAX).then_some(weight),
+        is_goal,
+        heuristic: |_| 0,
+    };
+
+    Ok(uniform_cost_search_observed(&problem, observer))
 }
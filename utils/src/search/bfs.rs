@@ -0,0 +1,110 @@
+use crate::search::budget::BestSoFar;
+use crate::search::observer::{NoopObserver, SearchObserver};
+use crate::search::outcome::SearchOutcome;
+use crate::search::search_problem::{path_cost, reconstruct_path, SearchProblem};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Breadth-first search over any `SearchProblem`, ignoring step costs.
+///
+/// The returned path has the fewest steps of any path to a goal.
+pub fn bfs<P: SearchProblem>(problem: &P) -> SearchOutcome<P::State, P::Cost> {
+    bfs_observed(problem, &mut NoopObserver)
+}
+
+pub fn bfs_observed<P, O>(problem: &P, observer: &mut O) -> SearchOutcome<P::State, P::Cost>
+where
+    P: SearchProblem,
+    O: SearchObserver<P::State>,
+{
+    let mut visited = Vec::new();
+    let mut queue = VecDeque::new();
+    let mut discovered = HashSet::new();
+    let mut came_from = HashMap::new();
+
+    let start = problem.initial_state();
+    let mut best = BestSoFar::new(start.clone(), problem.heuristic(&start));
+    discovered.insert(start.clone());
+    observer.on_push(&start);
+    queue.push_back(start);
+
+    while let Some(state) = queue.pop_front() {
+        if observer.should_stop() {
+            return SearchOutcome::BudgetExhausted {
+                best_path: reconstruct_path(&came_from, best.into_state()),
+                visited,
+            };
+        }
+
+        observer.on_expand(&state);
+        visited.push(state.clone());
+        best.offer(&state, problem.heuristic(&state));
+
+        if problem.is_goal(&state) {
+            let path = reconstruct_path(&came_from, state.clone());
+            observer.on_path_found(&path);
+            return SearchOutcome::Found {
+                goal: state,
+                cost: path_cost(problem, &path),
+                path,
+                visited,
+            };
+        }
+
+        for (next, _) in problem.successors(&state) {
+            if discovered.insert(next.clone()) {
+                came_from.insert(next.clone(), state.clone());
+                observer.on_push(&next);
+                queue.push_back(next);
+            }
+        }
+
+        observer.on_close(&state);
+    }
+
+    SearchOutcome::Exhausted { visited }
+}
+
+/// Breadth-first search that runs until every reachable state is expanded and
+/// returns each goal with its distance in steps, nearest first.
+///
+/// Goals do not block the search, so states beyond them are reached too.
+pub fn bfs_all<P: SearchProblem>(problem: &P) -> Vec<(P::State, usize)> {
+    bfs_all_observed(problem, &mut NoopObserver)
+}
+
+/// If the observer stops the search, the goals found so far are returned.
+pub fn bfs_all_observed<P, O>(problem: &P, observer: &mut O) -> Vec<(P::State, usize)>
+where
+    P: SearchProblem,
+    O: SearchObserver<P::State>,
+{
+    let mut goals = Vec::new();
+    let mut queue = VecDeque::new();
+    let mut discovered = HashSet::new();
+
+    let start = problem.initial_state();
+    discovered.insert(start.clone());
+    observer.on_push(&start);
+    queue.push_back((start, 0));
+
+    while let Some((state, distance)) = queue.pop_front() {
+        if observer.should_stop() {
+            break;
+        }
+
+        observer.on_expand(&state);
+        for (next, _) in problem.successors(&state) {
+            if discovered.insert(next.clone()) {
+                observer.on_push(&next);
+                queue.push_back((next, distance + 1));
+            }
+        }
+        observer.on_close(&state);
+
+        if problem.is_goal(&state) {
+            goals.push((state, distance));
+        }
+    }
+
+    goals
+}
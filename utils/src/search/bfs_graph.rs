@@ -1,42 +1,70 @@
-use petgraph::graph::{Graph, NodeIndex};
-use std::collections::VecDeque;
+use crate::search::bfs::bfs_observed;
+use crate::search::observer::{NoopObserver, SearchObserver};
+use crate::search::outcome::{validate_node, ContainsNode, SearchError, SearchOutcome};
+use crate::search::search_problem::GraphProblem;
+use petgraph::data::DataMap;
+use petgraph::visit::IntoEdges;
+use std::hash::Hash;
 
-pub fn bfs_graph<T>(
-    graph: &Graph<T, ()>,
-    start: NodeIndex,
-    target: Option<&T>,
-) -> Result<Vec<T>, Option<T>>
+/// Breadth-first search over any petgraph graph or view that implements `IntoEdges` and
+/// `DataMap`, such as `Graph`, `StableGraph`, `NodeFiltered` or `Reversed`,
+/// ignoring edge weights.
+///
+/// Stops at the first node whose weight equals `target`. Without a `target`,
+/// every reachable node is visited and the search ends `Exhausted`.
+pub fn bfs_graph<G>(
+    graph: G,
+    start: G::NodeId,
+    target: Option<&G::NodeWeight>,
+) -> Result<SearchOutcome<G::NodeId>, SearchError>
 where
-    T: PartialEq + Clone,
+    G: IntoEdges + ContainsNode + DataMap,
+    G::NodeId: Eq + Hash,
+    G::NodeWeight: PartialEq,
 {
-    let mut visited = Vec::new();
-    let mut queue = VecDeque::new();
-    let mut discovered = vec![false; graph.node_count()];
+    bfs_graph_where(graph, start, |node| {
+        target.is_some_and(|t| graph.node_weight(node) == Some(t))
+    })
+}
 
-    queue.push_back(start);
-    discovered[start.index()] = true;
-
-    while let Some(node) = queue.pop_front() {
-        let value = graph[node].clone();
-        visited.push(value.clone());
+/// Breadth-first search for the first node satisfying `is_goal`.
+///
+/// Needs no node weights, so it also runs on graphs such as `GraphMap` or
+/// `EdgeFiltered` that do not implement `DataMap`.
+pub fn bfs_graph_where<G, F>(
+    graph: G,
+    start: G::NodeId,
+    is_goal: F,
+) -> Result<SearchOutcome<G::NodeId>, SearchError>
+where
+    G: IntoEdges + ContainsNode,
+    G::NodeId: Eq + Hash,
+    F: Fn(G::NodeId) -> bool,
+{
+    bfs_graph_observed(graph, start, is_goal, &mut NoopObserver)
+}
 
-        if let Some(t) = target {
-            if &value == t {
-                return Err(Some(value));
-            }
-        }
+pub fn bfs_graph_observed<G, F, O>(
+    graph: G,
+    start: G::NodeId,
+    is_goal: F,
+    observer: &mut O,
+) -> Result<SearchOutcome<G::NodeId>, SearchError>
+where
+    G: IntoEdges + ContainsNode,
+    G::NodeId: Eq + Hash,
+    F: Fn(G::NodeId) -> bool,
+    O: SearchObserver<G::NodeId>,
+{
+    validate_node(graph, start)?;
 
-        for neighbor in graph.neighbors(node) {
-            if !discovered[neighbor.index()] {
-                queue.push_back(neighbor);
-                discovered[neighbor.index()] = true;
-            }
-        }
-    }
+    let problem = GraphProblem {
+        graph,
+        start,
+        edge_cost: |_: &G::EdgeWeight| 1,
+        is_goal,
+        heuristic: |_| 0,
+    };
 
-    if target.is_none() {
-        Ok(visited)
-    } else {
-        Err(None)
-    }
-}
\ No newline at end of file
+    Ok(bfs_observed(&problem, observer))
+}
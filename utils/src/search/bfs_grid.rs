@@ -1,38 +1,115 @@
-use std::collections::VecDeque;
+use crate::pathfinding::grid_movement::Movement;
+use crate::search::bfs::{bfs_all_observed, bfs_observed};
+use crate::search::observer::{NoopObserver, SearchObserver};
+use crate::search::outcome::{validate_grid, SearchError, SearchOutcome};
+use crate::search::search_problem::GridProblem;
 
+/// A cell and its distance from the start in steps.
+pub type CellDistance = ((usize, usize), usize);
+
+/// Breadth-first search for the nearest cell equal to `target`.
+///
+/// The path found has the fewest steps of any path to a matching cell. Fails if
+/// `grid` is empty or `start` lies outside it.
 pub fn bfs_grid<T: PartialEq>(
     grid: &[Vec<T>],
     start: (usize, usize),
     target: &T,
-) -> (bool, Option<(usize, usize)>) {
-    let mut visited = vec![vec![false; grid[0].len()]; grid.len()];
-    let mut queue = VecDeque::new();
-    queue.push_back(start);
-
-    while let Some((row, col)) = queue.pop_front() {
-        if row >= grid.len() || col >= grid[0].len() || visited[row][col] {
-            continue;
-        }
-
-        visited[row][col] = true;
-
-        if &grid[row][col] == target {
-            return (true, Some((row, col)));
-        }
-
-        let neighbors = [
-            (row.wrapping_sub(1), col),
-            (row + 1, col),          
-            (row, col.wrapping_sub(1)),
-            (row, col + 1),            
-        ];
-
-        for &(next_row, next_col) in &neighbors {
-            if next_row < grid.len() && next_col < grid[0].len() && !visited[next_row][next_col] {
-                queue.push_back((next_row, next_col));
-            }
-        }
-    }
-
-    (false, None)
+) -> Result<SearchOutcome<(usize, usize)>, SearchError> {
+    bfs_grid_where(grid, start, |_| true, |cell| cell == target)
+}
+
+/// Breadth-first search for the nearest passable cell satisfying `is_goal`.
+///
+/// Only cells for which `is_passable` holds are entered, so walls are never
+/// crossed. The returned path has the fewest steps of any path to a goal cell.
+pub fn bfs_grid_where<T, P, G>(
+    grid: &[Vec<T>],
+    start: (usize, usize),
+    is_passable: P,
+    is_goal: G,
+) -> Result<SearchOutcome<(usize, usize)>, SearchError>
+where
+    P: Fn(&T) -> bool,
+    G: Fn(&T) -> bool,
+{
+    bfs_grid_observed(grid, start, is_passable, is_goal, &mut NoopObserver)
+}
+
+pub fn bfs_grid_observed<T, P, G, O>(
+    grid: &[Vec<T>],
+    start: (usize, usize),
+    is_passable: P,
+    is_goal: G,
+    observer: &mut O,
+) -> Result<SearchOutcome<(usize, usize)>, SearchError>
+where
+    P: Fn(&T) -> bool,
+    G: Fn(&T) -> bool,
+    O: SearchObserver<(usize, usize)>,
+{
+    validate_grid(grid, start)?;
+
+    let problem = GridProblem {
+        grid,
+        start,
+        movement: Movement::four_way(),
+        step_cost: |cell: &T| is_passable(cell).then_some(1),
+        is_goal: |(row, col): (usize, usize)| is_goal(&grid[row][col]),
+        heuristic: |_| 0,
+    };
+
+    Ok(bfs_observed(&problem, observer))
+}
+
+/// Every cell equal to `target` that can be reached from `start`, each with its
+/// distance in steps, nearest first.
+pub fn bfs_grid_all<T: PartialEq>(
+    grid: &[Vec<T>],
+    start: (usize, usize),
+    target: &T,
+) -> Result<Vec<CellDistance>, SearchError> {
+    bfs_grid_all_where(grid, start, |_| true, |cell| cell == target)
+}
+
+/// Every passable cell satisfying `is_goal` that can be reached from `start`,
+/// each with its distance in steps, nearest first. Cells at the same distance
+/// come in the order the search reached them.
+pub fn bfs_grid_all_where<T, P, G>(
+    grid: &[Vec<T>],
+    start: (usize, usize),
+    is_passable: P,
+    is_goal: G,
+) -> Result<Vec<CellDistance>, SearchError>
+where
+    P: Fn(&T) -> bool,
+    G: Fn(&T) -> bool,
+{
+    bfs_grid_all_observed(grid, start, is_passable, is_goal, &mut NoopObserver)
+}
+
+pub fn bfs_grid_all_observed<T, P, G, O>(
+    grid: &[Vec<T>],
+    start: (usize, usize),
+    is_passable: P,
+    is_goal: G,
+    observer: &mut O,
+) -> Result<Vec<CellDistance>, SearchError>
+where
+    P: Fn(&T) -> bool,
+    G: Fn(&T) -> bool,
+    O: SearchObserver<(usize, usize)>,
+{
+    validate_grid(grid, start)?;
+
+    let problem = GridProblem {
+        grid,
+        start,
+        movement: Movement::four_way(),
+        step_cost: |cell: &T| is_passable(cell).then_some(1),
+        is_goal: |(row, col): (usize, usize)| is_goal(&grid[row][col]),
+        heuristic: |_| 0,
+    };
+
+    Ok(bfs_all_observed(&problem, observer))
 }
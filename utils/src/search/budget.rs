@@ -0,0 +1,134 @@
+use crate::pathfinding::cost::Cost;
+use crate::search::observer::SearchObserver;
+use std::cmp::Ordering;
+use std::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Limits on how much work a search may do, for running searches inside a
+/// game loop.
+///
+/// A budget is an observer: pass it to any `_observed` search, or pair it with
+/// another observer as `(observer, budget)`. Once a limit is hit the search
+/// ends with `SearchOutcome::BudgetExhausted`. Expansion and frontier limits
+/// are checked before every expansion, the deadline and cancellation flag every
+/// `CHECK_INTERVAL` expansions.
+#[derive(Debug, Clone, Default)]
+pub struct SearchBudget {
+    max_expansions: Option<usize>,
+    max_frontier: Option<usize>,
+    deadline: Option<Instant>,
+    cancelled: Option<Arc<AtomicBool>>,
+    expanded: usize,
+    frontier: usize,
+}
+
+impl SearchBudget {
+    pub const CHECK_INTERVAL: usize = 64;
+
+    /// A budget without any limits.
+    pub fn unlimited() -> Self {
+        Self::default()
+    }
+
+    /// Allows at most `max_expansions` expansions.
+    pub fn with_max_expansions(mut self, max_expansions: usize) -> Self {
+        self.max_expansions = Some(max_expansions);
+        self
+    }
+
+    /// Stops once more than `max_frontier` entries wait on the frontier. One
+    /// expansion can push several entries, so the frontier may briefly exceed
+    /// the limit before it is checked.
+    pub fn with_max_frontier(mut self, max_frontier: usize) -> Self {
+        self.max_frontier = Some(max_frontier);
+        self
+    }
+
+    /// Stops once `deadline` has passed.
+    pub fn with_deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Stops once `limit` has elapsed, counted from now.
+    pub fn with_time_limit(self, limit: Duration) -> Self {
+        self.with_deadline(Instant::now() + limit)
+    }
+
+    /// Stops once `cancelled` is set, e.g. from another thread.
+    pub fn with_cancel_flag(mut self, cancelled: Arc<AtomicBool>) -> Self {
+        self.cancelled = Some(cancelled);
+        self
+    }
+
+    /// Expansions counted so far.
+    pub fn expanded(&self) -> usize {
+        self.expanded
+    }
+}
+
+impl<S> SearchObserver<S> for SearchBudget {
+    fn on_push(&mut self, _state: &S) {
+        self.frontier += 1;
+    }
+
+    fn on_expand(&mut self, _state: &S) {
+        self.expanded += 1;
+        self.frontier = self.frontier.saturating_sub(1);
+    }
+
+    fn on_discard(&mut self, _state: &S) {
+        self.frontier = self.frontier.saturating_sub(1);
+    }
+
+    fn should_stop(&mut self) -> bool {
+        if self.max_expansions.is_some_and(|max| self.expanded >= max)
+            || self.max_frontier.is_some_and(|max| self.frontier > max)
+        {
+            return true;
+        }
+
+        if !self.expanded.is_multiple_of(Self::CHECK_INTERVAL) {
+            return false;
+        }
+
+        let past_deadline = self
+            .deadline
+            .is_some_and(|deadline| Instant::now() >= deadline);
+        let cancelled = self
+            .cancelled
+            .as_ref()
+            .is_some_and(|cancelled| cancelled.load(AtomicOrdering::Relaxed));
+        past_deadline || cancelled
+    }
+}
+
+/// The most promising state expanded so far, kept for the partial result of a
+/// search that runs out of budget. Lower estimates win, and later states win
+/// ties, so searches without a heuristic report the last state they expanded.
+pub(crate) struct BestSoFar<S, C> {
+    state: S,
+    estimate: C,
+}
+
+impl<S: Clone, C: Cost> BestSoFar<S, C> {
+    pub(crate) fn new(state: S, estimate: C) -> Self {
+        BestSoFar { state, estimate }
+    }
+
+    /// Records `state` if it is at least as promising as the current best,
+    /// and returns whether it was.
+    pub(crate) fn offer(&mut self, state: &S, estimate: C) -> bool {
+        if estimate.total_cmp(&self.estimate) == Ordering::Greater {
+            return false;
+        }
+        self.state = state.clone();
+        self.estimate = estimate;
+        true
+    }
+
+    pub(crate) fn into_state(self) -> S {
+        self.state
+    }
+}
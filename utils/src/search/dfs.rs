@@ -0,0 +1,72 @@
+use crate::search::budget::BestSoFar;
+use crate::search::observer::{NoopObserver, SearchObserver};
+use crate::search::outcome::SearchOutcome;
+use crate::search::search_problem::{path_cost, reconstruct_path, SearchProblem};
+use std::collections::{HashMap, HashSet};
+
+/// Depth-first search over any `SearchProblem`, ignoring step costs.
+///
+/// Successors are pushed in the order the problem yields them, so the last one
+/// is explored first.
+pub fn dfs<P: SearchProblem>(problem: &P) -> SearchOutcome<P::State, P::Cost> {
+    dfs_observed(problem, &mut NoopObserver)
+}
+
+pub fn dfs_observed<P, O>(problem: &P, observer: &mut O) -> SearchOutcome<P::State, P::Cost>
+where
+    P: SearchProblem,
+    O: SearchObserver<P::State>,
+{
+    let mut visited = Vec::new();
+    let mut expanded = HashSet::new();
+    let mut came_from = HashMap::new();
+
+    let start = problem.initial_state();
+    let mut best = BestSoFar::new(start.clone(), problem.heuristic(&start));
+    observer.on_push(&start);
+    let mut stack = vec![(start, None)];
+
+    while let Some((state, parent)) = stack.pop() {
+        if !expanded.insert(state.clone()) {
+            observer.on_discard(&state);
+            continue;
+        }
+
+        if let Some(parent) = parent {
+            came_from.insert(state.clone(), parent);
+        }
+
+        if observer.should_stop() {
+            return SearchOutcome::BudgetExhausted {
+                best_path: reconstruct_path(&came_from, best.into_state()),
+                visited,
+            };
+        }
+
+        observer.on_expand(&state);
+        visited.push(state.clone());
+        best.offer(&state, problem.heuristic(&state));
+
+        if problem.is_goal(&state) {
+            let path = reconstruct_path(&came_from, state.clone());
+            observer.on_path_found(&path);
+            return SearchOutcome::Found {
+                goal: state,
+                cost: path_cost(problem, &path),
+                path,
+                visited,
+            };
+        }
+
+        for (next, _) in problem.successors(&state) {
+            if !expanded.contains(&next) {
+                observer.on_push(&next);
+                stack.push((next, Some(state.clone())));
+            }
+        }
+
+        observer.on_close(&state);
+    }
+
+    SearchOutcome::Exhausted { visited }
+}
@@ -1,31 +1,70 @@
-use petgraph::graph::{Graph, NodeIndex};
-use petgraph::visit::Dfs;
+use crate::search::dfs::dfs_observed;
+use crate::search::observer::{NoopObserver, SearchObserver};
+use crate::search::outcome::{validate_node, ContainsNode, SearchError, SearchOutcome};
+use crate::search::search_problem::GraphProblem;
+use petgraph::data::DataMap;
+use petgraph::visit::IntoEdges;
+use std::hash::Hash;
 
-pub fn dfs_graph<T>(
-    graph: &Graph<T, ()>,
-    start: NodeIndex,
-    target: Option<&T>,
-) -> Result<Vec<T>, Option<T>>
+/// Depth-first search over any petgraph graph or view that implements `IntoEdges` and
+/// `DataMap`, such as `Graph`, `StableGraph`, `NodeFiltered` or `Reversed`,
+/// ignoring edge weights.
+///
+/// Stops at the first node whose weight equals `target`. Without a `target`,
+/// every reachable node is visited and the search ends `Exhausted`.
+pub fn dfs_graph<G>(
+    graph: G,
+    start: G::NodeId,
+    target: Option<&G::NodeWeight>,
+) -> Result<SearchOutcome<G::NodeId>, SearchError>
 where
-    T: PartialEq + Clone,
+    G: IntoEdges + ContainsNode + DataMap,
+    G::NodeId: Eq + Hash,
+    G::NodeWeight: PartialEq,
 {
-    let mut dfs = Dfs::new(graph, start);
-    let mut visited = Vec::new();
+    dfs_graph_where(graph, start, |node| {
+        target.is_some_and(|t| graph.node_weight(node) == Some(t))
+    })
+}
 
-    while let Some(node) = dfs.next(graph) {
-        let value = graph[node].clone();
-        visited.push(value.clone());
+/// Depth-first search for the first node satisfying `is_goal`.
+///
+/// Needs no node weights, so it also runs on graphs such as `GraphMap` or
+/// `EdgeFiltered` that do not implement `DataMap`.
+pub fn dfs_graph_where<G, F>(
+    graph: G,
+    start: G::NodeId,
+    is_goal: F,
+) -> Result<SearchOutcome<G::NodeId>, SearchError>
+where
+    G: IntoEdges + ContainsNode,
+    G::NodeId: Eq + Hash,
+    F: Fn(G::NodeId) -> bool,
+{
+    dfs_graph_observed(graph, start, is_goal, &mut NoopObserver)
+}
+
+pub fn dfs_graph_observed<G, F, O>(
+    graph: G,
+    start: G::NodeId,
+    is_goal: F,
+    observer: &mut O,
+) -> Result<SearchOutcome<G::NodeId>, SearchError>
+where
+    G: IntoEdges + ContainsNode,
+    G::NodeId: Eq + Hash,
+    F: Fn(G::NodeId) -> bool,
+    O: SearchObserver<G::NodeId>,
+{
+    validate_node(graph, start)?;
 
-        if let Some(t) = target {
-            if &value == t {
-                return Err(Some(value));
-            }
-        }
-    }
+    let problem = GraphProblem {
+        graph,
+        start,
+        edge_cost: |_: &G::EdgeWeight| 1,
+        is_goal,
+        heuristic: |_| 0,
+    };
 
-    if target.is_none() {
-        Ok(visited)
-    } else {
-        Err(None)
-    }
-}
\ No newline at end of file
+    Ok(dfs_observed(&problem, observer))
+}
@@ -1,35 +1,60 @@
+use crate::pathfinding::grid_movement::Movement;
+use crate::search::dfs::dfs_observed;
+use crate::search::observer::{NoopObserver, SearchObserver};
+use crate::search::outcome::{validate_grid, SearchError, SearchOutcome};
+use crate::search::search_problem::GridProblem;
+
+/// Depth-first search for a cell equal to `target`.
+///
+/// The path found leads to the first matching cell reached, and is not
+/// necessarily the shortest. Fails if `grid` is empty or `start` lies outside it.
 pub fn dfs_grid<T: PartialEq>(
     grid: &[Vec<T>],
     start: (usize, usize),
     target: &T,
-) -> (bool, Option<(usize, usize)>) {
-    let mut visited = vec![vec![false; grid[0].len()]; grid.len()];
-    let mut stack = vec![start];
-
-    while let Some((row, col)) = stack.pop() {
-        if row >= grid.len() || col >= grid[0].len() || visited[row][col] {
-            continue;
-        }
-
-        visited[row][col] = true;
+) -> Result<SearchOutcome<(usize, usize)>, SearchError> {
+    dfs_grid_where(grid, start, |_| true, |cell| cell == target)
+}
 
-        if &grid[row][col] == target {
-            return (true, Some((row, col)));
-        }
+/// Depth-first search for a passable cell satisfying `is_goal`.
+///
+/// Only cells for which `is_passable` holds are entered, so walls are never
+/// crossed. The returned path is not necessarily the shortest.
+pub fn dfs_grid_where<T, P, G>(
+    grid: &[Vec<T>],
+    start: (usize, usize),
+    is_passable: P,
+    is_goal: G,
+) -> Result<SearchOutcome<(usize, usize)>, SearchError>
+where
+    P: Fn(&T) -> bool,
+    G: Fn(&T) -> bool,
+{
+    dfs_grid_observed(grid, start, is_passable, is_goal, &mut NoopObserver)
+}
 
-        let neighbors = [
-            (row.wrapping_sub(1), col),
-            (row + 1, col),
-            (row, col.wrapping_sub(1)),
-            (row, col + 1),
-        ];
+pub fn dfs_grid_observed<T, P, G, O>(
+    grid: &[Vec<T>],
+    start: (usize, usize),
+    is_passable: P,
+    is_goal: G,
+    observer: &mut O,
+) -> Result<SearchOutcome<(usize, usize)>, SearchError>
+where
+    P: Fn(&T) -> bool,
+    G: Fn(&T) -> bool,
+    O: SearchObserver<(usize, usize)>,
+{
+    validate_grid(grid, start)?;
 
-        for &(next_row, next_col) in &neighbors {
-            if next_row < grid.len() && next_col < grid[0].len() && !visited[next_row][next_col] {
-                stack.push((next_row, next_col));
-            }
-        }
-    }
+    let problem = GridProblem {
+        grid,
+        start,
+        movement: Movement::four_way(),
+        step_cost: |cell: &T| is_passable(cell).then_some(1),
+        is_goal: |(row, col): (usize, usize)| is_goal(&grid[row][col]),
+        heuristic: |_| 0,
+    };
 
-    (false, None)
+    Ok(dfs_observed(&problem, observer))
 }
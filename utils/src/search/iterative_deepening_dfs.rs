@@ -0,0 +1,206 @@
+use crate::search::budget::BestSoFar;
+use crate::search::observer::{NoopObserver, SearchObserver};
+use crate::search::outcome::SearchOutcome;
+use crate::search::search_problem::{path_cost, SearchProblem};
+use std::collections::{HashMap, HashSet};
+
+/// Iterative deepening depth-first search over any `SearchProblem`.
+///
+/// Runs a depth-limited search for every limit up to `max_depth`, where a limit
+/// of `d` explores paths of at most `d` states, so the goal found is always a
+/// shallowest one; `SearchOutcome::depth` tells how deep. Successors already on
+/// the current path are skipped, so cycles are never walked. The search ends
+/// `Exhausted` early once an iteration finishes without reaching its depth
+/// limit. `visited` lists the states expanded by the last iteration, each once,
+/// in the order they were first expanded.
+pub fn iterative_deepening_dfs<P: SearchProblem>(
+    problem: &P,
+    max_depth: usize,
+) -> SearchOutcome<P::State, P::Cost> {
+    iterative_deepening_dfs_observed(problem, max_depth, &mut NoopObserver)
+}
+
+/// Unlike `visited`, states are reported again on every iteration and path
+/// that reaches them.
+pub fn iterative_deepening_dfs_observed<P, O>(
+    problem: &P,
+    max_depth: usize,
+    observer: &mut O,
+) -> SearchOutcome<P::State, P::Cost>
+where
+    P: SearchProblem,
+    O: SearchObserver<P::State>,
+{
+    deepen(problem, max_depth, None, observer)
+}
+
+/// `iterative_deepening_dfs` that also remembers, within each iteration, how
+/// much depth was left at every state reached, and prunes states reached again
+/// with no more depth left.
+///
+/// On graphs with many paths between the same states this avoids walking each
+/// of them, at the cost of memory for every state reached. The goal found is
+/// still a shallowest one.
+pub fn iterative_deepening_dfs_with_transpositions<P: SearchProblem>(
+    problem: &P,
+    max_depth: usize,
+) -> SearchOutcome<P::State, P::Cost> {
+    iterative_deepening_dfs_with_transpositions_observed(problem, max_depth, &mut NoopObserver)
+}
+
+pub fn iterative_deepening_dfs_with_transpositions_observed<P, O>(
+    problem: &P,
+    max_depth: usize,
+    observer: &mut O,
+) -> SearchOutcome<P::State, P::Cost>
+where
+    P: SearchProblem,
+    O: SearchObserver<P::State>,
+{
+    deepen(problem, max_depth, Some(HashMap::new()), observer)
+}
+
+fn deepen<P, O>(
+    problem: &P,
+    max_depth: usize,
+    transpositions: Option<HashMap<P::State, usize>>,
+    observer: &mut O,
+) -> SearchOutcome<P::State, P::Cost>
+where
+    P: SearchProblem,
+    O: SearchObserver<P::State>,
+{
+    let start = problem.initial_state();
+    let mut iteration = Iteration {
+        problem,
+        observer,
+        path: Vec::new(),
+        visited: Vec::new(),
+        expanded: HashSet::new(),
+        cut_off: false,
+        best: BestSoFar::new(start.clone(), problem.heuristic(&start)),
+        best_path: vec![start.clone()],
+        transpositions,
+    };
+
+    for depth in 0..=max_depth {
+        iteration.path.clear();
+        iteration.visited.clear();
+        iteration.expanded.clear();
+        iteration.cut_off = false;
+        if let Some(transpositions) = &mut iteration.transpositions {
+            transpositions.clear();
+        }
+        iteration.observer.on_push(&start);
+
+        match iteration.search(start.clone(), depth) {
+            Limited::Found => {
+                iteration.observer.on_path_found(&iteration.path);
+                return SearchOutcome::Found {
+                    goal: iteration.path.last().unwrap().clone(),
+                    cost: path_cost(problem, &iteration.path),
+                    path: iteration.path,
+                    visited: iteration.visited,
+                };
+            }
+            Limited::Stopped => {
+                return SearchOutcome::BudgetExhausted {
+                    best_path: iteration.best_path,
+                    visited: iteration.visited,
+                };
+            }
+            // Nothing reached the depth limit, so going deeper finds nothing new.
+            Limited::Cutoff if !iteration.cut_off => break,
+            Limited::Cutoff => {}
+        }
+    }
+
+    SearchOutcome::Exhausted {
+        visited: iteration.visited,
+    }
+}
+
+/// One depth-limited search, along with what carries over between iterations.
+struct Iteration<'a, P: SearchProblem, O> {
+    problem: &'a P,
+    observer: &'a mut O,
+    path: Vec<P::State>,
+    visited: Vec<P::State>,
+    /// The states in `visited`, so a state reached along several paths is
+    /// listed once.
+    expanded: HashSet<P::State>,
+    /// Whether any branch of this iteration was cut off at the depth limit.
+    cut_off: bool,
+    /// The most promising state expanded so far and the path that reached it.
+    best: BestSoFar<P::State, P::Cost>,
+    best_path: Vec<P::State>,
+    /// The most depth left at each state reached in this iteration.
+    transpositions: Option<HashMap<P::State, usize>>,
+}
+
+/// How a depth-limited search ended.
+enum Limited {
+    Found,
+    Cutoff,
+    Stopped,
+}
+
+impl<P, O> Iteration<'_, P, O>
+where
+    P: SearchProblem,
+    O: SearchObserver<P::State>,
+{
+    /// Depth-limited search from `state`, entering at most `depth` states.
+    fn search(&mut self, state: P::State, depth: usize) -> Limited {
+        if depth == 0 {
+            self.cut_off = true;
+            self.observer.on_discard(&state);
+            return Limited::Cutoff; // Depth limit reached
+        }
+
+        if let Some(transpositions) = &mut self.transpositions {
+            if transpositions
+                .get(&state)
+                .is_some_and(|&left| left >= depth)
+            {
+                self.observer.on_discard(&state);
+                return Limited::Cutoff; // Already searched at least as deep
+            }
+            transpositions.insert(state.clone(), depth);
+        }
+
+        if self.observer.should_stop() {
+            return Limited::Stopped;
+        }
+
+        self.observer.on_expand(&state);
+        if self.expanded.insert(state.clone()) {
+            self.visited.push(state.clone());
+        }
+        self.path.push(state.clone());
+        if self.best.offer(&state, self.problem.heuristic(&state)) {
+            self.best_path.clone_from(&self.path);
+        }
+
+        if self.problem.is_goal(&state) {
+            return Limited::Found; // Target found
+        }
+
+        for (next, _) in self.problem.successors(&state) {
+            // Never walk back into the current path.
+            if self.path.contains(&next) {
+                continue;
+            }
+
+            self.observer.on_push(&next);
+            match self.search(next, depth - 1) {
+                Limited::Cutoff => {}
+                found_or_stopped => return found_or_stopped,
+            }
+        }
+
+        self.observer.on_close(&state);
+        self.path.pop();
+        Limited::Cutoff // Target not found at this level
+    }
+}
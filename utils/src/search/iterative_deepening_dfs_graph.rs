@@ -1,62 +1,163 @@
-use petgraph::graph::{Graph, NodeIndex};
+use crate::search::iterative_deepening_dfs::{
+    iterative_deepening_dfs_observed, iterative_deepening_dfs_with_transpositions_observed,
+};
+use crate::search::observer::{NoopObserver, SearchObserver};
+use crate::search::outcome::{validate_node, ContainsNode, SearchError, SearchOutcome};
+use crate::search::search_problem::GraphProblem;
+use petgraph::data::DataMap;
+use petgraph::visit::IntoEdges;
+use std::hash::Hash;
 
-pub fn iterative_deepening_dfs_graph<T>(
-    graph: &Graph<T, ()>,
-    start: NodeIndex,
-    target: Option<&T>,
+/// Iterative deepening search, up to `max_depth` nodes deep, over any petgraph
+/// graph or view that implements `IntoEdges` and `DataMap`, such as `Graph`,
+/// `StableGraph`, `NodeFiltered` or `Reversed`, ignoring edge weights.
+///
+/// Stops at the shallowest node whose weight equals `target`; its depth is
+/// `SearchOutcome::depth`. Nodes already on the current path are skipped, so
+/// cycles are never walked. Without a `target`, every reachable node is
+/// visited and the search ends `Exhausted`.
+pub fn iterative_deepening_dfs_graph<G>(
+    graph: G,
+    start: G::NodeId,
+    target: Option<&G::NodeWeight>,
     max_depth: usize,
-) -> Result<Vec<T>, Option<T>>
+) -> Result<SearchOutcome<G::NodeId>, SearchError>
 where
-    T: PartialEq + Clone,
+    G: IntoEdges + ContainsNode + DataMap,
+    G::NodeId: Eq + Hash,
+    G::NodeWeight: PartialEq,
 {
-    let mut visited = Vec::new();
-
-    for depth in 0..=max_depth {
-        let mut current_visited = Vec::new();
-        if depth_limited_dfs(graph, start, target, depth, &mut current_visited) {
-            if target.is_some() {
-                return Err(Some(current_visited.last().unwrap().clone()));
-            }
-        }
-        visited.extend(current_visited);
-    }
-
-    if target.is_none() {
-        Ok(visited) // Return all visited nodes
-    } else {
-        Err(None) // Target not found
-    }
+    iterative_deepening_dfs_graph_where(
+        graph,
+        start,
+        |node| target.is_some_and(|t| graph.node_weight(node) == Some(t)),
+        max_depth,
+    )
+}
+
+/// Iterative deepening search for the shallowest node satisfying `is_goal`.
+///
+/// Needs no node weights, so it also runs on graphs such as `GraphMap` or
+/// `EdgeFiltered` that do not implement `DataMap`.
+pub fn iterative_deepening_dfs_graph_where<G, F>(
+    graph: G,
+    start: G::NodeId,
+    is_goal: F,
+    max_depth: usize,
+) -> Result<SearchOutcome<G::NodeId>, SearchError>
+where
+    G: IntoEdges + ContainsNode,
+    G::NodeId: Eq + Hash,
+    F: Fn(G::NodeId) -> bool,
+{
+    iterative_deepening_dfs_graph_observed(graph, start, is_goal, max_depth, &mut NoopObserver)
+}
+
+pub fn iterative_deepening_dfs_graph_observed<G, F, O>(
+    graph: G,
+    start: G::NodeId,
+    is_goal: F,
+    max_depth: usize,
+    observer: &mut O,
+) -> Result<SearchOutcome<G::NodeId>, SearchError>
+where
+    G: IntoEdges + ContainsNode,
+    G::NodeId: Eq + Hash,
+    F: Fn(G::NodeId) -> bool,
+    O: SearchObserver<G::NodeId>,
+{
+    search_graph(graph, start, is_goal, max_depth, false, observer)
+}
+
+/// `iterative_deepening_dfs_graph` that prunes nodes already reached with as
+/// much depth left in the same iteration. Worth it on graphs with many paths
+/// between the same nodes.
+pub fn iterative_deepening_dfs_graph_with_transpositions<G>(
+    graph: G,
+    start: G::NodeId,
+    target: Option<&G::NodeWeight>,
+    max_depth: usize,
+) -> Result<SearchOutcome<G::NodeId>, SearchError>
+where
+    G: IntoEdges + ContainsNode + DataMap,
+    G::NodeId: Eq + Hash,
+    G::NodeWeight: PartialEq,
+{
+    iterative_deepening_dfs_graph_with_transpositions_where(
+        graph,
+        start,
+        |node| target.is_some_and(|t| graph.node_weight(node) == Some(t)),
+        max_depth,
+    )
+}
+
+/// `iterative_deepening_dfs_graph_where` with the transposition pruning of
+/// `iterative_deepening_dfs_graph_with_transpositions`.
+pub fn iterative_deepening_dfs_graph_with_transpositions_where<G, F>(
+    graph: G,
+    start: G::NodeId,
+    is_goal: F,
+    max_depth: usize,
+) -> Result<SearchOutcome<G::NodeId>, SearchError>
+where
+    G: IntoEdges + ContainsNode,
+    G::NodeId: Eq + Hash,
+    F: Fn(G::NodeId) -> bool,
+{
+    iterative_deepening_dfs_graph_with_transpositions_observed(
+        graph,
+        start,
+        is_goal,
+        max_depth,
+        &mut NoopObserver,
+    )
 }
 
-/// Helper function to perform Depth-Limited Search (DLS).
-fn depth_limited_dfs<T>(
-    graph: &Graph<T, ()>,
-    node: NodeIndex,
-    target: Option<&T>,
-    depth: usize,
-    visited: &mut Vec<T>,
-) -> bool
+/// `iterative_deepening_dfs_graph_with_transpositions_where` that reports its
+/// progress to `observer`.
+pub fn iterative_deepening_dfs_graph_with_transpositions_observed<G, F, O>(
+    graph: G,
+    start: G::NodeId,
+    is_goal: F,
+    max_depth: usize,
+    observer: &mut O,
+) -> Result<SearchOutcome<G::NodeId>, SearchError>
 where
-    T: PartialEq + Clone,
+    G: IntoEdges + ContainsNode,
+    G::NodeId: Eq + Hash,
+    F: Fn(G::NodeId) -> bool,
+    O: SearchObserver<G::NodeId>,
 {
-    if depth == 0 {
-        return false; // Depth limit reached
-    }
-
-    let value = graph[node].clone();
-    visited.push(value.clone());
-
-    if let Some(t) = target {
-        if &value == t {
-            return true; // Target found
-        }
-    }
-
-    for neighbor in graph.neighbors(node) {
-        if depth_limited_dfs(graph, neighbor, target, depth - 1, visited) {
-            return true; // Target found in neighbor
-        }
-    }
-
-    false // Target not found at this level
+    search_graph(graph, start, is_goal, max_depth, true, observer)
+}
+
+fn search_graph<G, F, O>(
+    graph: G,
+    start: G::NodeId,
+    is_goal: F,
+    max_depth: usize,
+    transpositions: bool,
+    observer: &mut O,
+) -> Result<SearchOutcome<G::NodeId>, SearchError>
+where
+    G: IntoEdges + ContainsNode,
+    G::NodeId: Eq + Hash,
+    F: Fn(G::NodeId) -> bool,
+    O: SearchObserver<G::NodeId>,
+{
+    validate_node(graph, start)?;
+
+    let problem = GraphProblem {
+        graph,
+        start,
+        edge_cost: |_: &G::EdgeWeight| 1,
+        is_goal,
+        heuristic: |_| 0,
+    };
+
+    Ok(if transpositions {
+        iterative_deepening_dfs_with_transpositions_observed(&problem, max_depth, observer)
+    } else {
+        iterative_deepening_dfs_observed(&problem, max_depth, observer)
+    })
 }
@@ -1,67 +1,75 @@
+use crate::pathfinding::grid_movement::Movement;
+use crate::search::iterative_deepening_dfs::iterative_deepening_dfs_with_transpositions_observed;
+use crate::search::observer::{NoopObserver, SearchObserver};
+use crate::search::outcome::{validate_grid, SearchError, SearchOutcome};
+use crate::search::search_problem::GridProblem;
+
+/// Iterative deepening search for a cell equal to `target`.
+///
+/// The path found is a shallowest one and holds at most `max_depth` cells.
+/// Grids join the same cells by many paths, so each iteration remembers the
+/// depth left at every cell it reached and never enters a cell again with no
+/// more depth to spare; this keeps open grids from taking exponential time.
+/// Fails if `grid` is empty or `start` lies outside it.
 pub fn iterative_deepening_dfs_grid<T: PartialEq>(
     grid: &[Vec<T>],
     start: (usize, usize),
     target: &T,
     max_depth: usize,
-) -> (bool, Option<(usize, usize)>) {
-    for depth in 0..=max_depth {
-        let mut visited = vec![vec![false; grid[0].len()]; grid.len()];
-        let mut visited_positions = Vec::new();
-        if let Some(position) =
-            depth_limited_dfs_grid(grid, start, target, depth, &mut visited, &mut visited_positions)
-        {
-            return (true, Some(position)); // Return the correct position of the target
-        }
-    }
-
-    (false, None) // Target not found
+) -> Result<SearchOutcome<(usize, usize)>, SearchError> {
+    iterative_deepening_dfs_grid_where(grid, start, |_| true, |cell| cell == target, max_depth)
 }
 
-fn depth_limited_dfs_grid<T: PartialEq>(
+/// Iterative deepening search for a passable cell satisfying `is_goal`.
+///
+/// Only cells for which `is_passable` holds are entered, so walls are never
+/// crossed. The returned path holds at most `max_depth` cells.
+pub fn iterative_deepening_dfs_grid_where<T, P, G>(
     grid: &[Vec<T>],
-    position: (usize, usize),
-    target: &T,
-    depth: usize,
-    visited: &mut Vec<Vec<bool>>,
-    visited_positions: &mut Vec<(usize, usize)>,
-) -> Option<(usize, usize)> {
-    let (row, col) = position;
-
-    if depth == 0 || row >= grid.len() || col >= grid[0].len() || visited[row][col] {
-        return None;
-    }
-
-    visited[row][col] = true;
-    visited_positions.push((row, col));
-
-    if &grid[row][col] == target {
-        return Some(position); // Return the target position
-    }
+    start: (usize, usize),
+    is_passable: P,
+    is_goal: G,
+    max_depth: usize,
+) -> Result<SearchOutcome<(usize, usize)>, SearchError>
+where
+    P: Fn(&T) -> bool,
+    G: Fn(&T) -> bool,
+{
+    iterative_deepening_dfs_grid_observed(
+        grid,
+        start,
+        is_passable,
+        is_goal,
+        max_depth,
+        &mut NoopObserver,
+    )
+}
 
-    let neighbors = [
-        (row.wrapping_sub(1), col), // Up
-        (row + 1, col),             // Down
-        (row, col.wrapping_sub(1)), // Left
-        (row, col + 1),             // Right
-    ];
+pub fn iterative_deepening_dfs_grid_observed<T, P, G, O>(
+    grid: &[Vec<T>],
+    start: (usize, usize),
+    is_passable: P,
+    is_goal: G,
+    max_depth: usize,
+    observer: &mut O,
+) -> Result<SearchOutcome<(usize, usize)>, SearchError>
+where
+    P: Fn(&T) -> bool,
+    G: Fn(&T) -> bool,
+    O: SearchObserver<(usize, usize)>,
+{
+    validate_grid(grid, start)?;
 
-    for &(next_row, next_col) in &neighbors {
-        if next_row < grid.len()
-            && next_col < grid[0].len()
-            && !visited[next_row][next_col]
-        {
-            if let Some(found_position) = depth_limited_dfs_grid(
-                grid,
-                (next_row, next_col),
-                target,
-                depth - 1,
-                visited,
-                visited_positions,
-            ) {
-                return Some(found_position); // Propagate the target position upwards
-            }
-        }
-    }
+    let problem = GridProblem {
+        grid,
+        start,
+        movement: Movement::four_way(),
+        step_cost: |cell: &T| is_passable(cell).then_some(1),
+        is_goal: |(row, col): (usize, usize)| is_goal(&grid[row][col]),
+        heuristic: |_| 0,
+    };
 
-    None // Target not found
+    Ok(iterative_deepening_dfs_with_transpositions_observed(
+        &problem, max_depth, observer,
+    ))
 }
@@ -0,0 +1,230 @@
+/// Receives progress events from a running search.
+///
+/// Every method defaults to doing nothing, so observers only implement the
+/// events they care about and `NoopObserver` compiles away entirely.
+///
+/// Each search `x` comes with an `x_observed` form that takes the arguments of
+/// `x`, or of `x_where` when there is one, followed by an observer, and reports
+/// its progress to it. The docs of an `_observed` form only cover what it adds.
+pub trait SearchObserver<S> {
+    /// `state` was added to the frontier.
+    fn on_push(&mut self, _state: &S) {}
+
+    /// `state` was taken off the frontier to be goal-tested and expanded.
+    fn on_expand(&mut self, _state: &S) {}
+
+    /// All successors of `state` have been generated.
+    fn on_close(&mut self, _state: &S) {}
+
+    /// `state` was taken off the frontier and dropped without being expanded,
+    /// because it was already expanded, has since been reached more cheaply or
+    /// lies beyond a depth limit.
+    fn on_discard(&mut self, _state: &S) {}
+
+    /// The search finished with `path` from the start to a goal.
+    fn on_path_found(&mut self, _path: &[S]) {}
+
+    /// Polled before each expansion; returning `true` ends the search with
+    /// `SearchOutcome::BudgetExhausted`.
+    fn should_stop(&mut self) -> bool {
+        false
+    }
+}
+
+/// Observer that ignores every event.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoopObserver;
+
+impl<S> SearchObserver<S> for NoopObserver {}
+
+impl<S, O: SearchObserver<S> + ?Sized> SearchObserver<S> for &mut O {
+    fn on_push(&mut self, state: &S) {
+        (**self).on_push(state);
+    }
+
+    fn on_expand(&mut self, state: &S) {
+        (**self).on_expand(state);
+    }
+
+    fn on_close(&mut self, state: &S) {
+        (**self).on_close(state);
+    }
+
+    fn on_discard(&mut self, state: &S) {
+        (**self).on_discard(state);
+    }
+
+    fn on_path_found(&mut self, path: &[S]) {
+        (**self).on_path_found(path);
+    }
+
+    fn should_stop(&mut self) -> bool {
+        (**self).should_stop()
+    }
+}
+
+/// Runs two observers side by side, e.g. a `SearchTrace` and a `SearchBudget`.
+/// The search stops as soon as either one asks it to.
+impl<S, A: SearchObserver<S>, B: SearchObserver<S>> SearchObserver<S> for (A, B) {
+    fn on_push(&mut self, state: &S) {
+        self.0.on_push(state);
+        self.1.on_push(state);
+    }
+
+    fn on_expand(&mut self, state: &S) {
+        self.0.on_expand(state);
+        self.1.on_expand(state);
+    }
+
+    fn on_close(&mut self, state: &S) {
+        self.0.on_close(state);
+        self.1.on_close(state);
+    }
+
+    fn on_discard(&mut self, state: &S) {
+        self.0.on_discard(state);
+        self.1.on_discard(state);
+    }
+
+    fn on_path_found(&mut self, path: &[S]) {
+        self.0.on_path_found(path);
+        self.1.on_path_found(path);
+    }
+
+    fn should_stop(&mut self) -> bool {
+        self.0.should_stop() || self.1.should_stop()
+    }
+}
+
+/// A single event reported to a `SearchObserver`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SearchEvent<S> {
+    Pushed(S),
+    Expanded(S),
+    Closed(S),
+    Discarded(S),
+    PathFound(Vec<S>),
+}
+
+/// Observer that records every event so a search can be inspected or replayed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchTrace<S> {
+    events: Vec<SearchEvent<S>>,
+}
+
+impl<S> Default for SearchTrace<S> {
+    fn default() -> Self {
+        SearchTrace { events: Vec::new() }
+    }
+}
+
+impl<S> SearchTrace<S> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn events(&self) -> &[SearchEvent<S>] {
+        &self.events
+    }
+
+    /// States in the order they were expanded.
+    pub fn expanded(&self) -> Vec<&S> {
+        self.events
+            .iter()
+            .filter_map(|event| match event {
+                SearchEvent::Expanded(state) => Some(state),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// The path reported at the end of the search, if any.
+    pub fn path(&self) -> Option<&[S]> {
+        self.events.iter().rev().find_map(|event| match event {
+            SearchEvent::PathFound(path) => Some(path.as_slice()),
+            _ => None,
+        })
+    }
+
+    /// Feeds the recorded events, in order, to another observer.
+    pub fn replay<O: SearchObserver<S>>(&self, observer: &mut O) {
+        for event in &self.events {
+            match event {
+                SearchEvent::Pushed(state) => observer.on_push(state),
+                SearchEvent::Expanded(state) => observer.on_expand(state),
+                SearchEvent::Closed(state) => observer.on_close(state),
+                SearchEvent::Discarded(state) => observer.on_discard(state),
+                SearchEvent::PathFound(path) => observer.on_path_found(path),
+            }
+        }
+    }
+}
+
+impl<S: Clone> SearchObserver<S> for SearchTrace<S> {
+    fn on_push(&mut self, state: &S) {
+        self.events.push(SearchEvent::Pushed(state.clone()));
+    }
+
+    fn on_expand(&mut self, state: &S) {
+        self.events.push(SearchEvent::Expanded(state.clone()));
+    }
+
+    fn on_close(&mut self, state: &S) {
+        self.events.push(SearchEvent::Closed(state.clone()));
+    }
+
+    fn on_discard(&mut self, state: &S) {
+        self.events.push(SearchEvent::Discarded(state.clone()));
+    }
+
+    fn on_path_found(&mut self, path: &[S]) {
+        self.events.push(SearchEvent::PathFound(path.to_vec()));
+    }
+}
+
+/// Forwards every event to `inner` while recording the expansion order, for
+/// searches that do not otherwise keep one.
+pub(crate) struct VisitRecorder<'a, S, O> {
+    inner: &'a mut O,
+    visited: Vec<S>,
+}
+
+impl<'a, S, O: SearchObserver<S>> VisitRecorder<'a, S, O> {
+    pub(crate) fn new(inner: &'a mut O) -> Self {
+        VisitRecorder {
+            inner,
+            visited: Vec::new(),
+        }
+    }
+
+    pub(crate) fn into_visited(self) -> Vec<S> {
+        self.visited
+    }
+}
+
+impl<S: Clone, O: SearchObserver<S>> SearchObserver<S> for VisitRecorder<'_, S, O> {
+    fn on_push(&mut self, state: &S) {
+        self.inner.on_push(state);
+    }
+
+    fn on_expand(&mut self, state: &S) {
+        self.visited.push(state.clone());
+        self.inner.on_expand(state);
+    }
+
+    fn on_close(&mut self, state: &S) {
+        self.inner.on_close(state);
+    }
+
+    fn on_discard(&mut self, state: &S) {
+        self.inner.on_discard(state);
+    }
+
+    fn on_path_found(&mut self, path: &[S]) {
+        self.inner.on_path_found(path);
+    }
+
+    fn should_stop(&mut self) -> bool {
+        self.inner.should_stop()
+    }
+}
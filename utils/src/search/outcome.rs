@@ -0,0 +1,204 @@
+use petgraph::graph::{Graph, IndexType};
+use petgraph::graphmap::{GraphMap, NodeTrait};
+use petgraph::stable_graph::StableGraph;
+use petgraph::visit::{
+    EdgeFiltered, FilterNode, GraphBase, NodeFiltered, Reversed, UndirectedAdaptor,
+};
+use petgraph::EdgeType;
+use std::fmt;
+use std::hash::BuildHasher;
+
+/// How a search ended.
+///
+/// `C` is the path cost: the number of steps for the unweighted searches and the
+/// summed step costs for the weighted ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SearchOutcome<S, C = usize> {
+    /// A goal was reached.
+    Found {
+        goal: S,
+        /// Path from the start to `goal`, inclusive.
+        path: Vec<S>,
+        cost: C,
+        /// States in the order they were expanded.
+        visited: Vec<S>,
+    },
+    /// Every reachable state was expanded without reaching a goal.
+    Exhausted {
+        /// States in the order they were expanded.
+        visited: Vec<S>,
+    },
+    /// The observer stopped the search, usually because a `SearchBudget` ran
+    /// out, before a goal was reached.
+    BudgetExhausted {
+        /// Path from the start to the most promising state expanded so far.
+        best_path: Vec<S>,
+        /// States in the order they were expanded.
+        visited: Vec<S>,
+    },
+}
+
+impl<S, C> SearchOutcome<S, C> {
+    pub fn is_found(&self) -> bool {
+        matches!(self, SearchOutcome::Found { .. })
+    }
+
+    pub fn is_budget_exhausted(&self) -> bool {
+        matches!(self, SearchOutcome::BudgetExhausted { .. })
+    }
+
+    pub fn goal(&self) -> Option<&S> {
+        match self {
+            SearchOutcome::Found { goal, .. } => Some(goal),
+            SearchOutcome::Exhausted { .. } | SearchOutcome::BudgetExhausted { .. } => None,
+        }
+    }
+
+    pub fn path(&self) -> Option<&[S]> {
+        match self {
+            SearchOutcome::Found { path, .. } => Some(path),
+            SearchOutcome::Exhausted { .. } | SearchOutcome::BudgetExhausted { .. } => None,
+        }
+    }
+
+    pub fn cost(&self) -> Option<&C> {
+        match self {
+            SearchOutcome::Found { cost, .. } => Some(cost),
+            SearchOutcome::Exhausted { .. } | SearchOutcome::BudgetExhausted { .. } => None,
+        }
+    }
+
+    /// Number of steps in the path found, which for breadth-first and
+    /// iterative deepening searches is the depth of the shallowest goal.
+    pub fn depth(&self) -> Option<usize> {
+        self.path().map(|path| path.len() - 1)
+    }
+
+    /// The path to a goal if one was found, otherwise the best partial path
+    /// of a search that ran out of budget.
+    pub fn best_path(&self) -> Option<&[S]> {
+        match self {
+            SearchOutcome::Found { path, .. } => Some(path),
+            SearchOutcome::BudgetExhausted { best_path, .. } => Some(best_path),
+            SearchOutcome::Exhausted { .. } => None,
+        }
+    }
+
+    pub fn visited(&self) -> &[S] {
+        match self {
+            SearchOutcome::Found { visited, .. }
+            | SearchOutcome::Exhausted { visited }
+            | SearchOutcome::BudgetExhausted { visited, .. } => visited,
+        }
+    }
+
+    pub fn into_path(self) -> Option<Vec<S>> {
+        match self {
+            SearchOutcome::Found { path, .. } => Some(path),
+            SearchOutcome::Exhausted { .. } | SearchOutcome::BudgetExhausted { .. } => None,
+        }
+    }
+}
+
+/// Why a search could not be run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchError {
+    /// The grid has no rows or no columns.
+    EmptyGrid,
+    /// The start position lies outside the grid.
+    StartOutOfBounds { row: usize, col: usize },
+    /// A node passed to the search is not part of the graph. Every graph search
+    /// checks the nodes it is given before it begins.
+    InvalidNode,
+}
+
+impl fmt::Display for SearchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SearchError::EmptyGrid => write!(f, "the grid is empty"),
+            SearchError::StartOutOfBounds { row, col } => {
+                write!(f, "start position ({row}, {col}) is outside the grid")
+            }
+            SearchError::InvalidNode => write!(f, "the node is not in the graph"),
+        }
+    }
+}
+
+impl std::error::Error for SearchError {}
+
+/// Checks that `grid` has cells and that `start` is one of them.
+pub(crate) fn validate_grid<T>(grid: &[Vec<T>], start: (usize, usize)) -> Result<(), SearchError> {
+    if grid.is_empty() || grid[0].is_empty() {
+        return Err(SearchError::EmptyGrid);
+    }
+
+    let (row, col) = start;
+    if row >= grid.len() || col >= grid[row].len() {
+        return Err(SearchError::StartOutOfBounds { row, col });
+    }
+
+    Ok(())
+}
+
+/// Checks that `node` is one of the nodes of `graph`.
+pub(crate) fn validate_node<G: ContainsNode>(graph: G, node: G::NodeId) -> Result<(), SearchError> {
+    if graph.contains_node(node) {
+        Ok(())
+    } else {
+        Err(SearchError::InvalidNode)
+    }
+}
+
+/// A graph that can tell whether a node belongs to it without walking all of
+/// its nodes. The graph searches use it to reject a bad start up front.
+pub trait ContainsNode: GraphBase {
+    fn contains_node(&self, node: Self::NodeId) -> bool;
+}
+
+impl<G: ContainsNode> ContainsNode for &G {
+    fn contains_node(&self, node: G::NodeId) -> bool {
+        (**self).contains_node(node)
+    }
+}
+
+impl<N, E, Ty: EdgeType, Ix: IndexType> ContainsNode for Graph<N, E, Ty, Ix> {
+    fn contains_node(&self, node: Self::NodeId) -> bool {
+        self.node_weight(node).is_some()
+    }
+}
+
+impl<N, E, Ty: EdgeType, Ix: IndexType> ContainsNode for StableGraph<N, E, Ty, Ix> {
+    fn contains_node(&self, node: Self::NodeId) -> bool {
+        StableGraph::contains_node(self, node)
+    }
+}
+
+impl<N: NodeTrait, E, Ty: EdgeType, S: BuildHasher> ContainsNode for GraphMap<N, E, Ty, S> {
+    fn contains_node(&self, node: N) -> bool {
+        GraphMap::contains_node(self, node)
+    }
+}
+
+impl<G: ContainsNode, F: FilterNode<G::NodeId>> ContainsNode for NodeFiltered<G, F> {
+    fn contains_node(&self, node: G::NodeId) -> bool {
+        self.0.contains_node(node) && self.1.include_node(node)
+    }
+}
+
+impl<G: ContainsNode, F> ContainsNode for EdgeFiltered<G, F> {
+    fn contains_node(&self, node: G::NodeId) -> bool {
+        self.0.contains_node(node)
+    }
+}
+
+impl<G: ContainsNode> ContainsNode for Reversed<G> {
+    fn contains_node(&self, node: G::NodeId) -> bool {
+        self.0.contains_node(node)
+    }
+}
+
+impl<G: ContainsNode> ContainsNode for UndirectedAdaptor<G> {
+    fn contains_node(&self, node: G::NodeId) -> bool {
+        self.0.contains_node(node)
+    }
+}
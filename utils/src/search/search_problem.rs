@@ -0,0 +1,155 @@
+use crate::pathfinding::cost::Cost;
+use crate::pathfinding::grid_movement::Movement;
+use petgraph::visit::{EdgeRef, GraphBase, IntoEdges};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A state space explored by the generic searches in `utils::search` and
+/// `utils::pathfinding`. States are produced on demand, so the space never has
+/// to be materialized up front.
+pub trait SearchProblem {
+    type State: Clone + Eq + Hash;
+    /// Step and path cost, such as `usize` or `f64`.
+    type Cost: Cost;
+
+    fn initial_state(&self) -> Self::State;
+
+    /// States reachable in one step from `state`, paired with the cost of that step.
+    fn successors(&self, state: &Self::State) -> Vec<(Self::State, Self::Cost)>;
+
+    fn is_goal(&self, state: &Self::State) -> bool;
+
+    /// Estimated cost from `state` to the nearest goal. A* is only optimal
+    /// when this never overestimates.
+    fn heuristic(&self, _state: &Self::State) -> Self::Cost {
+        Self::Cost::ZERO
+    }
+}
+
+impl<P: SearchProblem + ?Sized> SearchProblem for &P {
+    type State = P::State;
+    type Cost = P::Cost;
+
+    fn initial_state(&self) -> Self::State {
+        (**self).initial_state()
+    }
+
+    fn successors(&self, state: &Self::State) -> Vec<(Self::State, Self::Cost)> {
+        (**self).successors(state)
+    }
+
+    fn is_goal(&self, state: &Self::State) -> bool {
+        (**self).is_goal(state)
+    }
+
+    fn heuristic(&self, state: &Self::State) -> Self::Cost {
+        (**self).heuristic(state)
+    }
+}
+
+/// Walks `came_from` back from `goal`, returning the path from the initial state.
+pub(crate) fn reconstruct_path<S>(came_from: &HashMap<S, S>, goal: S) -> Vec<S>
+where
+    S: Clone + Eq + Hash,
+{
+    let mut path = vec![goal];
+    while let Some(parent) = came_from.get(path.last().unwrap()) {
+        path.push(parent.clone());
+    }
+    path.reverse();
+    path
+}
+
+/// Total cost of walking `path`, taking the cheapest step wherever several
+/// successors lead to the next state.
+pub(crate) fn path_cost<P: SearchProblem>(problem: &P, path: &[P::State]) -> P::Cost {
+    path.windows(2).fold(P::Cost::ZERO, |total, pair| {
+        let step = problem
+            .successors(&pair[0])
+            .into_iter()
+            .filter(|(state, _)| *state == pair[1])
+            .map(|(_, cost)| cost)
+            .min_by(|a, b| a.total_cmp(b))
+            .unwrap_or(P::Cost::ZERO);
+        total.saturating_add(step)
+    })
+}
+
+/// Adapts any petgraph graph or view implementing `IntoEdges` to `SearchProblem`.
+pub(crate) struct GraphProblem<G: GraphBase, W, Gl, H> {
+    pub graph: G,
+    pub start: G::NodeId,
+    pub edge_cost: W,
+    pub is_goal: Gl,
+    pub heuristic: H,
+}
+
+impl<G, C, W, Gl, H> SearchProblem for GraphProblem<G, W, Gl, H>
+where
+    G: IntoEdges,
+    G::NodeId: Eq + Hash,
+    C: Cost,
+    W: Fn(&G::EdgeWeight) -> C,
+    Gl: Fn(G::NodeId) -> bool,
+    H: Fn(G::NodeId) -> C,
+{
+    type State = G::NodeId;
+    type Cost = C;
+
+    fn initial_state(&self) -> G::NodeId {
+        self.start
+    }
+
+    fn successors(&self, state: &G::NodeId) -> Vec<(G::NodeId, C)> {
+        self.graph
+            .edges(*state)
+            .map(|edge| (edge.target(), (self.edge_cost)(edge.weight())))
+            .collect()
+    }
+
+    fn is_goal(&self, state: &G::NodeId) -> bool {
+        (self.is_goal)(*state)
+    }
+
+    fn heuristic(&self, state: &G::NodeId) -> C {
+        (self.heuristic)(*state)
+    }
+}
+
+/// Adapts a `&[Vec<T>]` grid to `SearchProblem`, moving between cells as allowed
+/// by `movement`. `step_cost` gives the cost of entering a cell, or `None` if the
+/// cell is impassable.
+pub(crate) struct GridProblem<'a, T, C, G, H> {
+    pub grid: &'a [Vec<T>],
+    pub start: (usize, usize),
+    pub movement: Movement,
+    pub step_cost: C,
+    pub is_goal: G,
+    pub heuristic: H,
+}
+
+impl<T, C, G, H> SearchProblem for GridProblem<'_, T, C, G, H>
+where
+    C: Fn(&T) -> Option<usize>,
+    G: Fn((usize, usize)) -> bool,
+    H: Fn((usize, usize)) -> usize,
+{
+    type State = (usize, usize);
+    type Cost = usize;
+
+    fn initial_state(&self) -> (usize, usize) {
+        self.start
+    }
+
+    fn successors(&self, &position: &(usize, usize)) -> Vec<((usize, usize), usize)> {
+        self.movement.moves(self.grid, position, &self.step_cost)
+    }
+
+    fn is_goal(&self, state: &(usize, usize)) -> bool {
+        (self.is_goal)(*state)
+    }
+
+    fn heuristic(&self, state: &(usize, usize)) -> usize {
+        (self.heuristic)(*state)
+    }
+}
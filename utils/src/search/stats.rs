@@ -0,0 +1,88 @@
+use crate::search::observer::SearchObserver;
+use std::collections::HashSet;
+use std::hash::Hash;
+use std::time::{Duration, Instant};
+
+/// Counters describing how much work a search did.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchStats<C = usize> {
+    /// States taken off the frontier and expanded, re-expansions included.
+    pub expanded: usize,
+    /// States added to the frontier.
+    pub generated: usize,
+    /// Largest number of entries waiting on the frontier at once.
+    pub max_frontier: usize,
+    /// Expansions of a state that had already been expanded. Iterative
+    /// deepening counts every repeat from a deeper iteration here.
+    pub reopened: usize,
+    /// Cost of the path found, if any.
+    pub path_cost: Option<C>,
+    /// Wall-clock time from creating the `StatsObserver` to `finish`.
+    pub elapsed: Duration,
+}
+
+/// Observer that gathers `SearchStats` from any `_observed` search.
+#[derive(Debug, Clone)]
+pub struct StatsObserver<S> {
+    started: Instant,
+    expanded: usize,
+    generated: usize,
+    frontier: usize,
+    max_frontier: usize,
+    reopened: usize,
+    seen: HashSet<S>,
+}
+
+impl<S> StatsObserver<S> {
+    /// Starts the clock; create the observer right before running the search.
+    pub fn new() -> Self {
+        StatsObserver {
+            started: Instant::now(),
+            expanded: 0,
+            generated: 0,
+            frontier: 0,
+            max_frontier: 0,
+            reopened: 0,
+            seen: HashSet::new(),
+        }
+    }
+
+    /// Stops the clock and returns the counters along with `path_cost`, which
+    /// is usually `outcome.cost().copied()`.
+    pub fn finish<C>(self, path_cost: Option<C>) -> SearchStats<C> {
+        SearchStats {
+            expanded: self.expanded,
+            generated: self.generated,
+            max_frontier: self.max_frontier,
+            reopened: self.reopened,
+            path_cost,
+            elapsed: self.started.elapsed(),
+        }
+    }
+}
+
+impl<S> Default for StatsObserver<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: Clone + Eq + Hash> SearchObserver<S> for StatsObserver<S> {
+    fn on_push(&mut self, _state: &S) {
+        self.generated += 1;
+        self.frontier += 1;
+        self.max_frontier = self.max_frontier.max(self.frontier);
+    }
+
+    fn on_expand(&mut self, state: &S) {
+        self.expanded += 1;
+        self.frontier = self.frontier.saturating_sub(1);
+        if !self.seen.insert(state.clone()) {
+            self.reopened += 1;
+        }
+    }
+
+    fn on_discard(&mut self, _state: &S) {
+        self.frontier = self.frontier.saturating_sub(1);
+    }
+}
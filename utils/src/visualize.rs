@@ -0,0 +1,303 @@
+use crate::search::observer::{SearchEvent, SearchTrace};
+use std::fmt;
+use std::io::Write;
+
+/// An RGB color.
+pub type Rgb = [u8; 3];
+
+/// What a grid cell is showing at a given point of a search.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CellState {
+    Empty,
+    Wall,
+    Frontier,
+    Closed,
+    Path,
+}
+
+impl CellState {
+    const ALL: [CellState; 5] = [
+        CellState::Empty,
+        CellState::Wall,
+        CellState::Frontier,
+        CellState::Closed,
+        CellState::Path,
+    ];
+}
+
+/// Colors used for each `CellState`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Palette {
+    pub empty: Rgb,
+    pub wall: Rgb,
+    pub frontier: Rgb,
+    pub closed: Rgb,
+    pub path: Rgb,
+}
+
+impl Palette {
+    pub fn color(&self, state: CellState) -> Rgb {
+        match state {
+            CellState::Empty => self.empty,
+            CellState::Wall => self.wall,
+            CellState::Frontier => self.frontier,
+            CellState::Closed => self.closed,
+            CellState::Path => self.path,
+        }
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Palette {
+            empty: [255, 255, 255],
+            wall: [40, 40, 40],
+            frontier: [120, 200, 120],
+            closed: [170, 200, 240],
+            path: [230, 80, 60],
+        }
+    }
+}
+
+/// Controls how a trace is drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RenderOptions {
+    /// Width and height of a single cell, in pixels.
+    pub cell_size: usize,
+    /// Playback speed of the animation.
+    pub frames_per_second: u16,
+    /// Number of expansions shown between consecutive frames.
+    pub expansions_per_frame: usize,
+    pub palette: Palette,
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        RenderOptions {
+            cell_size: 16,
+            frames_per_second: 10,
+            expansions_per_frame: 1,
+            palette: Palette::default(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum VisualizeError {
+    /// The grid has no cells to draw.
+    EmptyGrid,
+    /// The rendered image does not fit the output format.
+    ImageTooLarge {
+        width: usize,
+        height: usize,
+    },
+    Gif(gif::EncodingError),
+    Png(png::EncodingError),
+}
+
+impl fmt::Display for VisualizeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VisualizeError::EmptyGrid => write!(f, "cannot render an empty grid"),
+            VisualizeError::ImageTooLarge { width, height } => {
+                write!(f, "a {width}x{height} image is too large to encode")
+            }
+            VisualizeError::Gif(error) => write!(f, "failed to encode GIF: {error}"),
+            VisualizeError::Png(error) => write!(f, "failed to encode PNG: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for VisualizeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            VisualizeError::Gif(error) => Some(error),
+            VisualizeError::Png(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<gif::EncodingError> for VisualizeError {
+    fn from(error: gif::EncodingError) -> Self {
+        VisualizeError::Gif(error)
+    }
+}
+
+impl From<png::EncodingError> for VisualizeError {
+    fn from(error: png::EncodingError) -> Self {
+        VisualizeError::Png(error)
+    }
+}
+
+/// Replays `trace` over `grid`, returning the state of every cell after each
+/// `expansions_per_frame` expansions. The last snapshot shows the finished
+/// search, including the path if one was found.
+pub fn snapshots<T, F>(
+    grid: &[Vec<T>],
+    is_wall: F,
+    trace: &SearchTrace<(usize, usize)>,
+    expansions_per_frame: usize,
+) -> Vec<Vec<Vec<CellState>>>
+where
+    F: Fn(&T) -> bool,
+{
+    let expansions_per_frame = expansions_per_frame.max(1);
+    let mut cells: Vec<Vec<CellState>> = grid
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|cell| {
+                    if is_wall(cell) {
+                        CellState::Wall
+                    } else {
+                        CellState::Empty
+                    }
+                })
+                .collect()
+        })
+        .collect();
+    let mut frames = Vec::new();
+    let mut expansions = 0;
+
+    for event in trace.events() {
+        match event {
+            SearchEvent::Pushed(position) => mark(&mut cells, *position, CellState::Frontier),
+            SearchEvent::Expanded(position) => {
+                // Snapshot before the expansion so each frame ends with the
+                // successors of the previous one on the frontier.
+                if expansions > 0 && expansions % expansions_per_frame == 0 {
+                    frames.push(cells.clone());
+                }
+                expansions += 1;
+                mark(&mut cells, *position, CellState::Closed);
+            }
+            SearchEvent::Closed(position) => mark(&mut cells, *position, CellState::Closed),
+            SearchEvent::Discarded(_) => {}
+            SearchEvent::PathFound(path) => {
+                for &position in path {
+                    mark(&mut cells, position, CellState::Path);
+                }
+            }
+        }
+    }
+
+    frames.push(cells);
+    frames
+}
+
+/// Writes `trace` as an animated GIF that loops forever.
+pub fn write_gif<T, F, W>(
+    writer: W,
+    grid: &[Vec<T>],
+    is_wall: F,
+    trace: &SearchTrace<(usize, usize)>,
+    options: &RenderOptions,
+) -> Result<(), VisualizeError>
+where
+    F: Fn(&T) -> bool,
+    W: Write,
+{
+    let (width, height) = image_size(grid, options.cell_size)?;
+    let (Ok(gif_width), Ok(gif_height)) = (u16::try_from(width), u16::try_from(height)) else {
+        return Err(VisualizeError::ImageTooLarge { width, height });
+    };
+
+    // Every frame indexes into the same palette, laid out in `CellState::ALL` order.
+    let palette: Vec<u8> = CellState::ALL
+        .iter()
+        .flat_map(|&state| options.palette.color(state))
+        .collect();
+    let delay = (100 / options.frames_per_second.max(1)).max(1);
+
+    let mut encoder = gif::Encoder::new(writer, gif_width, gif_height, &palette)?;
+    encoder.set_repeat(gif::Repeat::Infinite)?;
+
+    for cells in snapshots(grid, is_wall, trace, options.expansions_per_frame) {
+        let buffer = rasterize(&cells, width, options.cell_size, |state| [state as u8]);
+        let frame = gif::Frame {
+            width: gif_width,
+            height: gif_height,
+            delay,
+            buffer: buffer.into(),
+            ..gif::Frame::default()
+        };
+        encoder.write_frame(&frame)?;
+    }
+
+    Ok(())
+}
+
+/// Writes the final state of `trace` as an RGB PNG.
+pub fn write_png<T, F, W>(
+    writer: W,
+    grid: &[Vec<T>],
+    is_wall: F,
+    trace: &SearchTrace<(usize, usize)>,
+    options: &RenderOptions,
+) -> Result<(), VisualizeError>
+where
+    F: Fn(&T) -> bool,
+    W: Write,
+{
+    let (width, height) = image_size(grid, options.cell_size)?;
+    let (Ok(png_width), Ok(png_height)) = (u32::try_from(width), u32::try_from(height)) else {
+        return Err(VisualizeError::ImageTooLarge { width, height });
+    };
+
+    let cells = snapshots(grid, is_wall, trace, usize::MAX)
+        .pop()
+        .expect("snapshots always ends with the final frame");
+    let buffer = rasterize(&cells, width, options.cell_size, |state| {
+        options.palette.color(state)
+    });
+
+    let mut encoder = png::Encoder::new(writer, png_width, png_height);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut png_writer = encoder.write_header()?;
+    png_writer.write_image_data(&buffer)?;
+    png_writer.finish()?;
+
+    Ok(())
+}
+
+fn mark(cells: &mut [Vec<CellState>], (row, col): (usize, usize), state: CellState) {
+    if let Some(cell) = cells.get_mut(row).and_then(|line| line.get_mut(col)) {
+        if *cell != CellState::Wall {
+            *cell = state;
+        }
+    }
+}
+
+fn image_size<T>(grid: &[Vec<T>], cell_size: usize) -> Result<(usize, usize), VisualizeError> {
+    if grid.is_empty() || grid[0].is_empty() || cell_size == 0 {
+        return Err(VisualizeError::EmptyGrid);
+    }
+
+    Ok((grid[0].len() * cell_size, grid.len() * cell_size))
+}
+
+/// Scales every cell up to a `cell_size` square of pixels.
+fn rasterize<const N: usize>(
+    cells: &[Vec<CellState>],
+    width: usize,
+    cell_size: usize,
+    pixel: impl Fn(CellState) -> [u8; N],
+) -> Vec<u8> {
+    let mut buffer = Vec::with_capacity(width * cells.len() * cell_size * N);
+
+    for row in cells {
+        let mut line = Vec::with_capacity(width * N);
+        for &state in row {
+            for _ in 0..cell_size {
+                line.extend_from_slice(&pixel(state));
+            }
+        }
+        for _ in 0..cell_size {
+            buffer.extend_from_slice(&line);
+        }
+    }
+
+    buffer
+}
@@ -1,5 +1,8 @@
-use petgraph::graph::{Graph};
-use utils::pathfinding::a_star_graph::astar_graph;
+use petgraph::graph::{Graph, NodeIndex};
+use petgraph::graphmap::DiGraphMap;
+use petgraph::visit::EdgeFiltered;
+use utils::pathfinding::a_star::SearchProgress;
+use utils::pathfinding::a_star_graph::{astar_graph, astar_graph_search, astar_graph_to_any};
 
 #[test]
 fn test_astar_graph_no_obstacles() {
@@ -21,10 +24,10 @@ fn test_astar_graph_no_obstacles() {
     let goal = e;
 
     // Act: Perform A*.
-    let path = astar_graph(&graph, start, goal, |_current, _goal| 0);
+    let result = astar_graph(&graph, start, goal, |_current, _goal| 0).unwrap();
 
     // Assert: Verify the path is valid.
-    assert!(!path.is_empty(), "A* should find a path.");
+    let path = result.path().expect("A* should find a path.");
     assert_eq!(
         path.first(),
         Some(&start),
@@ -58,10 +61,10 @@ fn test_astar_graph_with_cycles() {
     let goal = e;
 
     // Act: Perform A*.
-    let path = astar_graph(&graph, start, goal, |_current, _goal| 0);
+    let result = astar_graph(&graph, start, goal, |_current, _goal| 0).unwrap();
 
     // Assert: Verify the path avoids cycles and is valid.
-    assert!(!path.is_empty(), "A* should find a valid path.");
+    let path = result.path().expect("A* should find a valid path.");
     assert_eq!(
         path.first(),
         Some(&start),
@@ -90,13 +93,14 @@ fn test_astar_graph_no_solution() {
     let goal = graph.add_node("D"); // Disconnected node.
 
     // Act: Perform A*.
-    let path = astar_graph(&graph, start, goal, |_current, _goal| 0);
+    let result = astar_graph(&graph, start, goal, |_current, _goal| 0).unwrap();
 
-    // Assert: Verify that A* returns an empty path when no solution exists.
+    // Assert: Verify that A* exhausts the graph when no solution exists.
     assert!(
-        path.is_empty(),
-        "A* should return an empty path when no solution exists."
+        !result.is_found(),
+        "A* should not find a path when no solution exists."
     );
+    assert_eq!(result.visited(), [a, b, c]);
 }
 
 #[test]
@@ -117,13 +121,12 @@ fn test_astar_graph_target_edge_case() {
     let goal = d; // Edge node.
 
     // Act: Perform A*.
-    let path = astar_graph(&graph, start, goal, |_current, _goal| 0);
+    let result = astar_graph(&graph, start, goal, |_current, _goal| 0).unwrap();
 
     // Assert: Verify the path is valid and reaches the edge target.
-    assert!(
-        !path.is_empty(),
-        "A* should find a valid path to the edge target."
-    );
+    let path = result
+        .path()
+        .expect("A* should find a valid path to the edge target.");
     assert_eq!(
         path.first(),
         Some(&start),
@@ -135,3 +138,120 @@ fn test_astar_graph_target_edge_case() {
         "Path should end at the edge target."
     );
 }
+
+#[test]
+fn test_astar_graph_float_weights_with_euclidean_heuristic() {
+    // Arrange: nodes are points on a plane and edges are straight roads between them.
+    let positions = [(0.0, 0.0), (1.0, 1.0), (1.0, -0.5), (2.0, 0.0)];
+    let distance = |from: NodeIndex, to: NodeIndex| {
+        let (p, q): ((f64, f64), (f64, f64)) = (positions[from.index()], positions[to.index()]);
+        ((p.0 - q.0).powi(2) + (p.1 - q.1).powi(2)).sqrt()
+    };
+
+    let mut graph = Graph::<&str, f64>::new();
+    let a = graph.add_node("A");
+    let b = graph.add_node("B");
+    let c = graph.add_node("C");
+    let d = graph.add_node("D");
+    for (from, to) in [(a, b), (b, d), (a, c), (c, d)] {
+        graph.add_edge(from, to, distance(from, to));
+    }
+
+    // Act
+    let path = astar_graph(&graph, a, d, distance).unwrap().into_path();
+
+    // Assert: the route through C is shorter (2.24 against 2.83).
+    assert_eq!(path, Some(vec![a, c, d]));
+}
+
+#[test]
+fn test_astar_graph_graph_map() {
+    // Arrange: a GraphMap whose nodes are their own names.
+    let graph = DiGraphMap::<&str, usize>::from_edges([
+        ("A", "B", 1),
+        ("B", "D", 1),
+        ("A", "C", 1),
+        ("C", "D", 5),
+    ]);
+
+    // Act
+    let path = astar_graph(&graph, "A", "D", |_current, _goal| 0)
+        .unwrap()
+        .into_path();
+
+    // Assert
+    assert_eq!(path, Some(vec!["A", "B", "D"]));
+}
+
+#[test]
+fn test_astar_graph_edge_filtered_view() {
+    // Arrange: hide the cheap shortcut so A* has to take the long way round.
+    let mut graph = Graph::<&str, usize>::new();
+    let a = graph.add_node("A");
+    let b = graph.add_node("B");
+    let c = graph.add_node("C");
+    graph.add_edge(a, c, 1);
+    graph.add_edge(a, b, 2);
+    graph.add_edge(b, c, 2);
+    let view = EdgeFiltered::from_fn(&graph, |edge| *edge.weight() > 1);
+
+    // Act
+    let unfiltered = astar_graph(&graph, a, c, |_current, _goal| 0).unwrap();
+    let filtered = astar_graph(&view, a, c, |_current, _goal| 0).unwrap();
+
+    // Assert
+    assert_eq!(unfiltered.path(), Some(&[a, c][..]));
+    assert_eq!(filtered.path(), Some(&[a, b, c][..]));
+}
+
+#[test]
+fn test_astar_graph_search_one_expansion_per_step() {
+    // Arrange
+    let mut graph = Graph::<&str, usize>::new();
+    let a = graph.add_node("A");
+    let b = graph.add_node("B");
+    let c = graph.add_node("C");
+    graph.add_edge(a, b, 1);
+    graph.add_edge(b, c, 1);
+    graph.add_edge(a, c, 5);
+    let mut search = astar_graph_search(&graph, a, c, |_, _| 0).unwrap();
+
+    // Act & Assert
+    assert_eq!(search.step(1), SearchProgress::Running);
+    assert_eq!(search.visited(), [a]);
+    assert_eq!(search.step(1), SearchProgress::Running);
+    assert_eq!(search.visited(), [a, b]);
+    assert_eq!(search.step(1), SearchProgress::Finished);
+    assert_eq!(search.outcome().unwrap().path(), Some(&[a, b, c][..]));
+    assert_eq!(
+        search.into_outcome(),
+        astar_graph(&graph, a, c, |_, _| 0).unwrap()
+    );
+}
+
+#[test]
+fn test_astar_graph_to_any_prefers_cheapest_goal_over_closest() {
+    // Arrange: exit X lies closest as the crow flies, but its road winds.
+    let mut graph = Graph::<(f64, f64), f64>::new();
+    let start = graph.add_node((0.0, 0.0));
+    let bend = graph.add_node((0.0, 5.0));
+    let x = graph.add_node((2.0, 0.0));
+    let y = graph.add_node((-4.0, 0.0));
+    graph.add_edge(start, bend, 5.0);
+    graph.add_edge(bend, x, 29f64.sqrt());
+    graph.add_edge(start, y, 4.0);
+    let distance = |a: NodeIndex, b: NodeIndex| {
+        let ((ax, ay), (bx, by)) = (graph[a], graph[b]);
+        (ax - bx).hypot(ay - by)
+    };
+
+    // Act
+    let result = astar_graph_to_any(&graph, start, &[x, y], distance).unwrap();
+    let none = astar_graph_to_any(&graph, start, &[], distance).unwrap();
+
+    // Assert
+    assert_eq!(result.goal(), Some(&y));
+    assert_eq!(result.path(), Some(&[start, y][..]));
+    assert_eq!(result.cost(), Some(&4.0));
+    assert!(!none.is_found());
+}
@@ -1,6 +1,13 @@
 mod pathfinding;
-use pathfinding::grid_generator::generate_test_grid;
-use utils::pathfinding::a_star_grid::a_star_grid;
+use pathfinding::grid_generator::{generate_random_test_grid, generate_test_grid};
+use utils::pathfinding::a_star::{SearchProgress, TieBreak};
+use utils::pathfinding::a_star_grid::{
+    a_star_grid, a_star_grid_search, a_star_grid_to_any, a_star_grid_to_any_with, a_star_grid_with,
+    a_star_grid_with_search, SearchContext,
+};
+use utils::pathfinding::grid_movement::{DistanceHeuristic, Movement};
+use utils::search::budget::SearchBudget;
+use utils::search::outcome::SearchError;
 
 // TODO: I'm not loving the grid implementations. Maybe create a test struct or something so that they can have the same grids.
 #[test]
@@ -9,9 +16,9 @@ fn test_astar_no_obstacles() {
     let start = (0, 0);
     let goal = (4, 4);
 
-    let path = a_star_grid(&grid, start, goal);
+    let result = a_star_grid(&grid, start, goal).unwrap();
 
-    assert!(!path.is_empty(), "A* should find a path.");
+    let path = result.path().expect("A* should find a path.");
     assert_eq!(path.first(), Some(&start), "Path should start at the start position.");
     assert_eq!(path.last(), Some(&goal), "Path should end at the goal position.");
 }
@@ -32,10 +39,10 @@ fn test_a_star_grid_with_cycles() {
     let goal = (4, 4);
 
     // Act: Perform A*.
-    let path = a_star_grid(&grid, start, goal);
+    let result = a_star_grid(&grid, start, goal).unwrap();
 
     // Assert: Verify that A* avoids cycles and finds a valid path.
-    assert!(!path.is_empty(), "A* should find a valid path.");
+    let path = result.path().expect("A* should find a valid path.");
     assert_eq!(path.first(), Some(&start), "Path should start at the start position.");
     assert_eq!(path.last(), Some(&goal), "Path should end at the goal position.");
 }
@@ -44,18 +51,18 @@ fn test_a_star_grid_with_cycles() {
 fn test_a_star_grid_no_solution() {
     // Arrange: A grid with no possible solution.
     let mut grid = generate_test_grid((5, 5), vec![]);
-    for row in 0..5 {
-        grid[row][2] = true; // Block the middle column.
+    for row in grid.iter_mut() {
+        row[2] = true; // Block the middle column.
     }
 
     let start = (0, 0);
     let goal = (4, 4);
 
     // Act: Perform A*.
-    let path = a_star_grid(&grid, start, goal);
+    let result = a_star_grid(&grid, start, goal).unwrap();
 
-    // Assert: Verify that A* returns an empty path when no solution exists.
-    assert!(path.is_empty(), "A* should return an empty path when no solution exists.");
+    // Assert: Verify that A* exhausts the grid when no solution exists.
+    assert!(!result.is_found(), "A* should not find a path when no solution exists.");
 }
 
 #[test]
@@ -66,10 +73,489 @@ fn test_a_star_grid_target_edge_case() {
     let goal = (0, 4); // Top-right corner.
 
     // Act: Perform A*.
-    let path = a_star_grid(&grid, start, goal);
+    let result = a_star_grid(&grid, start, goal).unwrap();
 
     // Assert: Verify that A* finds a valid path to the edge target.
-    assert!(!path.is_empty(), "A* should find a valid path to the edge target.");
+    let path = result.path().expect("A* should find a valid path to the edge target.");
     assert_eq!(path.first(), Some(&start), "Path should start at the start position.");
     assert_eq!(path.last(), Some(&goal), "Path should end at the edge target.");
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_a_star_grid_eight_way_moves_diagonally() {
+    let grid = generate_test_grid((5, 5), vec![]);
+
+    let result = a_star_grid_with(
+        &grid,
+        (0, 0),
+        (4, 4),
+        Movement::eight_way(),
+        DistanceHeuristic::Octile,
+    )
+    .unwrap();
+
+    assert_eq!(
+        result.path(),
+        Some(&[(0, 0), (1, 1), (2, 2), (3, 3), (4, 4)][..])
+    );
+    assert_eq!(result.cost(), Some(&(4 * Movement::DIAGONAL_COST)));
+}
+
+#[test]
+fn test_a_star_grid_corner_cutting() {
+    // Arrange: the only way out of the start squeezes between two walls.
+    let grid = generate_test_grid((3, 3), vec![(0, 1), (1, 0)]);
+
+    // Act
+    let blocked = a_star_grid_with(
+        &grid,
+        (0, 0),
+        (2, 2),
+        Movement::eight_way(),
+        DistanceHeuristic::Octile,
+    )
+    .unwrap();
+    let cutting = a_star_grid_with(
+        &grid,
+        (0, 0),
+        (2, 2),
+        Movement::eight_way_with_corner_cutting(),
+        DistanceHeuristic::Octile,
+    )
+    .unwrap();
+
+    // Assert
+    assert!(
+        !blocked.is_found(),
+        "Diagonal steps should not pass between walls."
+    );
+    assert_eq!(cutting.path(), Some(&[(0, 0), (1, 1), (2, 2)][..]));
+}
+
+#[test]
+fn test_a_star_grid_no_corner_cutting_goes_around_single_wall() {
+    let grid = generate_test_grid((2, 2), vec![(0, 1)]);
+
+    let result = a_star_grid_with(
+        &grid,
+        (0, 0),
+        (1, 1),
+        Movement::eight_way(),
+        DistanceHeuristic::Octile,
+    )
+    .unwrap();
+
+    assert_eq!(result.path(), Some(&[(0, 0), (1, 0), (1, 1)][..]));
+}
+
+#[test]
+fn test_a_star_grid_expensive_diagonals_are_avoided() {
+    let grid = generate_test_grid((5, 5), vec![]);
+    let movement = Movement::eight_way().with_diagonal_cost(3 * Movement::STRAIGHT_COST);
+
+    let result =
+        a_star_grid_with(&grid, (0, 0), (4, 4), movement, DistanceHeuristic::Octile).unwrap();
+
+    let path = result.path().unwrap();
+    assert_eq!(
+        path.len(),
+        9,
+        "Two straight steps are cheaper than one diagonal."
+    );
+    for pair in path.windows(2) {
+        assert_eq!(
+            pair[0].0.abs_diff(pair[1].0) + pair[0].1.abs_diff(pair[1].1),
+            1
+        );
+    }
+}
+
+#[test]
+fn test_a_star_grid_admissible_heuristics_agree_on_cost() {
+    for seed in 0..20 {
+        // Arrange
+        let grid = generate_random_test_grid((12, 12), 25, seed);
+        let (start, goal) = ((0, 0), (11, 11));
+        if grid[start.0][start.1] || grid[goal.0][goal.1] {
+            continue;
+        }
+
+        // Act
+        let dijkstra =
+            a_star_grid_with(&grid, start, goal, Movement::eight_way(), |_, _| 0).unwrap();
+        let octile = a_star_grid_with(
+            &grid,
+            start,
+            goal,
+            Movement::eight_way(),
+            DistanceHeuristic::Octile,
+        )
+        .unwrap();
+        let euclidean = a_star_grid_with(
+            &grid,
+            start,
+            goal,
+            Movement::eight_way(),
+            DistanceHeuristic::Euclidean,
+        )
+        .unwrap();
+
+        // Assert
+        assert_eq!(octile.cost(), dijkstra.cost(), "seed {seed}");
+        assert_eq!(euclidean.cost(), dijkstra.cost(), "seed {seed}");
+    }
+}
+
+#[test]
+fn test_a_star_grid_rejects_invalid_start() {
+    let grid = generate_test_grid((3, 3), vec![]);
+
+    assert_eq!(
+        a_star_grid(&grid, (0, 3), (0, 0)),
+        Err(SearchError::StartOutOfBounds { row: 0, col: 3 })
+    );
+    assert_eq!(
+        a_star_grid(&[], (0, 0), (0, 0)),
+        Err(SearchError::EmptyGrid)
+    );
+}
+
+#[test]
+fn test_a_star_grid_ragged_rows() {
+    // Arrange: the middle row holds a single cell, so the path has to go round.
+    let mut grid = generate_test_grid((3, 3), vec![]);
+    grid[1].truncate(1);
+
+    // Act
+    let result = a_star_grid(&grid, (0, 2), (2, 2)).unwrap();
+    let missing_goal = a_star_grid(&grid, (0, 2), (1, 1)).unwrap();
+
+    // Assert
+    assert_eq!(result.cost(), Some(&6));
+    assert!(!missing_goal.is_found());
+}
+
+#[test]
+fn test_a_star_grid_search_steps_to_same_outcome() {
+    // Arrange
+    let grid = generate_test_grid((6, 6), vec![(1, 1), (1, 2), (1, 3), (3, 2), (4, 2)]);
+    let (start, goal) = ((0, 0), (5, 5));
+    let expected = a_star_grid(&grid, start, goal).unwrap();
+    let mut search = a_star_grid_search(&grid, start, goal).unwrap();
+
+    // Act
+    let mut steps = 1;
+    while search.step(3) == SearchProgress::Running {
+        assert!(!search.is_finished());
+        assert!(search.visited().len() <= 3 * steps);
+        steps += 1;
+    }
+
+    // Assert
+    assert_eq!(steps, expected.visited().len().div_ceil(3));
+    assert_eq!(search.step(3), SearchProgress::Finished);
+    assert_eq!(search.outcome(), Some(&expected));
+    assert_eq!(search.into_outcome(), expected);
+}
+
+#[test]
+fn test_a_star_grid_search_agents_share_frames() {
+    // Arrange: several agents crossing the same level.
+    let grid = generate_random_test_grid((15, 15), 20, 4);
+    let routes = [((0, 0), (14, 14)), ((14, 0), (0, 14)), ((7, 0), (7, 14))];
+    let mut searches: Vec<_> = routes
+        .iter()
+        .map(|&(start, goal)| a_star_grid_search(&grid, start, goal).unwrap())
+        .collect();
+
+    // Act: every frame, each agent gets two expansions.
+    let mut frames = 0;
+    while searches.iter().any(|search| !search.is_finished()) {
+        for search in searches.iter_mut() {
+            search.step(2);
+        }
+        frames += 1;
+    }
+
+    // Assert
+    assert!(frames > 1);
+    for (search, &(start, goal)) in searches.into_iter().zip(&routes) {
+        assert_eq!(
+            search.into_outcome(),
+            a_star_grid(&grid, start, goal).unwrap()
+        );
+    }
+}
+
+#[test]
+fn test_a_star_grid_search_resumes_after_budget_stop() {
+    // Arrange
+    let grid = generate_test_grid((8, 8), vec![]);
+    let (start, goal) = ((0, 0), (7, 7));
+    let mut search = a_star_grid_search(&grid, start, goal).unwrap();
+    let mut budget = SearchBudget::unlimited().with_max_expansions(4);
+
+    // Act
+    let stopped = search.step_observed(usize::MAX, &mut budget);
+    let expanded_when_stopped = search.visited().len();
+    let resumed = search.step(usize::MAX);
+
+    // Assert
+    assert_eq!(stopped, SearchProgress::Stopped);
+    assert_eq!(expanded_when_stopped, 4);
+    assert_eq!(resumed, SearchProgress::Finished);
+    assert_eq!(
+        search.into_outcome(),
+        a_star_grid(&grid, start, goal).unwrap()
+    );
+}
+
+#[test]
+fn test_a_star_grid_search_unfinished_outcome_has_best_path() {
+    let grid = generate_test_grid((8, 8), vec![]);
+    let mut search = a_star_grid_search(&grid, (0, 0), (7, 7)).unwrap();
+
+    assert_eq!(search.step(5), SearchProgress::Running);
+    assert!(search.frontier_len() > 0);
+    let outcome = search.into_outcome();
+
+    assert!(outcome.is_budget_exhausted());
+    assert_eq!(outcome.visited().len(), 5);
+    assert_eq!(outcome.best_path().unwrap().len(), 5);
+}
+
+#[test]
+fn test_a_star_grid_matches_generic_a_star() {
+    let movements = [
+        Movement::four_way(),
+        Movement::eight_way(),
+        Movement::eight_way_with_corner_cutting(),
+    ];
+
+    for seed in 0..20 {
+        for movement in movements {
+            // Arrange
+            let grid = generate_random_test_grid((16, 12), 30, seed);
+            let (start, goal) = ((0, 0), (15, 11));
+
+            // Act
+            let generic =
+                a_star_grid_with_search(&grid, start, goal, movement, DistanceHeuristic::Octile)
+                    .unwrap()
+                    .run();
+            let dense =
+                a_star_grid_with(&grid, start, goal, movement, DistanceHeuristic::Octile).unwrap();
+
+            // Assert: same path, cost and expansion order.
+            assert_eq!(dense, generic, "seed {seed}, {movement:?}");
+        }
+    }
+}
+
+#[test]
+fn test_search_context_reuse_matches_fresh_searches() {
+    // Arrange: one context serves maps of different sizes in turn.
+    let mut context = SearchContext::new();
+    let queries = [
+        ((30, 30), 25, 1),
+        ((5, 40), 10, 2),
+        ((30, 30), 25, 3),
+        ((8, 8), 0, 4),
+    ];
+
+    for ((rows, cols), walls, seed) in queries {
+        let grid = generate_random_test_grid((rows, cols), walls, seed);
+        let (start, goal) = ((0, 0), (rows - 1, cols - 1));
+
+        // Act
+        let reused = context.a_star_grid(&grid, start, goal).unwrap();
+        let reversed = context.a_star_grid(&grid, goal, start).unwrap();
+
+        // Assert
+        assert_eq!(reused, a_star_grid(&grid, start, goal).unwrap());
+        assert_eq!(reversed, a_star_grid(&grid, goal, start).unwrap());
+    }
+}
+
+#[test]
+fn test_search_context_rejects_invalid_start() {
+    let mut context = SearchContext::new();
+
+    assert_eq!(
+        context.a_star_grid(&[], (0, 0), (0, 0)),
+        Err(SearchError::EmptyGrid)
+    );
+}
+
+#[test]
+fn test_tie_break_higher_g_saves_expansions_on_open_map() {
+    // Arrange
+    let grid = generate_test_grid((20, 20), vec![]);
+    let (start, goal) = ((0, 0), (19, 19));
+    let expanded = |tie_break| {
+        let mut context = SearchContext::new().with_tie_break(tie_break);
+        let outcome = context.a_star_grid(&grid, start, goal).unwrap();
+        assert_eq!(outcome.cost(), Some(&38));
+        outcome.visited().len()
+    };
+
+    // Act
+    let higher_g = expanded(TieBreak::HigherG);
+    let lower_h = expanded(TieBreak::LowerH);
+    let fifo = expanded(TieBreak::Fifo);
+
+    // Assert: every cell on the way is tied at f = 38.
+    assert_eq!(higher_g, 39);
+    assert_eq!(lower_h, 39);
+    assert_eq!(fifo, 400, "FIFO should sweep every tied cell.");
+}
+
+#[test]
+fn test_tie_break_policies_are_deterministic() {
+    let policies = [
+        TieBreak::HigherG,
+        TieBreak::LowerH,
+        TieBreak::Fifo,
+        TieBreak::Lifo,
+    ];
+
+    for seed in 0..10 {
+        for tie_break in policies {
+            // Arrange
+            let grid = generate_random_test_grid((16, 12), 20, seed);
+            let (start, goal) = ((0, 0), (15, 11));
+            let movement = Movement::eight_way();
+            let mut context = SearchContext::new().with_tie_break(tie_break);
+
+            // Act
+            let first = context
+                .a_star_grid_with(&grid, start, goal, movement, DistanceHeuristic::Octile)
+                .unwrap();
+            let second = context
+                .a_star_grid_with(&grid, start, goal, movement, DistanceHeuristic::Octile)
+                .unwrap();
+            let generic =
+                a_star_grid_with_search(&grid, start, goal, movement, DistanceHeuristic::Octile)
+                    .unwrap()
+                    .with_tie_break(tie_break)
+                    .run();
+
+            // Assert: repeat runs and both implementations expand in the same order.
+            assert_eq!(first, second, "seed {seed}, {tie_break:?}");
+            assert_eq!(first, generic, "seed {seed}, {tie_break:?}");
+        }
+    }
+}
+
+#[test]
+fn test_tie_break_fifo_and_lifo_paths() {
+    let grid = generate_test_grid((3, 3), vec![]);
+    let path = |tie_break| {
+        let mut context = SearchContext::new().with_tie_break(tie_break);
+        let outcome = context.a_star_grid(&grid, (0, 0), (2, 2)).unwrap();
+        outcome.path().unwrap().to_vec()
+    };
+
+    assert_eq!(
+        path(TieBreak::Fifo),
+        vec![(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)]
+    );
+    assert_eq!(
+        path(TieBreak::Lifo),
+        vec![(0, 0), (0, 1), (0, 2), (1, 2), (2, 2)]
+    );
+}
+
+#[test]
+fn test_weighted_a_star_stays_within_bound() {
+    for seed in 0..10 {
+        // Arrange
+        let grid = generate_random_test_grid((30, 30), 25, seed);
+        let (start, goal) = ((0, 0), (29, 29));
+        let optimal = a_star_grid(&grid, start, goal).unwrap();
+
+        for weight in [1.5, 3.0] {
+            // Act
+            let weighted = SearchContext::new()
+                .with_weight(weight)
+                .a_star_grid(&grid, start, goal)
+                .unwrap();
+            let generic = a_star_grid_search(&grid, start, goal)
+                .unwrap()
+                .with_weight(weight)
+                .run();
+
+            // Assert
+            assert_eq!(weighted, generic, "seed {seed}, weight {weight}");
+            if let Some(&cost) = optimal.cost() {
+                let weighted_cost = *weighted.cost().unwrap();
+                assert!(weighted_cost as f64 <= weight * cost as f64);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_weighted_a_star_expands_fewer_cells_around_obstacles() {
+    // Arrange: a wall across most of the map with a gap at the far end.
+    let walls = (0..19).map(|col| (10, col)).collect();
+    let grid = generate_test_grid((20, 20), walls);
+    let (start, goal) = ((0, 0), (19, 0));
+
+    // Act
+    let optimal = a_star_grid(&grid, start, goal).unwrap();
+    let weighted = SearchContext::new()
+        .with_weight(3.0)
+        .a_star_grid(&grid, start, goal)
+        .unwrap();
+
+    // Assert
+    assert!(weighted.visited().len() < optimal.visited().len());
+    assert!(*weighted.cost().unwrap() as f64 <= 3.0 * *optimal.cost().unwrap() as f64);
+}
+
+#[test]
+fn test_a_star_grid_to_any_reaches_nearest_goal() {
+    for seed in 0..20 {
+        // Arrange
+        let grid = generate_random_test_grid((15, 15), 25, seed);
+        let goals = [(14, 14), (0, 14), (14, 0), (7, 7)];
+
+        // Act
+        let result = a_star_grid_to_any(&grid, (0, 0), &goals).unwrap();
+
+        // Assert: as cheap as the best single-goal search.
+        let nearest = goals
+            .iter()
+            .filter_map(|&goal| a_star_grid(&grid, (0, 0), goal).unwrap().cost().copied())
+            .min();
+        assert_eq!(result.cost().copied(), nearest, "seed {seed}");
+        if let Some(goal) = result.goal() {
+            assert!(goals.contains(goal));
+            assert_eq!(result.path().unwrap().last(), Some(goal));
+        }
+    }
+}
+
+#[test]
+fn test_a_star_grid_to_any_with_eight_way_and_no_goals() {
+    // Arrange
+    let grid = generate_test_grid((10, 10), vec![(1, 5), (2, 5), (3, 5)]);
+    let goals = [(9, 0), (6, 6)];
+
+    // Act
+    let result = a_star_grid_to_any_with(
+        &grid,
+        (0, 0),
+        &goals,
+        Movement::eight_way(),
+        DistanceHeuristic::Octile,
+    )
+    .unwrap();
+    let none = a_star_grid_to_any(&grid, (0, 0), &[]).unwrap();
+
+    // Assert: (6, 6) is six diagonal steps away, (9, 0) nine straight ones.
+    assert_eq!(result.goal(), Some(&(6, 6)));
+    assert!(!none.is_found());
+    assert_eq!(none.visited().len(), 97);
+}
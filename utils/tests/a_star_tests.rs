@@ -0,0 +1,61 @@
+mod search;
+use search::problem_generator::{KeyDoorMaze, SlidingPuzzle, SOLVED_PUZZLE};
+use utils::pathfinding::a_star::a_star;
+use utils::pathfinding::uniform_cost_search::uniform_cost_search;
+use utils::search::search_problem::SearchProblem;
+
+#[test]
+fn test_a_star_sliding_puzzle() {
+    // Arrange
+    let puzzle = SlidingPuzzle::scrambled("ULDLURDRULDLUR");
+
+    // Act
+    let result = a_star(&puzzle);
+
+    // Assert
+    let path = result.path().expect("A* should solve the puzzle.");
+    let cost = *result.cost().unwrap();
+    assert_eq!(path.first(), Some(&puzzle.tiles));
+    assert_eq!(path.last(), Some(&SOLVED_PUZZLE));
+    assert_eq!(cost, path.len() - 1);
+    assert!(cost <= 14);
+    assert!(cost >= puzzle.heuristic(&puzzle.tiles));
+}
+
+#[test]
+fn test_a_star_matches_uniform_cost_search() {
+    for scramble in ["", "U", "ULDR", "ULDLUR", "LLUURRDD", "ULURDDLLUR"] {
+        // Arrange
+        let puzzle = SlidingPuzzle::scrambled(scramble);
+
+        // Act
+        let expected = uniform_cost_search(&puzzle).cost().copied();
+        let actual = a_star(&puzzle).cost().copied();
+
+        // Assert: An admissible heuristic keeps A* optimal.
+        assert_eq!(actual, expected, "Scramble {scramble}");
+    }
+}
+
+#[test]
+fn test_a_star_without_heuristic() {
+    // Arrange: KeyDoorMaze uses the default zero heuristic.
+    let maze = KeyDoorMaze::parse(&["S.#E", "..D.", "K###"]);
+
+    // Act
+    let result = a_star(&maze);
+
+    // Assert
+    assert_eq!(result.cost(), Some(&7));
+    assert_eq!(result.goal(), Some(&((0, 3), true)));
+}
+
+#[test]
+fn test_a_star_no_path() {
+    let maze = KeyDoorMaze::parse(&["S.#E", "..D.", ".###"]);
+
+    let result = a_star(&maze);
+
+    assert!(!result.is_found());
+    assert_eq!(result.visited().len(), 5);
+}
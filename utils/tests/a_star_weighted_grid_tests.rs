@@ -0,0 +1,134 @@
+mod pathfinding;
+use pathfinding::grid_generator::{
+    generate_random_weighted_test_grid, generate_weighted_test_grid,
+};
+use utils::pathfinding::a_star_weighted_grid::{a_star_weighted_grid, a_star_weighted_grid_with};
+use utils::pathfinding::djikstra_shortest_path_grid::djikstra_shortest_path_grid;
+use utils::pathfinding::grid_movement::{DistanceHeuristic, Movement};
+
+/// Sum of the weights of every cell entered along `path`.
+fn path_cost(grid: &[Vec<usize>], path: &[(usize, usize)]) -> usize {
+    path.iter().skip(1).map(|&(row, col)| grid[row][col]).sum()
+}
+
+#[test]
+fn test_a_star_weighted_grid_no_obstacles() {
+    // Arrange
+    let grid = generate_weighted_test_grid((5, 5), 3, vec![]);
+
+    // Act
+    let result = a_star_weighted_grid(&grid, (0, 0), (4, 4)).unwrap();
+
+    // Assert
+    let path = result.path().expect("A* should find a path.");
+    assert_eq!(path.len(), 9);
+    assert_eq!(path.first(), Some(&(0, 0)));
+    assert_eq!(path.last(), Some(&(4, 4)));
+    assert_eq!(result.cost(), Some(&24));
+}
+
+#[test]
+fn test_a_star_weighted_grid_avoids_expensive_terrain() {
+    // Arrange: a swamp blocks the straight route along the top row.
+    let mut grid = generate_weighted_test_grid((3, 5), 1, vec![]);
+    grid[0][2] = 20;
+    grid[1][2] = 20;
+
+    // Act
+    let result = a_star_weighted_grid(&grid, (0, 0), (0, 4)).unwrap();
+
+    // Assert
+    let path = result.path().unwrap();
+    assert_eq!(result.cost(), Some(&8));
+    assert_eq!(path_cost(&grid, path), 8);
+    assert!(
+        path.contains(&(2, 2)),
+        "The path should detour through the bottom row."
+    );
+}
+
+#[test]
+fn test_a_star_weighted_grid_no_solution() {
+    let grid = generate_weighted_test_grid((3, 3), 1, vec![(1, 0), (1, 1), (1, 2)]);
+
+    let result = a_star_weighted_grid(&grid, (0, 0), (2, 2)).unwrap();
+
+    assert!(!result.is_found());
+    assert_eq!(result.visited(), [(0, 0), (0, 1), (0, 2)]);
+}
+
+#[test]
+fn test_a_star_weighted_grid_start_is_goal() {
+    let grid = generate_weighted_test_grid((3, 3), 5, vec![]);
+
+    let result = a_star_weighted_grid(&grid, (1, 1), (1, 1)).unwrap();
+
+    assert_eq!(result.path(), Some(&[(1, 1)][..]));
+    assert_eq!(result.cost(), Some(&0));
+}
+
+#[test]
+fn test_a_star_weighted_grid_matches_dijkstra() {
+    for seed in 0..30 {
+        // Arrange
+        let grid = generate_random_weighted_test_grid((10, 10), 9, 20, seed);
+        let (start, goal) = ((0, 0), (9, 9));
+        let tree = djikstra_shortest_path_grid(&grid, start).unwrap();
+
+        // Act
+        let result = a_star_weighted_grid(&grid, start, goal).unwrap();
+
+        // Assert
+        match (result.path(), result.cost()) {
+            (Some(path), Some(&cost)) => {
+                assert_eq!(Some(cost), tree.distance_to(goal), "seed {seed}");
+                assert_eq!(path_cost(&grid, path), cost, "seed {seed}");
+            }
+            _ => assert!(!tree.is_reachable(goal), "seed {seed}"),
+        }
+    }
+}
+
+#[test]
+fn test_a_star_weighted_grid_eight_way_matches_zero_heuristic() {
+    for seed in 0..20 {
+        // Arrange
+        let grid = generate_random_weighted_test_grid((10, 10), 9, 20, seed);
+        let (start, goal) = ((0, 0), (9, 9));
+
+        // Act
+        let octile = a_star_weighted_grid_with(
+            &grid,
+            start,
+            goal,
+            Movement::eight_way(),
+            DistanceHeuristic::Octile,
+        )
+        .unwrap();
+        let dijkstra =
+            a_star_weighted_grid_with(&grid, start, goal, Movement::eight_way(), |_, _| 0).unwrap();
+
+        // Assert
+        assert_eq!(octile.cost(), dijkstra.cost(), "seed {seed}");
+    }
+}
+
+#[test]
+fn test_a_star_weighted_grid_ragged_rows() {
+    // Arrange: the middle row holds a single cell, so the path has to go round.
+    let mut grid = generate_weighted_test_grid((3, 3), 2, vec![]);
+    grid[1].truncate(1);
+
+    // Act
+    let result = a_star_weighted_grid(&grid, (0, 2), (2, 2)).unwrap();
+
+    // Assert
+    assert_eq!(result.cost(), Some(&12));
+    assert_eq!(
+        result.cost(),
+        djikstra_shortest_path_grid(&grid, (0, 2))
+            .unwrap()
+            .distance_to((2, 2))
+            .as_ref()
+    );
+}
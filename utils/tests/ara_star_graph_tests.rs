@@ -0,0 +1,82 @@
+mod pathfinding;
+use pathfinding::graph_generator::generate_random_weighted_graph;
+use petgraph::graph::{Graph, NodeIndex};
+use utils::pathfinding::a_star_graph::astar_graph;
+use utils::pathfinding::ara_star_graph::{ara_star_graph, ara_star_graph_observed};
+use utils::search::observer::SearchTrace;
+use utils::search::outcome::SearchError;
+
+#[test]
+fn test_ara_star_graph_matches_astar_graph() {
+    for seed in 0..10 {
+        // Arrange
+        let (graph, nodes) = generate_random_weighted_graph(60, 240, 9, seed);
+        let (start, goal) = (nodes[0], nodes[59]);
+
+        // Act
+        let mut search = ara_star_graph(&graph, start, goal, |_, _| 0, 3.0).unwrap();
+        let solution = search.run().cloned();
+
+        // Assert
+        let astar = astar_graph(&graph, start, goal, |_, _| 0).unwrap();
+        assert_eq!(
+            solution.map(|solution| solution.cost),
+            astar.cost().copied()
+        );
+    }
+}
+
+#[test]
+fn test_ara_star_graph_float_weights() {
+    // Arrange: a line of points, with a short hop that the inflated heuristic
+    // prefers and a cheaper path that only the later passes find.
+    let mut graph = Graph::<f64, f64>::new();
+    let a = graph.add_node(0.0);
+    let b = graph.add_node(2.0);
+    let c = graph.add_node(1.0);
+    let d = graph.add_node(3.0);
+    graph.add_edge(a, b, 2.0);
+    graph.add_edge(b, d, 2.0);
+    graph.add_edge(a, c, 1.0);
+    graph.add_edge(c, d, 2.0);
+    let distance = |from: NodeIndex, to: NodeIndex| (graph[to] - graph[from]).abs();
+
+    // Act
+    let mut search = ara_star_graph(&graph, a, d, distance, 5.0).unwrap();
+    let first = search.improve().cloned().unwrap();
+    let last = search.run().cloned().unwrap();
+
+    // Assert
+    assert_eq!(first.bound, 5.0);
+    assert_eq!(first.path, vec![a, b, d]);
+    assert_eq!(last.bound, 1.0);
+    assert_eq!(last.path, vec![a, c, d]);
+    assert_eq!(last.cost, 3.0);
+}
+
+#[test]
+fn test_ara_star_graph_observed_runs_to_optimal() {
+    // Arrange
+    let (graph, nodes) = generate_random_weighted_graph(40, 160, 9, 3);
+    let (start, goal) = (nodes[0], nodes[39]);
+    let mut trace = SearchTrace::new();
+
+    // Act
+    let search = ara_star_graph_observed(&graph, start, goal, |_, _| 0, 3.0, &mut trace).unwrap();
+
+    // Assert
+    let astar = astar_graph(&graph, start, goal, |_, _| 0).unwrap();
+    let solution = search.solution().unwrap();
+    assert!(search.is_finished());
+    assert_eq!(Some(&solution.cost), astar.cost());
+    assert_eq!(trace.expanded().first(), Some(&&start));
+}
+
+#[test]
+fn test_ara_star_graph_rejects_invalid_start() {
+    let graph = Graph::<(), usize>::new();
+
+    let result = ara_star_graph(&graph, NodeIndex::new(0), NodeIndex::new(0), |_, _| 0, 2.0);
+
+    assert!(matches!(result, Err(SearchError::InvalidNode)));
+}
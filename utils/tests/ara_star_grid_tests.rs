@@ -0,0 +1,124 @@
+mod pathfinding;
+use pathfinding::grid_generator::{generate_random_test_grid, generate_test_grid};
+use utils::pathfinding::a_star_grid::{a_star_grid, a_star_grid_with};
+use utils::pathfinding::ara_star_grid::{
+    ara_star_grid, ara_star_grid_observed, ara_star_grid_with, ara_star_grid_with_observed,
+};
+use utils::pathfinding::grid_movement::{DistanceHeuristic, Movement};
+use utils::search::budget::SearchBudget;
+use utils::search::observer::SearchTrace;
+use utils::search::outcome::SearchError;
+
+#[test]
+fn test_ara_star_grid_bounds_hold_on_every_pass() {
+    for seed in 0..10 {
+        // Arrange
+        let grid = generate_random_test_grid((25, 25), 30, seed);
+        let (start, goal) = ((0, 0), (24, 24));
+        let optimal = a_star_grid(&grid, start, goal).unwrap().cost().copied();
+        let mut search = ara_star_grid(&grid, start, goal, 4.0).unwrap();
+
+        // Act
+        let mut last = None;
+        while !search.is_finished() {
+            last = search.improve().cloned();
+
+            // Assert
+            if let (Some(solution), Some(optimal)) = (&last, optimal) {
+                assert!(solution.cost as f64 <= solution.bound * optimal as f64);
+            }
+        }
+        assert_eq!(last.map(|solution| solution.cost), optimal, "seed {seed}");
+    }
+}
+
+#[test]
+fn test_ara_star_grid_with_eight_way_movement() {
+    let grid = generate_random_test_grid((20, 20), 25, 4);
+    let (start, goal) = ((0, 0), (19, 19));
+    let movement = Movement::eight_way();
+
+    let mut search =
+        ara_star_grid_with(&grid, start, goal, movement, DistanceHeuristic::Octile, 2.0).unwrap();
+    let solution = search.run().cloned();
+
+    let a_star = a_star_grid_with(&grid, start, goal, movement, DistanceHeuristic::Octile).unwrap();
+    assert_eq!(
+        solution.map(|solution| solution.cost),
+        a_star.cost().copied()
+    );
+}
+
+#[test]
+fn test_ara_star_grid_observed_runs_to_optimal() {
+    // Arrange
+    let grid = generate_random_test_grid((15, 15), 25, 7);
+    let (start, goal) = ((0, 0), (14, 14));
+    let mut trace = SearchTrace::new();
+
+    // Act
+    let search = ara_star_grid_observed(&grid, start, goal, 3.0, &mut trace).unwrap();
+
+    // Assert
+    let optimal = a_star_grid(&grid, start, goal).unwrap().cost().copied();
+    assert!(search.is_finished());
+    assert_eq!(search.solution().map(|solution| solution.cost), optimal);
+    assert_eq!(trace.expanded().first(), Some(&&start));
+}
+
+#[test]
+fn test_ara_star_grid_with_observed_stops_and_resumes() {
+    // Arrange
+    let grid = generate_random_test_grid((20, 20), 25, 4);
+    let (start, goal) = ((0, 0), (19, 19));
+    let movement = Movement::eight_way();
+    let mut budget = SearchBudget::unlimited().with_max_expansions(1);
+
+    // Act
+    let mut search = ara_star_grid_with_observed(
+        &grid,
+        start,
+        goal,
+        movement,
+        DistanceHeuristic::Octile,
+        2.0,
+        &mut budget,
+    )
+    .unwrap();
+    let stopped = search.solution().cloned();
+    let resumed = search.run().cloned();
+
+    // Assert
+    let a_star = a_star_grid_with(&grid, start, goal, movement, DistanceHeuristic::Octile).unwrap();
+    assert_eq!(stopped, None);
+    assert_eq!(
+        resumed.map(|solution| solution.cost),
+        a_star.cost().copied()
+    );
+}
+
+#[test]
+fn test_ara_star_grid_rejects_invalid_start() {
+    let grid = generate_test_grid((3, 3), vec![]);
+
+    let result = ara_star_grid(&grid, (5, 5), (0, 0), 2.0);
+
+    assert!(matches!(
+        result,
+        Err(SearchError::StartOutOfBounds { row: 5, col: 5 })
+    ));
+}
+
+#[test]
+fn test_ara_star_grid_ragged_rows() {
+    // Arrange: the middle row holds a single cell, so the path has to go round.
+    let mut grid = generate_test_grid((3, 3), vec![]);
+    grid[1].truncate(1);
+
+    // Act
+    let mut search = ara_star_grid(&grid, (0, 2), (2, 2), 2.0).unwrap();
+    let solution = search.run().cloned();
+
+    // Assert
+    assert_eq!(solution.map(|solution| solution.cost), Some(6));
+}
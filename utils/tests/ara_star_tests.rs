@@ -0,0 +1,98 @@
+mod search;
+use search::problem_generator::{KeyDoorMaze, SlidingPuzzle, SOLVED_PUZZLE};
+use std::time::Instant;
+use utils::pathfinding::a_star::a_star;
+use utils::pathfinding::ara_star::AraStar;
+use utils::search::budget::SearchBudget;
+
+#[test]
+fn test_ara_star_improves_until_optimal() {
+    // Arrange
+    let puzzle = SlidingPuzzle::scrambled("ULDLURDRULDLURDLURRDLU");
+    let optimal = *a_star(&puzzle).cost().unwrap();
+    let mut search = AraStar::new(&puzzle, 3.0);
+
+    // Act
+    let mut solutions = Vec::new();
+    while !search.is_finished() {
+        solutions.push(search.improve().cloned().unwrap());
+    }
+
+    // Assert
+    assert!(solutions.len() > 1);
+    assert_eq!(solutions[0].bound, 3.0);
+    for pair in solutions.windows(2) {
+        assert!(pair[1].bound < pair[0].bound);
+        assert!(pair[1].cost <= pair[0].cost);
+    }
+    for solution in &solutions {
+        assert_eq!(solution.path.first(), Some(&puzzle.tiles));
+        assert_eq!(solution.path.last(), Some(&SOLVED_PUZZLE));
+        assert_eq!(solution.cost, solution.path.len() - 1);
+        assert!(solution.cost as f64 <= solution.bound * optimal as f64);
+    }
+    let last = solutions.last().unwrap();
+    assert_eq!(last.bound, 1.0);
+    assert_eq!(last.cost, optimal);
+}
+
+#[test]
+fn test_ara_star_run_matches_a_star() {
+    for scramble in ["", "U", "ULDR", "ULDLUR", "LLUURRDD", "ULURDDLLUR"] {
+        // Arrange
+        let puzzle = SlidingPuzzle::scrambled(scramble);
+
+        // Act
+        let solution = AraStar::new(&puzzle, 2.5)
+            .with_weight_step(0.25)
+            .run()
+            .cloned();
+
+        // Assert
+        assert_eq!(
+            solution.map(|solution| solution.cost),
+            a_star(&puzzle).cost().copied(),
+            "scramble {scramble:?}"
+        );
+    }
+}
+
+#[test]
+fn test_ara_star_past_deadline_has_no_solution() {
+    let puzzle = SlidingPuzzle::scrambled("ULDLURDRULDLURDLURRDLU");
+    let mut search = AraStar::new(&puzzle, 2.0);
+
+    let solution = search.run_until(Instant::now());
+
+    assert_eq!(solution, None);
+    assert!(!search.is_finished());
+}
+
+#[test]
+fn test_ara_star_stopped_pass_resumes() {
+    // Arrange
+    let puzzle = SlidingPuzzle::scrambled("ULDLURDRULDLURDLURRDLU");
+    let mut search = AraStar::new(&puzzle, 2.0);
+
+    // Act
+    let stopped = search
+        .improve_observed(&mut SearchBudget::unlimited().with_max_expansions(1))
+        .cloned();
+    let resumed = search.improve().cloned();
+
+    // Assert
+    assert_eq!(stopped, None);
+    assert_eq!(search.weight(), 2.0);
+    assert_eq!(resumed.unwrap().bound, 2.0);
+}
+
+#[test]
+fn test_ara_star_no_path() {
+    let maze = KeyDoorMaze::parse(&["S.#E", "..D.", ".###"]);
+    let mut search = AraStar::new(&maze, 2.0);
+
+    let solution = search.run();
+
+    assert_eq!(solution, None);
+    assert!(search.is_finished());
+}
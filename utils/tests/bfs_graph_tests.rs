@@ -1,68 +1,209 @@
 mod search;
 use search::graph_generator::{
-    generate_balanced_graph, generate_balanced_graph_with_cycles, generate_unbalanced_graph,
+    generate_balanced_graph, generate_balanced_graph_with_cycles, generate_unbalanced_graph, labels,
 };
-use utils::search::bfs_graph::bfs_graph;
+use petgraph::graph::Graph;
+use petgraph::graphmap::DiGraphMap;
+use petgraph::stable_graph::StableGraph;
+use petgraph::visit::{EdgeFiltered, NodeFiltered};
+use petgraph::Undirected;
+use utils::search::bfs_graph::{bfs_graph, bfs_graph_where};
+use utils::search::outcome::SearchError;
 
 #[test]
 fn test_bfs_graph_balanced_with_target_found() {
     let (graph, start) = generate_balanced_graph(3);
-    let result = bfs_graph(&graph, start, Some(&'C'));
-    assert_eq!(result, Err(Some('C')));
+    let result = bfs_graph(&graph, start, Some(&'C')).unwrap();
+    assert_eq!(result.goal().map(|&node| graph[node]), Some('C'));
 }
 
 #[test]
 fn test_bfs_graph_balanced_with_target_not_found() {
     let (graph, start) = generate_balanced_graph(3);
-    let result = bfs_graph(&graph, start, Some(&'D'));
-    assert_eq!(result, Err(None));
+    let result = bfs_graph(&graph, start, Some(&'D')).unwrap();
+    assert!(!result.is_found());
 }
 
 #[test]
 fn test_bfs_graph_balanced_return_all_nodes() {
     let (graph, start) = generate_balanced_graph(3);
-    let result = bfs_graph(&graph, start, None);
-    assert_eq!(result, Ok(vec!['A', 'C', 'B']));
+    let result = bfs_graph(&graph, start, None).unwrap();
+    assert_eq!(labels(&graph, result.visited()), vec!['A', 'C', 'B']);
 }
 
 #[test]
 fn test_bfs_graph_unbalanced_with_target_found() {
     let (graph, start) = generate_unbalanced_graph(3);
-    let result = bfs_graph(&graph, start, Some(&'C'));
-    assert_eq!(result, Err(Some('C')));
+    let result = bfs_graph(&graph, start, Some(&'C')).unwrap();
+    assert_eq!(result.goal().map(|&node| graph[node]), Some('C'));
 }
 
 #[test]
 fn test_bfs_graph_unbalanced_with_target_not_found() {
     let (graph, start) = generate_unbalanced_graph(3);
-    let result = bfs_graph(&graph, start, Some(&'D'));
-    assert_eq!(result, Err(None));
+    let result = bfs_graph(&graph, start, Some(&'D')).unwrap();
+    assert!(!result.is_found());
 }
 
 #[test]
 fn test_bfs_graph_unbalanced_return_all_nodes() {
     let (graph, start) = generate_unbalanced_graph(3);
-    let result = bfs_graph(&graph, start, None);
-    assert_eq!(result, Ok(vec!['A', 'B', 'C']));
+    let result = bfs_graph(&graph, start, None).unwrap();
+    assert_eq!(labels(&graph, result.visited()), vec!['A', 'B', 'C']);
 }
 
 #[test]
 fn test_bfs_graph_balanced_with_cycles_with_target_found() {
     let (graph, start) = generate_balanced_graph_with_cycles(3);
-    let result = bfs_graph(&graph, start, Some(&'C'));
-    assert_eq!(result, Err(Some('C')));
+    let result = bfs_graph(&graph, start, Some(&'C')).unwrap();
+    assert_eq!(result.goal().map(|&node| graph[node]), Some('C'));
 }
 
 #[test]
 fn test_bfs_graph_balanced_with_cycles_with_target_not_found() {
     let (graph, start) = generate_balanced_graph_with_cycles(3);
-    let result = bfs_graph(&graph, start, Some(&'D'));
-    assert_eq!(result, Err(None));
+    let result = bfs_graph(&graph, start, Some(&'D')).unwrap();
+    assert!(!result.is_found());
 }
 
 #[test]
 fn test_bfs_graph_balanced_with_cycles_return_all_nodes() {
     let (graph, start) = generate_balanced_graph_with_cycles(3);
-    let result = bfs_graph(&graph, start, None);
-    assert_eq!(result, Ok(vec!['A', 'C', 'B']));
+    let result = bfs_graph(&graph, start, None).unwrap();
+    assert_eq!(labels(&graph, result.visited()), vec!['A', 'C', 'B']);
+}
+
+#[test]
+fn test_bfs_graph_stable_graph_with_removed_node() {
+    // Arrange
+    let (graph, start) = generate_balanced_graph(3);
+    let mut stable = StableGraph::from(graph);
+    let b = stable
+        .node_indices()
+        .find(|&node| stable[node] == 'B')
+        .unwrap();
+    stable.remove_node(b);
+
+    // Act
+    let result = bfs_graph(&stable, start, None).unwrap();
+
+    // Assert
+    assert_eq!(labels(&stable, result.visited()), vec!['A', 'C']);
+}
+
+#[test]
+fn test_bfs_graph_undirected() {
+    // Arrange: starting from a leaf, only an undirected graph leads back to the root.
+    let (graph, _) = generate_balanced_graph(3);
+    let undirected = graph.into_edge_type::<Undirected>();
+    let c = undirected
+        .node_indices()
+        .find(|&node| undirected[node] == 'C')
+        .unwrap();
+
+    // Act
+    let result = bfs_graph(&undirected, c, None).unwrap();
+
+    // Assert
+    assert_eq!(labels(&undirected, result.visited()), vec!['C', 'A', 'B']);
+}
+
+#[test]
+fn test_bfs_graph_node_filtered_view() {
+    // Arrange
+    let (graph, start) = generate_balanced_graph(7);
+    let view = NodeFiltered::from_fn(&graph, |node| graph[node] != 'B');
+
+    // Act
+    let hidden = bfs_graph(&view, start, Some(&'D')).unwrap();
+    let visible = bfs_graph(&view, start, Some(&'F')).unwrap();
+
+    // Assert: 'D' is only reachable through the filtered out 'B'.
+    assert!(!hidden.is_found());
+    assert_eq!(visible.goal().map(|&node| graph[node]), Some('F'));
+}
+
+#[test]
+fn test_bfs_graph_removed_start_node() {
+    // Arrange
+    let (graph, start) = generate_balanced_graph(3);
+    let mut stable = StableGraph::from(graph);
+    stable.remove_node(start);
+
+    // Act
+    let result = bfs_graph(&stable, start, Some(&'C'));
+
+    // Assert
+    assert_eq!(result, Err(SearchError::InvalidNode));
+}
+
+#[test]
+fn test_bfs_graph_found_path() {
+    // Arrange
+    let (graph, start) = generate_balanced_graph(7);
+
+    // Act
+    let result = bfs_graph(&graph, start, Some(&'F')).unwrap();
+
+    // Assert
+    assert_eq!(labels(&graph, result.path().unwrap()), vec!['A', 'C', 'F']);
+    assert_eq!(result.cost(), Some(&2));
+    assert_eq!(
+        labels(&graph, result.visited()),
+        vec!['A', 'C', 'B', 'G', 'F']
+    );
+}
+
+#[test]
+fn test_bfs_graph_graph_map() {
+    // Arrange: a GraphMap whose nodes are their own names.
+    let graph = DiGraphMap::<&str, usize>::from_edges([
+        ("A", "B", 1),
+        ("A", "C", 1),
+        ("B", "D", 1),
+        ("C", "E", 1),
+        ("E", "D", 1),
+    ]);
+
+    // Act
+    let result = bfs_graph_where(&graph, "A", |node| node == "D").unwrap();
+    let missing = bfs_graph_where(&graph, "Z", |node| node == "D");
+
+    // Assert
+    assert_eq!(result.path(), Some(&["A", "B", "D"][..]));
+    assert_eq!(missing, Err(SearchError::InvalidNode));
+}
+
+#[test]
+fn test_bfs_graph_edge_filtered_view() {
+    // Arrange: hide the direct edge so the search has to take the long way round.
+    let mut graph = Graph::<&str, usize>::new();
+    let a = graph.add_node("A");
+    let b = graph.add_node("B");
+    let c = graph.add_node("C");
+    graph.add_edge(a, c, 1);
+    graph.add_edge(a, b, 2);
+    graph.add_edge(b, c, 2);
+    let view = EdgeFiltered::from_fn(&graph, |edge| *edge.weight() > 1);
+
+    // Act
+    let unfiltered = bfs_graph_where(&graph, a, |node| node == c).unwrap();
+    let filtered = bfs_graph_where(&view, a, |node| node == c).unwrap();
+
+    // Assert
+    assert_eq!(unfiltered.path(), Some(&[a, c][..]));
+    assert_eq!(filtered.path(), Some(&[a, b, c][..]));
+}
+
+#[test]
+fn test_bfs_graph_filtered_out_start_node() {
+    // Arrange
+    let (graph, start) = generate_balanced_graph(3);
+    let view = NodeFiltered::from_fn(&graph, |node| node != start);
+
+    // Act
+    let result = bfs_graph(&view, start, Some(&'C'));
+
+    // Assert
+    assert_eq!(result, Err(SearchError::InvalidNode));
 }
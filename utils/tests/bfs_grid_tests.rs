@@ -1,19 +1,169 @@
 mod search;
-use search::grid_generator::generate_balanced_grid;
-use utils::search::bfs_grid::bfs_grid;
+use search::grid_generator::{generate_balanced_grid, generate_maze_grid};
+use utils::search::bfs_grid::{bfs_grid, bfs_grid_all, bfs_grid_all_where, bfs_grid_where};
+use utils::search::outcome::SearchError;
 
 #[test]
 fn test_bfs_graph_balanced_with_target_found() {
     let (graph, start) = generate_balanced_grid(3, 3);
-    let (found, position) = bfs_grid(&graph, start, &'C');
-    assert_eq!(found, true);
-    assert_eq!(position, Some((0, 2)));
+    let path = bfs_grid(&graph, start, &'C').unwrap().into_path();
+    assert_eq!(path, Some(vec![(0, 0), (0, 1), (0, 2)]));
 }
 
 #[test]
 fn test_bfs_graph_balanced_with_target_not_found() {
     let (graph, start) = generate_balanced_grid(3, 3);
-    let (found, position) = bfs_grid(&graph, start, &'Z');
-    assert_eq!(found, false);
-    assert_eq!(position, None);
+    let path = bfs_grid(&graph, start, &'Z').unwrap().into_path();
+    assert_eq!(path, None);
+}
+
+#[test]
+fn test_bfs_grid_path_is_shortest() {
+    // Arrange: 'Y' appears far along the first row and close by in the last row.
+    let (mut grid, start) = generate_balanced_grid(5, 5);
+    grid[0][4] = 'Y';
+    grid[2][1] = 'Y';
+
+    // Act
+    let path = bfs_grid(&grid, start, &'Y')
+        .unwrap()
+        .into_path()
+        .expect("BFS should find a target.");
+
+    // Assert
+    assert_eq!(path.len(), 4);
+    assert_eq!(path.first(), Some(&start));
+    assert_eq!(path.last(), Some(&(2, 1)));
+    for pair in path.windows(2) {
+        let (a, b) = (pair[0], pair[1]);
+        assert_eq!(a.0.abs_diff(b.0) + a.1.abs_diff(b.1), 1);
+    }
+}
+
+#[test]
+fn test_bfs_grid_target_at_start() {
+    let (grid, start) = generate_balanced_grid(3, 3);
+    assert_eq!(
+        bfs_grid(&grid, start, &'A').unwrap().into_path(),
+        Some(vec![start])
+    );
+}
+
+#[test]
+fn test_bfs_grid_where_avoids_walls() {
+    // Arrange
+    let (grid, start) = generate_maze_grid(&["S.#E", ".##.", "...."]);
+
+    // Act
+    let path = bfs_grid_where(&grid, start, |&cell| cell != '#', |&cell| cell == 'E')
+        .unwrap()
+        .into_path();
+
+    // Assert
+    assert_eq!(
+        path,
+        Some(vec![
+            (0, 0),
+            (1, 0),
+            (2, 0),
+            (2, 1),
+            (2, 2),
+            (2, 3),
+            (1, 3),
+            (0, 3)
+        ])
+    );
+}
+
+#[test]
+fn test_bfs_grid_where_goal_predicate() {
+    // Arrange: Any digit counts as a goal; the nearest one wins.
+    let (grid, start) = generate_maze_grid(&["S..7", "#.##", "3..."]);
+
+    // Act
+    let path = bfs_grid_where(&grid, start, |&cell| cell != '#', char::is_ascii_digit)
+        .unwrap()
+        .into_path();
+
+    // Assert
+    assert_eq!(path, Some(vec![(0, 0), (0, 1), (0, 2), (0, 3)]));
+}
+
+#[test]
+fn test_bfs_grid_where_walled_in() {
+    let (grid, start) = generate_maze_grid(&["S#E", "##."]);
+
+    let path = bfs_grid_where(&grid, start, |&cell| cell != '#', |&cell| cell == 'E')
+        .unwrap()
+        .into_path();
+
+    assert_eq!(path, None);
+}
+
+#[test]
+fn test_bfs_grid_empty_grid() {
+    let grid: Vec<Vec<char>> = Vec::new();
+
+    assert_eq!(bfs_grid(&grid, (0, 0), &'A'), Err(SearchError::EmptyGrid));
+    assert_eq!(
+        bfs_grid(&[vec![]], (0, 0), &'A'),
+        Err(SearchError::EmptyGrid)
+    );
+}
+
+#[test]
+fn test_bfs_grid_start_out_of_bounds() {
+    // Arrange
+    let (grid, _) = generate_balanced_grid(3, 3);
+
+    // Act
+    let result = bfs_grid(&grid, (1, 3), &'A');
+
+    // Assert
+    assert_eq!(
+        result,
+        Err(SearchError::StartOutOfBounds { row: 1, col: 3 })
+    );
+}
+
+#[test]
+fn test_bfs_grid_outcome_records_visit_order() {
+    // Arrange
+    let (grid, start) = generate_balanced_grid(2, 2);
+
+    // Act
+    let result = bfs_grid(&grid, start, &'D').unwrap();
+
+    // Assert
+    assert_eq!(result.goal(), Some(&(1, 1)));
+    assert_eq!(result.cost(), Some(&2));
+    assert_eq!(result.visited(), [(0, 0), (1, 0), (0, 1), (1, 1)]);
+}
+
+#[test]
+fn test_bfs_grid_ragged_rows() {
+    // Arrange: the middle row holds a single cell, so the path has to go round.
+    let (grid, start) = generate_maze_grid(&["..S", ".", "..E"]);
+
+    // Act
+    let result = bfs_grid(&grid, start, &'E').unwrap();
+
+    // Assert
+    assert_eq!(result.cost(), Some(&6));
+    assert_eq!(result.visited().len(), 7);
+}
+
+#[test]
+fn test_bfs_grid_all_reports_every_target_with_distance() {
+    // Arrange: three health packs, one sealed off behind walls.
+    let (grid, start) = generate_maze_grid(&["S.H#H", ".##..", "H..#.", "###.#"]);
+
+    // Act
+    let packs = bfs_grid_all_where(&grid, start, |&c| c != '#', |&c| c == 'H').unwrap();
+    let walls = bfs_grid_all_where(&grid, start, |&c| c != '#', |&c| c == '#').unwrap();
+
+    // Assert: ties come in visit order, and the pack at (0, 4) is unreachable.
+    assert_eq!(packs, vec![((2, 0), 2), ((0, 2), 2)]);
+    assert!(walls.is_empty());
+    assert_eq!(bfs_grid_all(&grid, start, &'S').unwrap(), vec![((0, 0), 0)]);
 }
@@ -0,0 +1,66 @@
+mod search;
+use search::problem_generator::{KeyDoorMaze, SlidingPuzzle, SOLVED_PUZZLE};
+use utils::search::bfs::bfs;
+use utils::search::search_problem::SearchProblem;
+
+#[test]
+fn test_bfs_sliding_puzzle_fewest_moves() {
+    // Arrange
+    let puzzle = SlidingPuzzle::scrambled("ULDLUR");
+
+    // Act
+    let result = bfs(&puzzle);
+
+    // Assert
+    let path = result.path().expect("BFS should solve the puzzle.");
+    assert_eq!(path.first(), Some(&puzzle.tiles));
+    assert_eq!(path.last(), Some(&SOLVED_PUZZLE));
+    assert_eq!(path.len() - 1, 6);
+    for pair in path.windows(2) {
+        assert!(puzzle
+            .successors(&pair[0])
+            .iter()
+            .any(|(next, _)| *next == pair[1]));
+    }
+}
+
+#[test]
+fn test_bfs_solved_puzzle() {
+    let puzzle = SlidingPuzzle::scrambled("");
+
+    let result = bfs(&puzzle);
+
+    assert_eq!(result.path(), Some(&[SOLVED_PUZZLE][..]));
+    assert_eq!(result.cost(), Some(&0));
+    assert_eq!(result.visited(), [SOLVED_PUZZLE]);
+}
+
+#[test]
+fn test_bfs_key_door_maze() {
+    // Arrange: The exit is only reachable after a detour to fetch the key.
+    let maze = KeyDoorMaze::parse(&["S.#E", "..D.", "K###"]);
+
+    // Act
+    let result = bfs(&maze);
+
+    // Assert
+    let path = result.path().expect("BFS should escape the maze.");
+    assert_eq!(path.len() - 1, 7);
+    assert_eq!(path.first(), Some(&((0, 0), false)));
+    assert_eq!(path.last(), Some(&((0, 3), true)));
+    assert!(path.contains(&((1, 0), false)));
+    assert!(path.contains(&((1, 0), true)));
+}
+
+#[test]
+fn test_bfs_key_door_maze_no_key() {
+    // Arrange
+    let maze = KeyDoorMaze::parse(&["S.#E", "..D.", ".###"]);
+
+    // Act
+    let result = bfs(&maze);
+
+    // Assert: Every reachable state is expanded exactly once.
+    assert!(!result.is_found());
+    assert_eq!(result.visited().len(), 5);
+}
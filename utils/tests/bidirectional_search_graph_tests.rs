@@ -0,0 +1,196 @@
+mod pathfinding;
+use pathfinding::graph_generator::generate_random_weighted_graph;
+use petgraph::graph::{Graph, NodeIndex};
+use utils::pathfinding::bidirectional_search_graph::{
+    bidirectional_dijkstra_graph, bidirectional_search_graph,
+};
+use utils::pathfinding::uniform_cost_search_graph::uniform_cost_search_graph;
+use utils::search::outcome::SearchError;
+
+#[test]
+fn test_bidirectional_search_graph_path_found() {
+    // Arrange
+    let mut graph = Graph::<&str, ()>::new();
+    let a = graph.add_node("A");
+    let b = graph.add_node("B");
+    let c = graph.add_node("C");
+    let d = graph.add_node("D");
+    let e = graph.add_node("E");
+
+    graph.add_edge(a, b, ());
+    graph.add_edge(b, c, ());
+    graph.add_edge(c, d, ());
+    graph.add_edge(d, e, ());
+    graph.add_edge(a, d, ());
+
+    // Act
+    let result = bidirectional_search_graph(&graph, a, e).unwrap();
+
+    // Assert
+    assert_eq!(result.path(), Some(&[a, d, e][..]));
+    assert_eq!(result.cost(), Some(&2));
+}
+
+#[test]
+fn test_bidirectional_search_graph_respects_edge_direction() {
+    // Arrange: The only route back from C to A goes through D.
+    let mut graph = Graph::<&str, ()>::new();
+    let a = graph.add_node("A");
+    let b = graph.add_node("B");
+    let c = graph.add_node("C");
+    let d = graph.add_node("D");
+
+    graph.add_edge(a, b, ());
+    graph.add_edge(b, c, ());
+    graph.add_edge(c, d, ());
+    graph.add_edge(d, a, ());
+
+    // Act & Assert
+    assert_eq!(
+        bidirectional_search_graph(&graph, a, c).unwrap().path(),
+        Some(&[a, b, c][..])
+    );
+    assert_eq!(
+        bidirectional_search_graph(&graph, c, a).unwrap().path(),
+        Some(&[c, d, a][..])
+    );
+}
+
+#[test]
+fn test_bidirectional_search_graph_no_path() {
+    // Arrange
+    let mut graph = Graph::<&str, ()>::new();
+    let a = graph.add_node("A");
+    let b = graph.add_node("B");
+    let c = graph.add_node("C");
+
+    graph.add_edge(a, b, ());
+    graph.add_edge(c, b, ());
+
+    // Act
+    let result = bidirectional_search_graph(&graph, a, c).unwrap();
+
+    // Assert
+    assert!(!result.is_found());
+}
+
+#[test]
+fn test_bidirectional_search_graph_start_is_goal() {
+    let mut graph = Graph::<&str, ()>::new();
+    let a = graph.add_node("A");
+
+    let unweighted = bidirectional_search_graph(&graph, a, a).unwrap();
+    let weighted = bidirectional_dijkstra_graph(&graph.map(|_, n| *n, |_, _| 1), a, a).unwrap();
+
+    assert_eq!(unweighted.path(), Some(&[a][..]));
+    assert_eq!(weighted.path(), Some(&[a][..]));
+    assert_eq!(weighted.cost(), Some(&0));
+    assert_eq!(weighted.visited(), [a]);
+}
+
+#[test]
+fn test_bidirectional_dijkstra_graph_path_found() {
+    // Arrange
+    let mut graph = Graph::new();
+    let a = graph.add_node("A");
+    let b = graph.add_node("B");
+    let c = graph.add_node("C");
+    let d = graph.add_node("D");
+
+    graph.add_edge(a, b, 1);
+    graph.add_edge(b, c, 2);
+    graph.add_edge(a, c, 5);
+    graph.add_edge(c, d, 1);
+
+    // Act
+    let result = bidirectional_dijkstra_graph(&graph, a, d).unwrap();
+
+    // Assert
+    assert_eq!(result.path(), Some(&[a, b, c, d][..]));
+    assert_eq!(result.cost(), Some(&4));
+}
+
+#[test]
+fn test_bidirectional_dijkstra_graph_no_path() {
+    let mut graph = Graph::new();
+    let a = graph.add_node("A");
+    let b = graph.add_node("B");
+    let c = graph.add_node("C");
+
+    graph.add_edge(a, b, 1);
+
+    let result = bidirectional_dijkstra_graph(&graph, a, c).unwrap();
+
+    assert!(!result.is_found());
+}
+
+#[test]
+fn test_bidirectional_search_graph_invalid_node() {
+    let mut graph = Graph::<&str, usize>::new();
+    let a = graph.add_node("A");
+    let missing = NodeIndex::new(1);
+
+    assert_eq!(
+        bidirectional_search_graph(&graph, a, missing),
+        Err(SearchError::InvalidNode)
+    );
+    assert_eq!(
+        bidirectional_dijkstra_graph(&graph, missing, a),
+        Err(SearchError::InvalidNode)
+    );
+}
+
+#[test]
+fn test_bidirectional_dijkstra_graph_matches_uniform_cost_search() {
+    for seed in 0..25 {
+        // Arrange
+        let (graph, nodes) = generate_random_weighted_graph(40, 120, 9, seed);
+
+        for &goal in nodes.iter().step_by(7) {
+            // Act
+            let expected = uniform_cost_search_graph(&graph, nodes[0], goal).unwrap();
+            let actual = bidirectional_dijkstra_graph(&graph, nodes[0], goal).unwrap();
+
+            // Assert: Same cost, and the stitched path is a real path with that cost.
+            assert_eq!(actual.cost(), expected.cost());
+            if let (Some(path), Some(&cost)) = (actual.path(), actual.cost()) {
+                assert_eq!(path.first(), Some(&nodes[0]));
+                assert_eq!(path.last(), Some(&goal));
+                let total: usize = path
+                    .windows(2)
+                    .map(|pair| {
+                        graph
+                            .edges_connecting(pair[0], pair[1])
+                            .map(|edge| *edge.weight())
+                            .min()
+                            .expect("consecutive path nodes must be connected")
+                    })
+                    .sum();
+                assert_eq!(total, cost);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_bidirectional_search_graph_finds_fewest_edges() {
+    for seed in 0..25 {
+        // Arrange: Unit weights turn uniform cost search into a breadth-first reference.
+        let (weighted, nodes) = generate_random_weighted_graph(40, 90, 1, seed);
+        let graph = weighted.map(|_, n| *n, |_, _| ());
+
+        for &goal in nodes.iter().step_by(5) {
+            // Act
+            let expected = uniform_cost_search_graph(&weighted, nodes[0], goal).unwrap();
+            let actual = bidirectional_search_graph(&graph, nodes[0], goal).unwrap();
+
+            // Assert
+            assert_eq!(actual.cost(), expected.cost());
+            if let Some(path) = actual.path() {
+                for pair in path.windows(2) {
+                    assert!(graph.contains_edge(pair[0], pair[1]));
+                }
+            }
+        }
+    }
+}
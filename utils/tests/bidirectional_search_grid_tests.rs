@@ -0,0 +1,209 @@
+mod pathfinding;
+use pathfinding::grid_generator::{
+    generate_random_test_grid, generate_random_weighted_test_grid, generate_test_grid,
+    generate_weighted_test_grid,
+};
+use utils::pathfinding::a_star_grid::a_star_grid;
+use utils::pathfinding::bidirectional_search_grid::{
+    bidirectional_dijkstra_grid, bidirectional_search_grid,
+};
+use utils::pathfinding::uniform_cost_search_grid::uniform_cost_search_grid;
+use utils::search::outcome::SearchError;
+
+fn assert_connected(path: &[(usize, usize)]) {
+    for pair in path.windows(2) {
+        let (a, b) = (pair[0], pair[1]);
+        assert_eq!(
+            a.0.abs_diff(b.0) + a.1.abs_diff(b.1),
+            1,
+            "Path must move one cell at a time."
+        );
+    }
+}
+
+fn path_cost(grid: &[Vec<usize>], path: &[(usize, usize)]) -> usize {
+    path.iter().skip(1).map(|&(row, col)| grid[row][col]).sum()
+}
+
+#[test]
+fn test_bidirectional_search_grid_no_obstacles() {
+    // Arrange
+    let grid = generate_test_grid((5, 5), vec![]);
+    let start = (0, 0);
+    let goal = (4, 4);
+
+    // Act
+    let result = bidirectional_search_grid(&grid, start, goal).unwrap();
+
+    // Assert
+    let path = result.path().unwrap();
+    assert_eq!(path.len(), 9);
+    assert_eq!(path.first(), Some(&start));
+    assert_eq!(path.last(), Some(&goal));
+    assert_connected(path);
+}
+
+#[test]
+fn test_bidirectional_search_grid_with_cycles() {
+    // Arrange
+    let grid = generate_test_grid(
+        (5, 5),
+        vec![(1, 1), (1, 2), (1, 3), (2, 1), (3, 1), (3, 2), (3, 3)],
+    );
+    let start = (0, 0);
+    let goal = (2, 2);
+
+    // Act
+    let result = bidirectional_search_grid(&grid, start, goal).unwrap();
+
+    // Assert
+    let path = result.path().unwrap();
+    assert_eq!(result.cost(), Some(&8));
+    assert_eq!(path.first(), Some(&start));
+    assert_eq!(path.last(), Some(&goal));
+    assert_connected(path);
+    assert!(path.iter().all(|&(row, col)| !grid[row][col]));
+}
+
+#[test]
+fn test_bidirectional_search_grid_no_solution() {
+    let grid = generate_test_grid((5, 5), vec![(0, 2), (1, 2), (2, 2), (3, 2), (4, 2)]);
+
+    for goal in [(4, 4), (0, 2), (5, 0)] {
+        let result = bidirectional_search_grid(&grid, (0, 0), goal).unwrap();
+        assert!(!result.is_found(), "{goal:?} should be unreachable");
+    }
+
+    let result = bidirectional_search_grid(&grid, (1, 1), (1, 1)).unwrap();
+    assert_eq!(result.path(), Some(&[(1, 1)][..]));
+}
+
+#[test]
+fn test_bidirectional_search_grid_invalid_start() {
+    let grid = generate_test_grid((5, 5), vec![]);
+
+    assert_eq!(
+        bidirectional_search_grid(&grid, (5, 0), (0, 0)),
+        Err(SearchError::StartOutOfBounds { row: 5, col: 0 })
+    );
+    assert_eq!(
+        bidirectional_dijkstra_grid(&[], (0, 0), (0, 0)),
+        Err(SearchError::EmptyGrid)
+    );
+}
+
+#[test]
+fn test_bidirectional_search_grid_matches_a_star_length() {
+    for seed in 0..30 {
+        // Arrange
+        let grid = generate_random_test_grid((20, 20), 30, seed);
+        let start = (0, 0);
+        let goal = (19, 19);
+
+        // Act
+        let expected = a_star_grid(&grid, start, goal).unwrap();
+        let actual = bidirectional_search_grid(&grid, start, goal).unwrap();
+
+        // Assert
+        if grid[goal.0][goal.1] {
+            assert!(!actual.is_found());
+            continue;
+        }
+        assert_eq!(actual.cost(), expected.cost());
+        assert_connected(actual.path().unwrap_or_default());
+    }
+}
+
+#[test]
+fn test_bidirectional_dijkstra_grid_no_obstacles() {
+    // Arrange
+    let grid = generate_weighted_test_grid((5, 5), 1, vec![]);
+    let start = (2, 2);
+    let goal = (0, 4);
+
+    // Act
+    let result = bidirectional_dijkstra_grid(&grid, start, goal).unwrap();
+
+    // Assert
+    let path = result.path().unwrap();
+    assert_eq!(path.first(), Some(&start));
+    assert_eq!(path.last(), Some(&goal));
+    assert_eq!(path_cost(&grid, path), 4);
+    assert_eq!(result.cost(), Some(&4));
+    assert_connected(path);
+}
+
+#[test]
+fn test_bidirectional_dijkstra_grid_no_solution() {
+    let grid = generate_weighted_test_grid((5, 5), 1, vec![(0, 2), (1, 2), (2, 2), (3, 2), (4, 2)]);
+
+    for goal in [(4, 4), (2, 2)] {
+        let result = bidirectional_dijkstra_grid(&grid, (0, 0), goal).unwrap();
+        assert!(!result.is_found(), "{goal:?} should be unreachable");
+    }
+}
+
+#[test]
+fn test_bidirectional_dijkstra_grid_matches_uniform_cost_search() {
+    for seed in 0..30 {
+        // Arrange
+        let grid = generate_random_weighted_test_grid((15, 15), 9, 20, seed);
+        let start = (0, 0);
+        let goal = (14, 14);
+
+        // Act
+        let expected = uniform_cost_search_grid(&grid, start, goal).unwrap();
+        let actual = bidirectional_dijkstra_grid(&grid, start, goal).unwrap();
+
+        // Assert
+        assert_eq!(actual.cost(), expected.cost());
+        if let Some(path) = actual.path() {
+            assert_eq!(Some(&path_cost(&grid, path)), actual.cost());
+            assert_connected(path);
+        }
+    }
+}
+
+#[test]
+fn test_bidirectional_searches_start_on_walled_goal() {
+    // Arrange: the only cell is a wall, and the agent already stands on it.
+    let walls = vec![vec![true]];
+    let weights = vec![vec![usize::MAX]];
+
+    // Act
+    let breadth_first = bidirectional_search_grid(&walls, (0, 0), (0, 0)).unwrap();
+    let dijkstra = bidirectional_dijkstra_grid(&weights, (0, 0), (0, 0)).unwrap();
+
+    // Assert: the same as the single-direction searches.
+    assert_eq!(breadth_first.cost(), Some(&0));
+    assert_eq!(
+        breadth_first.cost(),
+        a_star_grid(&walls, (0, 0), (0, 0)).unwrap().cost()
+    );
+    assert_eq!(dijkstra.path(), Some(&[(0, 0)][..]));
+    assert_eq!(
+        dijkstra.cost(),
+        uniform_cost_search_grid(&weights, (0, 0), (0, 0))
+            .unwrap()
+            .cost()
+    );
+}
+
+#[test]
+fn test_bidirectional_searches_ragged_rows() {
+    // Arrange: the middle row holds a single cell, so the path has to go round.
+    let walls = vec![vec![false; 3], vec![false], vec![false; 3]];
+    let weights = vec![vec![1; 3], vec![1], vec![1; 3]];
+
+    // Act
+    let breadth_first = bidirectional_search_grid(&walls, (0, 2), (2, 2)).unwrap();
+    let dijkstra = bidirectional_dijkstra_grid(&weights, (0, 2), (2, 2)).unwrap();
+    let missing_goal = bidirectional_search_grid(&walls, (0, 0), (1, 1)).unwrap();
+
+    // Assert
+    assert_eq!(breadth_first.cost(), Some(&6));
+    assert_connected(breadth_first.path().unwrap());
+    assert_eq!(dijkstra.cost(), Some(&6));
+    assert_connected(dijkstra.path().unwrap());
+    assert!(!missing_goal.is_found());
+}
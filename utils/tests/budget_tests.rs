@@ -0,0 +1,188 @@
+mod pathfinding;
+mod search;
+use pathfinding::grid_generator::{generate_test_grid, generate_weighted_test_grid};
+use search::grid_generator::generate_balanced_grid;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use utils::pathfinding::a_star_grid::{a_star_grid, a_star_grid_observed};
+use utils::pathfinding::bidirectional_search_grid::bidirectional_search_grid_observed;
+use utils::pathfinding::djikstra_shortest_path_grid::djikstra_shortest_path_grid_observed;
+use utils::pathfinding::greedy_best_first_search_grid::greedy_best_first_search_grid_observed;
+use utils::search::bfs_grid::bfs_grid_observed;
+use utils::search::budget::SearchBudget;
+use utils::search::iterative_deepening_dfs_grid::iterative_deepening_dfs_grid_observed;
+use utils::search::observer::SearchTrace;
+
+fn assert_connected(path: &[(usize, usize)]) {
+    for pair in path.windows(2) {
+        let (a, b) = (pair[0], pair[1]);
+        assert_eq!(a.0.abs_diff(b.0) + a.1.abs_diff(b.1), 1);
+    }
+}
+
+fn manhattan(a: (usize, usize), b: (usize, usize)) -> usize {
+    a.0.abs_diff(b.0) + a.1.abs_diff(b.1)
+}
+
+#[test]
+fn test_budget_max_expansions_returns_closest_node() {
+    // Arrange
+    let grid = generate_test_grid((20, 20), vec![]);
+    let (start, goal) = ((0, 0), (19, 19));
+    let mut budget = SearchBudget::unlimited().with_max_expansions(5);
+
+    // Act
+    let outcome = a_star_grid_observed(&grid, start, goal, &mut budget).unwrap();
+
+    // Assert
+    assert!(outcome.is_budget_exhausted());
+    assert_eq!(outcome.path(), None);
+    assert_eq!(outcome.visited().len(), 5);
+    assert_eq!(budget.expanded(), 5);
+    let best_path = outcome.best_path().unwrap();
+    assert_eq!(best_path.first(), Some(&start));
+    assert_connected(best_path);
+    let closest = outcome
+        .visited()
+        .iter()
+        .map(|&cell| manhattan(cell, goal))
+        .min();
+    assert_eq!(best_path.last().map(|&cell| manhattan(cell, goal)), closest);
+}
+
+#[test]
+fn test_unlimited_budget_matches_plain_search() {
+    let grid = generate_test_grid((8, 8), vec![(1, 1), (1, 2), (2, 1)]);
+    let (start, goal) = ((0, 0), (7, 7));
+
+    let budgeted =
+        a_star_grid_observed(&grid, start, goal, &mut SearchBudget::unlimited()).unwrap();
+
+    assert_eq!(budgeted, a_star_grid(&grid, start, goal).unwrap());
+}
+
+#[test]
+fn test_budget_past_deadline_stops_before_first_expansion() {
+    // Arrange
+    let (grid, start) = generate_balanced_grid(4, 4);
+    let mut budget = SearchBudget::unlimited().with_deadline(Instant::now());
+
+    // Act
+    let outcome =
+        bfs_grid_observed(&grid, start, |_| true, |&cell| cell == 'P', &mut budget).unwrap();
+
+    // Assert
+    assert!(outcome.is_budget_exhausted());
+    assert!(outcome.visited().is_empty());
+    assert_eq!(outcome.best_path(), Some(&[start][..]));
+}
+
+#[test]
+fn test_budget_cancel_flag() {
+    // Arrange
+    let grid = generate_test_grid((10, 10), vec![]);
+    let cancelled = Arc::new(AtomicBool::new(false));
+    let budget = || {
+        SearchBudget::unlimited()
+            .with_time_limit(Duration::from_secs(60))
+            .with_cancel_flag(Arc::clone(&cancelled))
+    };
+
+    // Act
+    let running = a_star_grid_observed(&grid, (0, 0), (9, 9), &mut budget()).unwrap();
+    cancelled.store(true, std::sync::atomic::Ordering::Relaxed);
+    let stopped = a_star_grid_observed(&grid, (0, 0), (9, 9), &mut budget()).unwrap();
+
+    // Assert
+    assert!(running.is_found());
+    assert!(stopped.is_budget_exhausted());
+}
+
+#[test]
+fn test_budget_max_frontier() {
+    // Arrange: breadth-first layers on an open grid keep growing.
+    let (grid, start) = generate_balanced_grid(6, 6);
+    let mut budget = SearchBudget::unlimited().with_max_frontier(3);
+
+    // Act
+    let outcome = bfs_grid_observed(&grid, start, |_| true, |_| false, &mut budget).unwrap();
+
+    // Assert
+    assert!(outcome.is_budget_exhausted());
+    assert!(outcome.visited().len() < 36);
+}
+
+#[test]
+fn test_budget_paired_with_trace() {
+    // Arrange
+    let grid = generate_test_grid((10, 10), vec![]);
+    let mut trace = SearchTrace::new();
+    let budget = SearchBudget::unlimited().with_max_expansions(3);
+
+    // Act
+    let outcome = greedy_best_first_search_grid_observed(
+        &grid,
+        (0, 0),
+        (9, 9),
+        manhattan,
+        &mut (&mut trace, budget),
+    )
+    .unwrap();
+
+    // Assert
+    assert!(outcome.is_budget_exhausted());
+    assert_eq!(trace.expanded().len(), 3);
+    assert_eq!(trace.path(), None);
+    assert_eq!(outcome.best_path().unwrap().len(), 3);
+}
+
+#[test]
+fn test_budget_iterative_deepening_keeps_deepest_best_path() {
+    // Arrange
+    let (grid, start) = generate_balanced_grid(3, 3);
+    let mut trace = SearchTrace::new();
+    let budget = SearchBudget::unlimited().with_max_expansions(4);
+
+    // Act
+    let outcome = iterative_deepening_dfs_grid_observed(
+        &grid,
+        start,
+        |_| true,
+        |&cell| cell == 'I',
+        10,
+        &mut (&mut trace, budget),
+    )
+    .unwrap();
+
+    // Assert: depths 1 and 2 use up the budget, so the third iteration stops
+    // before expanding anything. Without a heuristic the last state expanded
+    // is the best one.
+    assert!(outcome.is_budget_exhausted());
+    assert_eq!(trace.expanded().len(), 4);
+    assert!(outcome.visited().is_empty());
+    let best_path = outcome.best_path().unwrap();
+    assert_eq!(best_path.len(), 2);
+    assert_eq!(best_path.last(), trace.expanded().last().copied());
+}
+
+#[test]
+fn test_budget_stops_bidirectional_and_dijkstra_searches() {
+    let grid = generate_test_grid((10, 10), vec![]);
+    let weights = generate_weighted_test_grid((10, 10), 1, vec![]);
+    let budget = || SearchBudget::unlimited().with_max_expansions(6);
+
+    let outcome = bidirectional_search_grid_observed(&grid, (0, 0), (9, 9), &mut budget()).unwrap();
+    let tree = djikstra_shortest_path_grid_observed(&weights, (0, 0), &mut budget()).unwrap();
+    let full =
+        djikstra_shortest_path_grid_observed(&weights, (0, 0), &mut SearchBudget::unlimited())
+            .unwrap();
+
+    assert!(outcome.is_budget_exhausted());
+    assert_eq!(outcome.visited().len(), 6);
+    assert_eq!(outcome.best_path().unwrap().first(), Some(&(0, 0)));
+    assert!(!tree.is_complete());
+    assert!(!tree.is_reachable((9, 9)));
+    assert!(full.is_complete());
+    assert_eq!(full.distance_to((9, 9)), Some(18));
+}
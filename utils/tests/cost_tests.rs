@@ -0,0 +1,45 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use utils::pathfinding::cost::{Cost, TotalOrder};
+
+#[test]
+fn test_integer_costs_saturate() {
+    assert_eq!(Cost::saturating_add(250u8, 10), u8::MAX);
+    assert_eq!(Cost::saturating_add(usize::MAX, 1), usize::MAX);
+    assert_eq!(Cost::saturating_add(3i32, 4), 7);
+}
+
+#[test]
+fn test_float_costs_overflow_to_infinity() {
+    assert_eq!(Cost::saturating_add(f64::MAX, f64::MAX), f64::INFINITY);
+    assert_eq!(Cost::saturating_add(0.5f32, 0.25), 0.75);
+}
+
+#[test]
+fn test_float_total_order_places_nan_last() {
+    assert_eq!(Cost::total_cmp(&1.0f64, &2.0), Ordering::Less);
+    assert_eq!(
+        Cost::total_cmp(&f64::NAN, &f64::INFINITY),
+        Ordering::Greater
+    );
+}
+
+#[test]
+fn test_total_order_in_binary_heap() {
+    // Arrange
+    let mut heap: BinaryHeap<_> = [2.5, 0.5, 1.5].into_iter().map(TotalOrder).collect();
+
+    // Act
+    let popped: Vec<f64> = std::iter::from_fn(|| heap.pop().map(|TotalOrder(cost)| cost)).collect();
+
+    // Assert
+    assert_eq!(popped, vec![2.5, 1.5, 0.5]);
+}
+
+#[test]
+fn test_scale_rounds_integers_down() {
+    assert_eq!(Cost::scale(7usize, 1.5), 10);
+    assert_eq!(Cost::scale(usize::MAX, 1.0), usize::MAX);
+    assert_eq!(Cost::scale(200u8, 2.0), u8::MAX);
+    assert_eq!(Cost::scale(1.5f64, 2.0), 3.0);
+}
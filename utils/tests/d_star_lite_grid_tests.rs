@@ -0,0 +1,216 @@
+mod pathfinding;
+use pathfinding::grid_generator::{generate_random_test_grid, generate_test_grid, next_random};
+use utils::pathfinding::a_star_grid::{a_star_grid, a_star_grid_with};
+use utils::pathfinding::d_star_lite_grid::DStarLite;
+use utils::pathfinding::grid_movement::{DistanceHeuristic, Movement};
+use utils::search::budget::SearchBudget;
+use utils::search::outcome::SearchError;
+
+/// Checks that `path` only takes single 4-connected steps into free cells.
+fn assert_walkable(grid: &[Vec<bool>], path: &[(usize, usize)]) {
+    for pair in path.windows(2) {
+        let (a, b) = (pair[0], pair[1]);
+        assert_eq!(a.0.abs_diff(b.0) + a.1.abs_diff(b.1), 1);
+        assert!(!grid[b.0][b.1], "The path should not enter a wall.");
+    }
+}
+
+#[test]
+fn test_d_star_lite_initial_plan_matches_a_star() {
+    // Arrange
+    let grid = generate_random_test_grid((20, 20), 25, 3);
+    let (start, goal) = ((0, 0), (19, 19));
+    let mut planner = DStarLite::new(&grid, start, goal).unwrap();
+
+    // Act
+    let outcome = planner.plan();
+
+    // Assert
+    let fresh = a_star_grid(&grid, start, goal).unwrap();
+    assert_eq!(outcome.cost(), fresh.cost());
+    assert_eq!(outcome.path().map(<[_]>::len), fresh.path().map(<[_]>::len));
+}
+
+#[test]
+fn test_d_star_lite_replans_after_cell_changes() {
+    for seed in 0..20 {
+        // Arrange
+        let mut grid = generate_random_test_grid((15, 15), 20, seed);
+        let (start, goal) = ((0, 0), (14, 14));
+        grid[goal.0][goal.1] = false;
+        let mut planner = DStarLite::new(&grid, start, goal).unwrap();
+        planner.plan();
+        let mut random = seed;
+
+        for _ in 0..10 {
+            // Act: flip a few cells between plans.
+            for _ in 0..5 {
+                let row = (next_random(&mut random) % 15) as usize;
+                let col = (next_random(&mut random) % 15) as usize;
+                if (row, col) != goal {
+                    grid[row][col] = !grid[row][col];
+                    planner.update_cell((row, col), grid[row][col]);
+                }
+            }
+            let outcome = planner.plan();
+
+            // Assert
+            let fresh = a_star_grid(&grid, start, goal).unwrap();
+            assert_eq!(outcome.cost(), fresh.cost(), "seed {seed}");
+            if let Some(path) = outcome.path() {
+                assert_eq!(path.first(), Some(&start));
+                assert_eq!(path.last(), Some(&goal));
+                assert_eq!(path.len() - 1, *fresh.cost().unwrap());
+                assert_walkable(&grid, path);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_d_star_lite_agent_discovers_walls_while_moving() {
+    // Arrange: the agent only learns about the wall once it stands next to it.
+    let grid = generate_test_grid((10, 10), vec![]);
+    let hidden: Vec<_> = (0..9).map(|col| (5, col)).collect();
+    let actual = generate_test_grid((10, 10), hidden.clone());
+    let (start, goal) = ((0, 0), (9, 0));
+    let mut planner = DStarLite::new(&grid, start, goal).unwrap();
+    let mut position = start;
+    let mut steps = 0;
+
+    // Act
+    while position != goal {
+        for &wall in &hidden {
+            if wall.0.abs_diff(position.0) + wall.1.abs_diff(position.1) <= 1 {
+                planner.update_cell(wall, true);
+            }
+        }
+        let outcome = planner.plan();
+        let path = outcome.path().expect("The goal stays reachable.");
+
+        // Assert: every plan is as short as a fresh search on what is known.
+        let fresh = a_star_grid(planner.grid(), position, goal).unwrap();
+        assert_eq!(outcome.cost(), fresh.cost());
+
+        position = path[1];
+        assert!(!actual[position.0][position.1]);
+        planner.move_start(position).unwrap();
+        steps += 1;
+    }
+
+    // Assert: down to the wall, around its open end and back.
+    assert_eq!(position, goal);
+    assert_eq!(steps, 4 + 2 * 9 + 4 + 1);
+}
+
+#[test]
+fn test_d_star_lite_repairs_less_than_a_fresh_plan() {
+    // Arrange
+    let grid = generate_test_grid((30, 30), vec![]);
+    let (start, goal) = ((0, 0), (29, 29));
+    let mut planner = DStarLite::new(&grid, start, goal).unwrap();
+    let initial = planner.plan();
+
+    // Act: a wall far off the path barely matters.
+    planner.update_cell((29, 0), true);
+    let repaired = planner.plan();
+
+    // Assert
+    assert_eq!(repaired.cost(), initial.cost());
+    assert!(repaired.visited().len() < initial.visited().len() / 4);
+}
+
+#[test]
+fn test_d_star_lite_goal_cut_off_and_reopened() {
+    // Arrange
+    let grid = generate_test_grid((5, 5), vec![]);
+    let (start, goal) = ((0, 0), (4, 4));
+    let mut planner = DStarLite::new(&grid, start, goal).unwrap();
+    planner.plan();
+
+    // Act
+    planner.update_cell((3, 4), true);
+    planner.update_cell((4, 3), true);
+    let blocked = planner.plan();
+    planner.update_cell((3, 4), false);
+    let reopened = planner.plan();
+
+    // Assert
+    assert!(!blocked.is_found());
+    assert_eq!(reopened.cost(), Some(&8));
+    assert_eq!(
+        reopened.path().unwrap()[reopened.path().unwrap().len() - 2],
+        (3, 4)
+    );
+}
+
+#[test]
+fn test_d_star_lite_eight_way_matches_a_star() {
+    for seed in 0..10 {
+        // Arrange
+        let mut grid = generate_random_test_grid((12, 12), 20, seed);
+        let (start, goal) = ((0, 0), (11, 11));
+        grid[goal.0][goal.1] = false;
+        let movement = Movement::eight_way();
+        let heuristic = DistanceHeuristic::Octile;
+        let mut planner =
+            DStarLite::with_movement(&grid, start, goal, movement, heuristic).unwrap();
+        planner.plan();
+
+        // Act
+        for cell in [(5, 5), (6, 6), (2, 9), (9, 2)] {
+            grid[cell.0][cell.1] = !grid[cell.0][cell.1];
+            planner.update_cell(cell, grid[cell.0][cell.1]);
+        }
+        let outcome = planner.plan();
+
+        // Assert
+        let fresh = a_star_grid_with(&grid, start, goal, movement, heuristic).unwrap();
+        assert_eq!(outcome.cost(), fresh.cost(), "seed {seed}");
+    }
+}
+
+#[test]
+fn test_d_star_lite_resumes_after_budget_stop() {
+    let grid = generate_test_grid((20, 20), vec![]);
+    let mut planner = DStarLite::new(&grid, (0, 0), (19, 19)).unwrap();
+
+    let stopped = planner.plan_observed(&mut SearchBudget::unlimited().with_max_expansions(5));
+    let resumed = planner.plan();
+
+    assert!(stopped.is_budget_exhausted());
+    assert_eq!(stopped.visited().len(), 5);
+    assert_eq!(resumed.cost(), Some(&38));
+}
+
+#[test]
+fn test_d_star_lite_rejects_positions_outside_grid() {
+    let grid = generate_test_grid((3, 3), vec![]);
+
+    let mut planner = DStarLite::new(&grid, (0, 0), (2, 2)).unwrap();
+
+    assert_eq!(
+        DStarLite::new(&grid, (3, 0), (2, 2)).unwrap_err(),
+        SearchError::StartOutOfBounds { row: 3, col: 0 }
+    );
+    assert_eq!(
+        planner.move_start((0, 7)),
+        Err(SearchError::StartOutOfBounds { row: 0, col: 7 })
+    );
+    assert_eq!(planner.start(), (0, 0));
+}
+
+#[test]
+fn test_d_star_lite_ragged_rows() {
+    // Arrange: the middle row holds a single cell, so the path has to go round.
+    let mut grid = generate_test_grid((3, 3), vec![]);
+    grid[1].truncate(1);
+
+    // Act
+    let around = DStarLite::new(&grid, (0, 2), (2, 2)).unwrap().plan();
+    let missing_goal = DStarLite::new(&grid, (0, 2), (1, 1)).unwrap().plan();
+
+    // Assert
+    assert_eq!(around.cost(), Some(&6));
+    assert!(!missing_goal.is_found());
+}
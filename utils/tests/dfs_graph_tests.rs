@@ -1,68 +1,140 @@
 mod search;
 use search::graph_generator::{
-    generate_balanced_graph, generate_balanced_graph_with_cycles, generate_unbalanced_graph,
+    generate_balanced_graph, generate_balanced_graph_with_cycles, generate_unbalanced_graph, labels,
 };
-use utils::search::dfs_graph::dfs_graph;
+use petgraph::graph::{Graph, NodeIndex};
+use petgraph::graphmap::DiGraphMap;
+use petgraph::visit::{EdgeFiltered, Reversed};
+use utils::search::dfs_graph::{dfs_graph, dfs_graph_where};
+use utils::search::outcome::SearchError;
 
 #[test]
 fn test_dfs_graph_target_found() {
     let (graph, start) = generate_balanced_graph(3);
-    let result = dfs_graph(&graph, start, Some(&'C'));
-    assert_eq!(result, Err(Some('C')));
+    let result = dfs_graph(&graph, start, Some(&'C')).unwrap();
+    assert_eq!(result.goal().map(|&node| graph[node]), Some('C'));
 }
 
 #[test]
 fn test_dfs_graph_target_not_found() {
     let (graph, start) = generate_balanced_graph(3);
-    let result = dfs_graph(&graph, start, Some(&'D'));
-    assert_eq!(result, Err(None));
+    let result = dfs_graph(&graph, start, Some(&'D')).unwrap();
+    assert!(!result.is_found());
 }
 
 #[test]
 fn test_dfs_graph_return_all_nodes() {
     let (graph, start) = generate_balanced_graph(3);
-    let result = dfs_graph(&graph, start, None);
-    assert_eq!(result, Ok(vec!['A', 'B', 'C']));
+    let result = dfs_graph(&graph, start, None).unwrap();
+    assert_eq!(labels(&graph, result.visited()), vec!['A', 'B', 'C']);
 }
 
 #[test]
 fn test_dfs_graph_unbalanced_with_target_found() {
     let (graph, start) = generate_unbalanced_graph(3);
-    let result = dfs_graph(&graph, start, Some(&'C'));
-    assert_eq!(result, Err(Some('C')));
+    let result = dfs_graph(&graph, start, Some(&'C')).unwrap();
+    assert_eq!(result.goal().map(|&node| graph[node]), Some('C'));
 }
 
 #[test]
 fn test_dfs_graph_unbalanced_with_target_not_found() {
     let (graph, start) = generate_unbalanced_graph(3);
-    let result = dfs_graph(&graph, start, Some(&'D'));
-    assert_eq!(result, Err(None));
+    let result = dfs_graph(&graph, start, Some(&'D')).unwrap();
+    assert!(!result.is_found());
 }
 
 #[test]
 fn test_dfs_graph_unbalanced_return_all_nodes() {
     let (graph, start) = generate_unbalanced_graph(3);
-    let result = dfs_graph(&graph, start, None);
-    assert_eq!(result, Ok(vec!['A', 'B', 'C']));
+    let result = dfs_graph(&graph, start, None).unwrap();
+    assert_eq!(labels(&graph, result.visited()), vec!['A', 'B', 'C']);
 }
 
 #[test]
 fn test_dfs_graph_balanced_with_cycles_with_target_found() {
     let (graph, start) = generate_balanced_graph_with_cycles(3);
-    let result = dfs_graph(&graph, start, Some(&'C'));
-    assert_eq!(result, Err(Some('C')));
+    let result = dfs_graph(&graph, start, Some(&'C')).unwrap();
+    assert_eq!(result.goal().map(|&node| graph[node]), Some('C'));
 }
 
 #[test]
 fn test_dfs_graph_balanced_with_cycles_with_target_not_found() {
     let (graph, start) = generate_balanced_graph_with_cycles(3);
-    let result = dfs_graph(&graph, start, Some(&'D'));
-    assert_eq!(result, Err(None));
+    let result = dfs_graph(&graph, start, Some(&'D')).unwrap();
+    assert!(!result.is_found());
 }
 
 #[test]
 fn test_dfs_graph_balanced_with_cycles_return_all_nodes() {
     let (graph, start) = generate_balanced_graph_with_cycles(3);
-    let result = dfs_graph(&graph, start, None);
-    assert_eq!(result, Ok(vec!['A', 'B', 'C']));
+    let result = dfs_graph(&graph, start, None).unwrap();
+    assert_eq!(labels(&graph, result.visited()), vec!['A', 'B', 'C']);
+}
+
+#[test]
+fn test_dfs_graph_reversed_view() {
+    // Arrange: walk from a leaf back up to the root.
+    let (graph, start) = generate_balanced_graph(7);
+    let leaf = graph
+        .node_indices()
+        .find(|&node| graph[node] == 'G')
+        .unwrap();
+
+    // Act
+    let forward = dfs_graph(&graph, leaf, None).unwrap();
+    let reversed = dfs_graph(Reversed(&graph), leaf, None).unwrap();
+
+    // Assert
+    assert_eq!(labels(&graph, forward.visited()), vec!['G']);
+    assert_eq!(labels(&graph, reversed.visited()), vec!['G', 'C', 'A']);
+    let to_root = dfs_graph(Reversed(&graph), leaf, Some(&graph[start])).unwrap();
+    assert_eq!(labels(&graph, to_root.path().unwrap()), vec!['G', 'C', 'A']);
+}
+
+#[test]
+fn test_dfs_graph_start_out_of_range() {
+    // Arrange
+    let (graph, _) = generate_balanced_graph(3);
+
+    // Act
+    let result = dfs_graph(&graph, NodeIndex::new(3), None);
+
+    // Assert
+    assert_eq!(result, Err(SearchError::InvalidNode));
+}
+
+#[test]
+fn test_dfs_graph_graph_map() {
+    // Arrange: a GraphMap whose nodes are their own names.
+    let graph =
+        DiGraphMap::<&str, usize>::from_edges([("A", "B", 1), ("B", "C", 1), ("A", "D", 1)]);
+
+    // Act
+    let result = dfs_graph_where(&graph, "A", |node| node == "C").unwrap();
+    let missing = dfs_graph_where(&graph, "Z", |node| node == "C");
+
+    // Assert
+    assert_eq!(result.path(), Some(&["A", "B", "C"][..]));
+    assert_eq!(missing, Err(SearchError::InvalidNode));
+}
+
+#[test]
+fn test_dfs_graph_edge_filtered_view() {
+    // Arrange: hide the direct edge so the search has to take the long way round.
+    let mut graph = Graph::<&str, usize>::new();
+    let a = graph.add_node("A");
+    let b = graph.add_node("B");
+    let c = graph.add_node("C");
+    graph.add_edge(a, c, 1);
+    graph.add_edge(a, b, 2);
+    graph.add_edge(b, c, 2);
+    let view = EdgeFiltered::from_fn(&graph, |edge| *edge.weight() > 1);
+
+    // Act
+    let unfiltered = dfs_graph_where(&graph, a, |node| node == c).unwrap();
+    let filtered = dfs_graph_where(&view, a, |node| node == c).unwrap();
+
+    // Assert
+    assert_eq!(unfiltered.path(), Some(&[a, c][..]));
+    assert_eq!(filtered.path(), Some(&[a, b, c][..]));
 }
@@ -1,19 +1,87 @@
 mod search;
-use search::grid_generator::generate_balanced_grid;
-use utils::search::dfs_grid::dfs_grid;
+use search::grid_generator::{generate_balanced_grid, generate_maze_grid};
+use utils::search::dfs_grid::{dfs_grid, dfs_grid_where};
 
 #[test]
 fn test_dfs_graph_balanced_with_target_found() {
     let (graph, start) = generate_balanced_grid(3, 3);
-    let (found, position) = dfs_grid(&graph, start, &'C');
-    assert_eq!(found, true);
-    assert_eq!(position, Some((0, 2)));
+    let path = dfs_grid(&graph, start, &'C')
+        .unwrap()
+        .into_path()
+        .expect("DFS should find the target.");
+    assert_eq!(path.first(), Some(&start));
+    assert_eq!(path.last(), Some(&(0, 2)));
 }
 
 #[test]
 fn test_dfs_graph_balanced_with_target_not_found() {
     let (graph, start) = generate_balanced_grid(3, 3);
-    let (found, position) = dfs_grid(&graph, start, &'Z');
-    assert_eq!(found, false);
-    assert_eq!(position, None);
-}
\ No newline at end of file
+    let path = dfs_grid(&graph, start, &'Z').unwrap().into_path();
+    assert_eq!(path, None);
+}
+
+#[test]
+fn test_dfs_grid_path_follows_search_order() {
+    // Arrange: Neighbors pushed last are explored first, so DFS snakes through every row.
+    let (grid, start) = generate_balanced_grid(3, 3);
+
+    // Act
+    let path = dfs_grid(&grid, start, &'I').unwrap().into_path();
+
+    // Assert
+    assert_eq!(
+        path,
+        Some(vec![
+            (0, 0),
+            (0, 1),
+            (0, 2),
+            (1, 2),
+            (1, 1),
+            (1, 0),
+            (2, 0),
+            (2, 1),
+            (2, 2)
+        ])
+    );
+}
+
+#[test]
+fn test_dfs_grid_where_avoids_walls() {
+    // Arrange
+    let (grid, start) = generate_maze_grid(&["S.#E", ".##.", "...."]);
+
+    // Act
+    let path = dfs_grid_where(&grid, start, |&cell| cell != '#', |&cell| cell == 'E')
+        .unwrap()
+        .into_path()
+        .expect("DFS should find the exit.");
+
+    // Assert
+    assert_eq!(path.first(), Some(&start));
+    assert_eq!(path.last(), Some(&(0, 3)));
+    assert!(path.iter().all(|&(row, col)| grid[row][col] != '#'));
+}
+
+#[test]
+fn test_dfs_grid_where_walled_in() {
+    let (grid, start) = generate_maze_grid(&["S#E", "##."]);
+
+    let path = dfs_grid_where(&grid, start, |&cell| cell != '#', |&cell| cell == 'E')
+        .unwrap()
+        .into_path();
+
+    assert_eq!(path, None);
+}
+
+#[test]
+fn test_dfs_grid_ragged_rows() {
+    // Arrange: the middle row holds a single cell, so the path has to go round.
+    let (grid, start) = generate_maze_grid(&["..S", ".", "..E"]);
+
+    // Act
+    let result = dfs_grid(&grid, start, &'E').unwrap();
+
+    // Assert
+    assert_eq!(result.goal(), Some(&(2, 2)));
+    assert_eq!(result.cost(), Some(&6));
+}
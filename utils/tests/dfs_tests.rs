@@ -0,0 +1,47 @@
+mod search;
+use search::problem_generator::KeyDoorMaze;
+use utils::search::dfs::dfs;
+
+#[test]
+fn test_dfs_key_door_maze() {
+    // Arrange
+    let maze = KeyDoorMaze::parse(&["S.#E", "..D.", "K###"]);
+
+    // Act
+    let result = dfs(&maze);
+
+    // Assert
+    let path = result.path().expect("DFS should escape the maze.");
+    assert_eq!(path.first(), Some(&((0, 0), false)));
+    assert_eq!(path.last(), Some(&((0, 3), true)));
+    for pair in path.windows(2) {
+        let (((r1, c1), _), ((r2, c2), _)) = (pair[0], pair[1]);
+        assert_eq!(r1.abs_diff(r2) + c1.abs_diff(c2), 1);
+    }
+}
+
+#[test]
+fn test_dfs_explores_last_successor_first() {
+    // Arrange: From the start, Down is yielded before Right, so Right is explored first.
+    let maze = KeyDoorMaze::parse(&["S.", ".E"]);
+
+    // Act
+    let result = dfs(&maze);
+
+    // Assert
+    assert_eq!(
+        result.visited(),
+        [((0, 0), false), ((0, 1), false), ((1, 1), false)]
+    );
+    assert_eq!(result.path(), Some(result.visited()));
+}
+
+#[test]
+fn test_dfs_key_door_maze_no_key() {
+    let maze = KeyDoorMaze::parse(&["S.#E", "..D.", ".###"]);
+
+    let result = dfs(&maze);
+
+    assert!(!result.is_found());
+    assert_eq!(result.visited().len(), 5);
+}
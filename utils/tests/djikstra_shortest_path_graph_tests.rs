@@ -0,0 +1,103 @@
+use petgraph::graph::Graph;
+use utils::pathfinding::djikstra_shortest_path_graph::djikstra_shortest_path_graph;
+
+#[test]
+fn test_djikstra_shortest_path_graph_distances_to_every_node() {
+    // Arrange
+    let mut graph = Graph::new();
+    let a = graph.add_node("A");
+    let b = graph.add_node("B");
+    let c = graph.add_node("C");
+    let d = graph.add_node("D");
+
+    graph.add_edge(a, b, 1);
+    graph.add_edge(b, c, 2);
+    graph.add_edge(a, c, 5);
+    graph.add_edge(c, d, 1);
+
+    // Act
+    let tree = djikstra_shortest_path_graph(&graph, a).unwrap();
+
+    // Assert
+    assert_eq!(tree.source(), a);
+    assert_eq!(tree.distance_to(a), Some(0));
+    assert_eq!(tree.distance_to(b), Some(1));
+    assert_eq!(tree.distance_to(c), Some(3));
+    assert_eq!(tree.distance_to(d), Some(4));
+    assert_eq!(
+        tree.distances().collect::<Vec<_>>(),
+        vec![(a, 0), (b, 1), (c, 3), (d, 4)]
+    );
+}
+
+#[test]
+fn test_djikstra_shortest_path_graph_paths_to_many_targets() {
+    // Arrange
+    let mut graph = Graph::new();
+    let a = graph.add_node("A");
+    let b = graph.add_node("B");
+    let c = graph.add_node("C");
+    let d = graph.add_node("D");
+    let e = graph.add_node("E");
+
+    graph.add_edge(a, b, 4);
+    graph.add_edge(a, c, 1);
+    graph.add_edge(c, b, 2);
+    graph.add_edge(b, d, 1);
+    graph.add_edge(c, e, 7);
+    graph.add_edge(d, e, 1);
+
+    // Act
+    let tree = djikstra_shortest_path_graph(&graph, a).unwrap();
+
+    // Assert: One search answers every query.
+    assert_eq!(tree.path_to(a), Some(vec![a]));
+    assert_eq!(tree.path_to(b), Some(vec![a, c, b]));
+    assert_eq!(tree.path_to(d), Some(vec![a, c, b, d]));
+    assert_eq!(tree.path_to(e), Some(vec![a, c, b, d, e]));
+    assert_eq!(tree.distance_to(e), Some(5));
+    assert_eq!(tree.predecessor(e), Some(d));
+    assert_eq!(tree.predecessor(a), None);
+}
+
+#[test]
+fn test_djikstra_shortest_path_graph_unreachable_node() {
+    // Arrange
+    let mut graph = Graph::new();
+    let a = graph.add_node("A");
+    let b = graph.add_node("B");
+    let c = graph.add_node("C");
+
+    graph.add_edge(a, b, 1);
+    graph.add_edge(c, a, 1);
+
+    // Act
+    let tree = djikstra_shortest_path_graph(&graph, a).unwrap();
+
+    // Assert
+    assert!(tree.is_reachable(b));
+    assert!(!tree.is_reachable(c));
+    assert_eq!(tree.distance_to(c), None);
+    assert_eq!(tree.path_to(c), None);
+}
+
+#[test]
+fn test_djikstra_shortest_path_graph_with_cycles() {
+    // Arrange
+    let mut graph = Graph::new();
+    let a = graph.add_node("A");
+    let b = graph.add_node("B");
+    let c = graph.add_node("C");
+
+    graph.add_edge(a, b, 2);
+    graph.add_edge(b, a, 2);
+    graph.add_edge(b, c, 2);
+    graph.add_edge(c, b, 1);
+
+    // Act
+    let tree = djikstra_shortest_path_graph(&graph, c).unwrap();
+
+    // Assert
+    assert_eq!(tree.path_to(a), Some(vec![c, b, a]));
+    assert_eq!(tree.distance_to(a), Some(3));
+}
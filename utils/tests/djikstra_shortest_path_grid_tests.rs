@@ -0,0 +1,105 @@
+mod pathfinding;
+use pathfinding::grid_generator::generate_weighted_test_grid;
+use utils::pathfinding::djikstra_shortest_path_grid::djikstra_shortest_path_grid;
+use utils::pathfinding::uniform_cost_search_grid::uniform_cost_search_grid;
+
+#[test]
+fn test_djikstra_shortest_path_grid_no_obstacles() {
+    // Arrange
+    let grid = generate_weighted_test_grid((5, 5), 1, vec![]);
+    let start = (0, 0);
+
+    // Act
+    let tree = djikstra_shortest_path_grid(&grid, start).unwrap();
+
+    // Assert
+    assert_eq!(tree.source(), start);
+    assert_eq!(tree.distance_to(start), Some(0));
+    assert_eq!(tree.distance_to((4, 4)), Some(8));
+    assert_eq!(tree.distance_to((0, 4)), Some(4));
+
+    let path = tree.path_to((4, 4)).unwrap();
+    assert_eq!(path.first(), Some(&start));
+    assert_eq!(path.last(), Some(&(4, 4)));
+    assert_eq!(path.len(), 9);
+}
+
+#[test]
+fn test_djikstra_shortest_path_grid_matches_uniform_cost_search() {
+    // Arrange
+    let mut grid = generate_weighted_test_grid(
+        (5, 5),
+        1,
+        vec![(1, 1), (1, 2), (1, 3), (2, 1), (3, 1), (3, 2), (3, 3)],
+    );
+    grid[0][4] = 9;
+    grid[4][0] = 3;
+    let start = (0, 0);
+
+    // Act
+    let tree = djikstra_shortest_path_grid(&grid, start).unwrap();
+
+    // Assert: Every query agrees with a dedicated uniform cost search.
+    for row in 0..5 {
+        for col in 0..5 {
+            let expected = uniform_cost_search_grid(&grid, start, (row, col)).unwrap();
+            match tree.path_to((row, col)) {
+                Some(path) => {
+                    let cost: usize = path.iter().skip(1).map(|&(r, c)| grid[r][c]).sum();
+                    assert_eq!(tree.distance_to((row, col)), Some(cost));
+                    assert_eq!(expected.cost(), Some(&cost));
+                }
+                None => assert!(!expected.is_found()),
+            }
+        }
+    }
+}
+
+#[test]
+fn test_djikstra_shortest_path_grid_unreachable_cells() {
+    // Arrange
+    let grid = generate_weighted_test_grid((5, 5), 1, vec![(0, 2), (1, 2), (2, 2), (3, 2), (4, 2)]);
+
+    // Act
+    let tree = djikstra_shortest_path_grid(&grid, (0, 0)).unwrap();
+
+    // Assert
+    assert!(tree.is_reachable((4, 1)));
+    assert!(!tree.is_reachable((4, 4)));
+    assert!(!tree.is_reachable((0, 2)));
+    assert_eq!(tree.path_to((4, 4)), None);
+    assert_eq!(tree.distances()[4][4], None);
+    assert_eq!(tree.distance_to((9, 9)), None);
+}
+
+#[test]
+fn test_djikstra_shortest_path_grid_saturated_cost_is_reachable() {
+    // Arrange: entering the far cell costs just under the impassable marker.
+    let mut grid = generate_weighted_test_grid((1, 3), 1, vec![]);
+    grid[0][2] = usize::MAX - 1;
+
+    // Act
+    let tree = djikstra_shortest_path_grid(&grid, (0, 0)).unwrap();
+
+    // Assert: the total saturates but the cell is still reached.
+    assert_eq!(tree.distance_to((0, 2)), Some(usize::MAX));
+    assert_eq!(tree.path_to((0, 2)), Some(vec![(0, 0), (0, 1), (0, 2)]));
+}
+
+#[test]
+fn test_djikstra_shortest_path_grid_ragged_rows() {
+    // Arrange: the middle row holds a single cell.
+    let grid = vec![vec![1, 1, 1], vec![1], vec![1, 1, 1]];
+
+    // Act
+    let tree = djikstra_shortest_path_grid(&grid, (0, 0)).unwrap();
+
+    // Assert: the right column is only reached around the short row.
+    assert_eq!(tree.distance_to((2, 2)), Some(4));
+    assert_eq!(tree.distance_to((0, 2)), Some(2));
+    assert_eq!(tree.distance_to((1, 1)), None);
+    assert_eq!(
+        tree.path_to((2, 2)),
+        Some(vec![(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)])
+    );
+}
@@ -0,0 +1,256 @@
+mod pathfinding;
+use pathfinding::grid_generator::{generate_weighted_test_grid, next_random};
+use utils::pathfinding::a_star_grid::a_star_grid_with;
+use utils::pathfinding::flow_field_grid::FlowField;
+use utils::pathfinding::grid_movement::{DistanceHeuristic, Movement};
+use utils::pathfinding::uniform_cost_search_grid::uniform_cost_search_grid;
+use utils::search::outcome::SearchError;
+
+/// A grid of weights 1 to 5 with roughly `wall_percent` impassable cells.
+fn generate_random_weighted_grid(size: usize, wall_percent: u64, seed: u64) -> Vec<Vec<usize>> {
+    let mut seed = seed;
+    (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| {
+                    if next_random(&mut seed) % 100 < wall_percent {
+                        usize::MAX
+                    } else {
+                        1 + (next_random(&mut seed) % 5) as usize
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// The cheapest cost from `position` to any of `sources`, by running one
+/// uniform cost search per source.
+fn cheapest_to_any(
+    grid: &[Vec<usize>],
+    position: (usize, usize),
+    sources: &[(usize, usize)],
+) -> Option<usize> {
+    sources
+        .iter()
+        .filter_map(|&source| {
+            uniform_cost_search_grid(grid, position, source)
+                .unwrap()
+                .cost()
+                .copied()
+        })
+        .min()
+}
+
+/// Checks every passable cell against `cheapest_to_any`, and that following the
+/// field pays exactly the distance it reports.
+fn assert_matches_searches(field: &FlowField, grid: &[Vec<usize>], sources: &[(usize, usize)]) {
+    for row in 0..grid.len() {
+        for col in 0..grid[row].len() {
+            if grid[row][col] == usize::MAX {
+                continue;
+            }
+
+            let expected = cheapest_to_any(grid, (row, col), sources);
+            assert_eq!(field.distance((row, col)), expected, "cell ({row}, {col})");
+
+            if let Some(path) = field.path_from((row, col)) {
+                let paid: usize = path[1..].iter().map(|&(r, c)| grid[r][c]).sum();
+                assert_eq!(Some(paid), expected);
+                assert!(sources.contains(path.last().unwrap()));
+                assert_eq!(field.nearest_source((row, col)), path.last().copied());
+            }
+        }
+    }
+}
+
+#[test]
+fn test_flow_field_matches_uniform_cost_search() {
+    for seed in 0..5 {
+        // Arrange
+        let grid = generate_random_weighted_grid(10, 20, seed);
+        let sources = [(0, 0), (9, 9), (4, 6)];
+
+        // Act
+        let field = FlowField::new(&grid, &sources).unwrap();
+
+        // Assert
+        assert_matches_searches(&field, &grid, &sources);
+    }
+}
+
+#[test]
+fn test_flow_field_directions_point_downhill() {
+    // Arrange
+    let grid = generate_weighted_test_grid((5, 5), 1, vec![(1, 1), (1, 2), (1, 3)]);
+
+    // Act
+    let field = FlowField::new(&grid, &[(2, 2)]).unwrap();
+
+    // Assert
+    assert_eq!(field.distance((2, 2)), Some(0));
+    assert_eq!(field.direction((2, 2)), None);
+    assert_eq!(field.direction((3, 2)), Some((-1, 0)));
+    assert_eq!(field.direction((2, 0)), Some((0, 1)));
+    assert_eq!(field.distance((0, 2)), Some(6));
+    assert_eq!(
+        field.next_step((0, 2)).map(|cell| field.distance(cell)),
+        Some(Some(5))
+    );
+}
+
+#[test]
+fn test_flow_field_eight_way_matches_a_star() {
+    // Arrange: walls and unit weights, so A* on the matching wall grid agrees.
+    let mut grid = generate_random_weighted_grid(8, 25, 11)
+        .into_iter()
+        .map(|row| {
+            row.into_iter()
+                .map(|w| if w == usize::MAX { w } else { 1 })
+                .collect()
+        })
+        .collect::<Vec<Vec<usize>>>();
+    let source = (4, 4);
+    grid[source.0][source.1] = 1;
+    let walls: Vec<Vec<bool>> = grid
+        .iter()
+        .map(|row| row.iter().map(|&w| w == usize::MAX).collect())
+        .collect();
+    let movement = Movement::eight_way();
+
+    // Act
+    let field = FlowField::with_movement(&grid, &[source], movement).unwrap();
+
+    // Assert
+    for row in 0..8 {
+        for col in 0..8 {
+            if !walls[row][col] {
+                let fresh = a_star_grid_with(
+                    &walls,
+                    (row, col),
+                    source,
+                    movement,
+                    DistanceHeuristic::Octile,
+                )
+                .unwrap();
+                assert_eq!(
+                    field.distance((row, col)).as_ref(),
+                    fresh.cost(),
+                    "cell ({row}, {col})"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_flow_field_updates_incrementally_as_sources_move() {
+    for seed in 0..5 {
+        // Arrange
+        let grid = generate_random_weighted_grid(9, 15, seed);
+        let mut sources = vec![(0, 0), (8, 8)];
+        let mut field = FlowField::new(&grid, &sources).unwrap();
+        let mut random = seed;
+
+        for _ in 0..6 {
+            // Act: move a random source to a random cell.
+            let slot = (next_random(&mut random) % sources.len() as u64) as usize;
+            let to = (
+                (next_random(&mut random) % 9) as usize,
+                (next_random(&mut random) % 9) as usize,
+            );
+            field.move_source(sources[slot], to).unwrap();
+            sources[slot] = to;
+
+            // Assert: the same as a field built from scratch.
+            let fresh = FlowField::new(&grid, &sources).unwrap();
+            for row in 0..9 {
+                for col in 0..9 {
+                    assert_eq!(field.distance((row, col)), fresh.distance((row, col)));
+                }
+            }
+        }
+        assert_matches_searches(&field, &grid, &sources);
+    }
+}
+
+#[test]
+fn test_flow_field_add_and_remove_sources() {
+    // Arrange
+    let grid = generate_weighted_test_grid((1, 9), 1, vec![]);
+    let mut field = FlowField::new(&grid, &[(0, 0)]).unwrap();
+
+    // Act & Assert
+    assert_eq!(field.distance((0, 8)), Some(8));
+    field.add_source((0, 8)).unwrap();
+    assert_eq!(field.distance((0, 5)), Some(3));
+    assert_eq!(field.nearest_source((0, 5)), Some((0, 8)));
+    assert!(field.remove_source((0, 0)));
+    assert!(!field.remove_source((0, 0)));
+    assert_eq!(field.distance((0, 1)), Some(7));
+    assert!(field.remove_source((0, 8)));
+    assert_eq!(field.distance((0, 4)), None);
+    assert_eq!(field.path_from((0, 4)), None);
+}
+
+#[test]
+fn test_flow_field_walls_and_unreachable_cells() {
+    // Arrange: a wall column cuts the right side off, and one source is a wall.
+    let grid = generate_weighted_test_grid((3, 5), 1, vec![(0, 2), (1, 2), (2, 2), (2, 0)]);
+
+    // Act
+    let mut field = FlowField::new(&grid, &[(0, 0), (2, 0)]).unwrap();
+
+    // Assert
+    assert_eq!(field.distance((2, 1)), Some(3));
+    assert_eq!(field.distance((0, 4)), None);
+    assert_eq!(field.next_step((0, 4)), None);
+    assert_eq!(field.distance((2, 0)), None);
+    assert!(field.remove_source((2, 0)));
+    assert_eq!(field.distance((2, 1)), Some(3));
+}
+
+#[test]
+fn test_flow_field_rejects_sources_outside_grid() {
+    let grid = generate_weighted_test_grid((3, 3), 1, vec![]);
+
+    let mut field = FlowField::new(&grid, &[(1, 1)]).unwrap();
+
+    assert_eq!(
+        FlowField::new(&grid, &[(3, 0)]).unwrap_err(),
+        SearchError::StartOutOfBounds { row: 3, col: 0 }
+    );
+    assert_eq!(
+        FlowField::new(&[], &[]).unwrap_err(),
+        SearchError::EmptyGrid
+    );
+    assert_eq!(
+        FlowField::new(&[vec![]], &[]).unwrap_err(),
+        SearchError::EmptyGrid
+    );
+    assert_eq!(
+        field.move_source((1, 1), (0, 7)),
+        Err(SearchError::StartOutOfBounds { row: 0, col: 7 })
+    );
+    assert_eq!(
+        field.add_source((4, 1)),
+        Err(SearchError::StartOutOfBounds { row: 4, col: 1 })
+    );
+    assert_eq!(field.sources(), [(1, 1)]);
+    assert_eq!(field.distance((0, 0)), Some(2));
+}
+
+#[test]
+fn test_flow_field_ragged_rows() {
+    // Arrange: the middle row holds a single cell, so the field flows round it.
+    let mut grid = generate_weighted_test_grid((3, 3), 1, vec![]);
+    grid[1].truncate(1);
+
+    // Act
+    let field = FlowField::new(&grid, &[(2, 2)]).unwrap();
+
+    // Assert
+    assert_matches_searches(&field, &grid, &[(2, 2)]);
+    assert_eq!(field.distance((0, 2)), Some(6));
+    assert_eq!(field.distance((1, 1)), None);
+}
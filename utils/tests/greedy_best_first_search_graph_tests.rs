@@ -0,0 +1,113 @@
+use petgraph::graph::Graph;
+use petgraph::graphmap::UnGraphMap;
+use utils::pathfinding::greedy_best_first_search_graph::greedy_best_first_search_graph;
+
+#[test]
+fn test_greedy_best_first_search_graph_path_found() {
+    // Arrange
+    let mut graph = Graph::<&str, usize>::new();
+    let a = graph.add_node("A");
+    let b = graph.add_node("B");
+    let c = graph.add_node("C");
+    let d = graph.add_node("D");
+
+    graph.add_edge(a, b, 1);
+    graph.add_edge(b, c, 1);
+    graph.add_edge(c, d, 1);
+
+    // Act
+    let result = greedy_best_first_search_graph(&graph, a, d, |_current, _goal| 0).unwrap();
+
+    // Assert
+    assert_eq!(result.path(), Some(&[a, b, c, d][..]));
+    assert_eq!(result.visited(), [a, b, c, d]);
+}
+
+#[test]
+fn test_greedy_best_first_search_graph_follows_heuristic_not_cost() {
+    // Arrange: The direct edge A -> D is expensive but looks closest to the goal.
+    let mut graph = Graph::<&str, usize>::new();
+    let a = graph.add_node("A");
+    let b = graph.add_node("B");
+    let c = graph.add_node("C");
+    let d = graph.add_node("D");
+    let e = graph.add_node("E");
+
+    graph.add_edge(a, b, 1);
+    graph.add_edge(b, c, 1);
+    graph.add_edge(c, e, 1);
+    graph.add_edge(a, d, 10);
+    graph.add_edge(d, e, 10);
+
+    let estimates = [3, 2, 1, 0, 0];
+
+    // Act
+    let result =
+        greedy_best_first_search_graph(&graph, a, e, |node, _goal| estimates[node.index()])
+            .unwrap();
+
+    // Assert: Greedy trusts the heuristic and takes the costlier route.
+    assert_eq!(result.path(), Some(&[a, d, e][..]));
+    assert_eq!(result.cost(), Some(&20));
+    assert_eq!(result.visited(), [a, d, e]);
+}
+
+#[test]
+fn test_greedy_best_first_search_graph_with_cycles() {
+    // Arrange
+    let mut graph = Graph::<&str, usize>::new();
+    let a = graph.add_node("A");
+    let b = graph.add_node("B");
+    let c = graph.add_node("C");
+    let d = graph.add_node("D");
+
+    graph.add_edge(a, b, 1);
+    graph.add_edge(b, a, 1);
+    graph.add_edge(b, c, 1);
+    graph.add_edge(c, b, 1);
+    graph.add_edge(c, d, 1);
+
+    // Act
+    let result = greedy_best_first_search_graph(&graph, a, d, |_current, _goal| 0).unwrap();
+
+    // Assert
+    assert_eq!(result.path(), Some(&[a, b, c, d][..]));
+    assert_eq!(result.cost(), Some(&3));
+}
+
+#[test]
+fn test_greedy_best_first_search_graph_no_solution() {
+    // Arrange
+    let mut graph = Graph::<&str, usize>::new();
+    let a = graph.add_node("A");
+    let b = graph.add_node("B");
+    let c = graph.add_node("C");
+
+    graph.add_edge(a, b, 1);
+    graph.add_edge(b, a, 1);
+
+    // Act
+    let result = greedy_best_first_search_graph(&graph, a, c, |_current, _goal| 0).unwrap();
+
+    // Assert
+    assert!(!result.is_found());
+    assert_eq!(result.visited(), [a, b]);
+}
+
+#[test]
+fn test_greedy_best_first_search_graph_accepts_graph_map_and_float_costs() {
+    // Arrange
+    let mut graph = UnGraphMap::<u32, f64>::new();
+    graph.add_edge(1, 2, 0.5);
+    graph.add_edge(2, 3, 0.25);
+    graph.add_edge(1, 3, 2.0);
+
+    // Act
+    let result =
+        greedy_best_first_search_graph(&graph, 1, 3, |node, goal| f64::from(node.abs_diff(goal)))
+            .unwrap();
+
+    // Assert: node 3 looks closest, so greedy takes the expensive direct edge.
+    assert_eq!(result.path(), Some(&[1, 3][..]));
+    assert_eq!(result.cost(), Some(&2.0));
+}
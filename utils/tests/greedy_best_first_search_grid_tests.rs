@@ -0,0 +1,113 @@
+mod pathfinding;
+use pathfinding::grid_generator::generate_test_grid;
+use utils::pathfinding::greedy_best_first_search_grid::{
+    greedy_best_first_search_grid, greedy_best_first_search_grid_with,
+};
+use utils::pathfinding::grid_movement::{DistanceHeuristic, Movement};
+
+fn manhattan(a: (usize, usize), b: (usize, usize)) -> usize {
+    a.0.abs_diff(b.0) + a.1.abs_diff(b.1)
+}
+
+#[test]
+fn test_greedy_best_first_search_grid_no_obstacles() {
+    // Arrange
+    let grid = generate_test_grid((5, 5), vec![]);
+    let start = (0, 0);
+    let goal = (4, 4);
+
+    // Act
+    let result = greedy_best_first_search_grid(&grid, start, goal, manhattan).unwrap();
+
+    // Assert: On an open grid the heuristic leads straight to the goal.
+    let path = result.path().unwrap();
+    assert_eq!(path.len(), 9);
+    assert_eq!(path.first(), Some(&start));
+    assert_eq!(path.last(), Some(&goal));
+    assert_eq!(result.visited(), path);
+}
+
+#[test]
+fn test_greedy_best_first_search_grid_with_cycles() {
+    // Arrange
+    let grid = generate_test_grid(
+        (5, 5),
+        vec![(1, 1), (1, 2), (1, 3), (2, 1), (3, 1), (3, 2), (3, 3)],
+    );
+    let start = (0, 0);
+    let goal = (4, 4);
+
+    // Act
+    let result = greedy_best_first_search_grid(&grid, start, goal, manhattan).unwrap();
+
+    // Assert
+    let path = result.path().unwrap();
+    assert_eq!(path.first(), Some(&start));
+    assert_eq!(path.last(), Some(&goal));
+    assert!(path.iter().all(|&(row, col)| !grid[row][col]));
+}
+
+#[test]
+fn test_greedy_best_first_search_grid_is_not_optimal() {
+    // Arrange: A ledge in front of the goal lures greedy search down a longer route.
+    let grid = generate_test_grid((5, 7), vec![(1, 1), (2, 1), (2, 2), (3, 5), (3, 6), (4, 1)]);
+    let start = (0, 0);
+    let goal = (4, 6);
+
+    // Act
+    let result = greedy_best_first_search_grid(&grid, start, goal, manhattan).unwrap();
+
+    // Assert: The optimal route is 10 steps; greedy settles for 14.
+    let path = result.path().unwrap();
+    assert_eq!(path.first(), Some(&start));
+    assert_eq!(path.last(), Some(&goal));
+    assert_eq!(result.cost(), Some(&14));
+    assert_eq!(result.visited().len(), 15);
+}
+
+#[test]
+fn test_greedy_best_first_search_grid_no_solution() {
+    // Arrange
+    let grid = generate_test_grid((5, 5), vec![(0, 2), (1, 2), (2, 2), (3, 2), (4, 2)]);
+
+    // Act
+    let result = greedy_best_first_search_grid(&grid, (0, 0), (4, 4), manhattan).unwrap();
+
+    // Assert: Every reachable cell on the left is expanded before giving up.
+    assert!(!result.is_found());
+    assert_eq!(result.visited().len(), 10);
+}
+
+#[test]
+fn test_greedy_best_first_search_grid_with_eight_way_moves() {
+    // Arrange
+    let grid = generate_test_grid((5, 5), vec![]);
+
+    // Act
+    let result = greedy_best_first_search_grid_with(
+        &grid,
+        (0, 0),
+        (4, 4),
+        Movement::eight_way(),
+        DistanceHeuristic::Octile,
+    )
+    .unwrap();
+
+    // Assert: straight down the diagonal.
+    assert_eq!(result.path().map(<[_]>::len), Some(5));
+    assert_eq!(result.cost(), Some(&(4 * Movement::DIAGONAL_COST)));
+}
+
+#[test]
+fn test_greedy_best_first_search_grid_ragged_rows() {
+    // Arrange: the middle row holds a single cell, so the path has to go round.
+    let mut grid = generate_test_grid((3, 3), vec![]);
+    grid[1].truncate(1);
+
+    // Act
+    let result =
+        greedy_best_first_search_grid(&grid, (0, 2), (2, 2), DistanceHeuristic::Manhattan).unwrap();
+
+    // Assert
+    assert_eq!(result.cost(), Some(&6));
+}
@@ -0,0 +1,20 @@
+mod search;
+use search::problem_generator::{SlidingPuzzle, SOLVED_PUZZLE};
+use utils::pathfinding::a_star::a_star;
+use utils::pathfinding::greedy_best_first_search::greedy_best_first_search;
+
+#[test]
+fn test_greedy_best_first_search_sliding_puzzle() {
+    // Arrange
+    let puzzle = SlidingPuzzle::scrambled("ULDLURDRULDLUR");
+
+    // Act
+    let result = greedy_best_first_search(&puzzle);
+
+    // Assert: a valid solution, never cheaper than the optimal one.
+    let path = result.path().expect("Greedy should solve the puzzle.");
+    assert_eq!(path.first(), Some(&puzzle.tiles));
+    assert_eq!(path.last(), Some(&SOLVED_PUZZLE));
+    assert_eq!(result.cost(), Some(&(path.len() - 1)));
+    assert!(result.cost() >= a_star(&puzzle).cost());
+}
@@ -0,0 +1,120 @@
+mod pathfinding;
+use pathfinding::grid_generator::generate_test_grid;
+use utils::pathfinding::grid_movement::{DistanceHeuristic, GridHeuristic, Movement};
+
+fn passable(wall: &bool) -> Option<usize> {
+    (!wall).then_some(1)
+}
+
+#[test]
+fn test_four_way_moves_order() {
+    let grid = generate_test_grid((3, 3), vec![]);
+
+    let moves = Movement::four_way().moves(&grid, (1, 1), passable);
+
+    assert_eq!(
+        moves,
+        vec![((0, 1), 1), ((2, 1), 1), ((1, 0), 1), ((1, 2), 1)]
+    );
+}
+
+#[test]
+fn test_eight_way_moves_include_diagonals() {
+    let grid = generate_test_grid((3, 3), vec![]);
+
+    let moves = Movement::eight_way().moves(&grid, (1, 1), passable);
+
+    assert_eq!(moves.len(), 8);
+    assert_eq!(moves[4], ((0, 0), Movement::DIAGONAL_COST));
+    assert_eq!(moves[7], ((2, 2), Movement::DIAGONAL_COST));
+}
+
+#[test]
+fn test_moves_stay_in_bounds() {
+    let grid = generate_test_grid((3, 3), vec![]);
+
+    let moves = Movement::eight_way().moves(&grid, (0, 0), passable);
+
+    assert_eq!(
+        moves,
+        vec![
+            ((1, 0), Movement::STRAIGHT_COST),
+            ((0, 1), Movement::STRAIGHT_COST),
+            ((1, 1), Movement::DIAGONAL_COST),
+        ]
+    );
+}
+
+#[test]
+fn test_moves_scale_cell_cost() {
+    let grid = vec![vec![1, 5], vec![2, 3]];
+
+    let moves = Movement::eight_way().moves(&grid, (0, 0), |&cost: &usize| Some(cost));
+
+    assert_eq!(
+        moves,
+        vec![
+            ((1, 0), 2 * Movement::STRAIGHT_COST),
+            ((0, 1), 5 * Movement::STRAIGHT_COST),
+            ((1, 1), 3 * Movement::DIAGONAL_COST),
+        ]
+    );
+}
+
+#[test]
+fn test_distance_heuristics() {
+    // Arrange
+    let four_way = Movement::four_way();
+    let eight_way = Movement::eight_way();
+    let (from, to) = ((0, 0), (3, 4));
+
+    // Act & Assert
+    assert_eq!(
+        DistanceHeuristic::Manhattan.estimate(from, to, &four_way),
+        7
+    );
+    assert_eq!(
+        DistanceHeuristic::Euclidean.estimate(from, to, &four_way),
+        5
+    );
+    assert_eq!(
+        DistanceHeuristic::Chebyshev.estimate(from, to, &four_way),
+        4
+    );
+    assert_eq!(
+        DistanceHeuristic::Octile.estimate(from, to, &eight_way),
+        Movement::STRAIGHT_COST + 3 * Movement::DIAGONAL_COST
+    );
+    // A diagonal step costs just under `sqrt(2)` straight steps.
+    assert_eq!(
+        DistanceHeuristic::Euclidean.estimate(from, to, &eight_way),
+        5 * Movement::STRAIGHT_COST - 1
+    );
+}
+
+#[test]
+fn test_distance_heuristics_respect_cheap_diagonals() {
+    // Arrange: diagonals as cheap as straight steps, or cheaper.
+    let level = Movement::eight_way().with_diagonal_cost(Movement::STRAIGHT_COST);
+    let cheap = Movement::eight_way().with_diagonal_cost(500);
+    let (from, to) = ((0, 0), (1, 1));
+
+    // Act & Assert: neither exceeds the single diagonal step it takes.
+    assert!(DistanceHeuristic::Euclidean.estimate(from, to, &level) <= Movement::STRAIGHT_COST);
+    assert!(DistanceHeuristic::Euclidean.estimate(from, to, &cheap) <= 500);
+    assert_eq!(DistanceHeuristic::Chebyshev.estimate(from, to, &cheap), 500);
+    assert_eq!(
+        DistanceHeuristic::Chebyshev.estimate(from, to, &level),
+        DistanceHeuristic::Octile.estimate(from, to, &level)
+    );
+}
+
+#[test]
+fn test_closure_heuristic_ignores_movement() {
+    let heuristic = |from: (usize, usize), to: (usize, usize)| from.0.abs_diff(to.0) * 10;
+
+    assert_eq!(
+        heuristic.estimate((0, 0), (2, 5), &Movement::eight_way()),
+        20
+    );
+}
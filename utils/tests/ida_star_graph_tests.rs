@@ -0,0 +1,53 @@
+mod pathfinding;
+use pathfinding::graph_generator::generate_random_weighted_graph;
+use petgraph::graph::{Graph, NodeIndex};
+use utils::pathfinding::a_star_graph::astar_graph;
+use utils::pathfinding::ida_star_graph::ida_star_graph;
+use utils::search::outcome::SearchError;
+
+#[test]
+fn test_ida_star_graph_matches_astar_graph() {
+    for seed in 0..10 {
+        // Arrange
+        let (graph, nodes) = generate_random_weighted_graph(12, 30, 9, seed);
+        let (start, goal) = (nodes[0], nodes[11]);
+
+        // Act
+        let ida = ida_star_graph(&graph, start, goal, |_, _| 0).unwrap();
+
+        // Assert
+        let astar = astar_graph(&graph, start, goal, |_, _| 0).unwrap();
+        assert_eq!(ida.cost(), astar.cost(), "seed {seed}");
+    }
+}
+
+#[test]
+fn test_ida_star_graph_float_weights_and_heuristic() {
+    // Arrange: points on a line, with a detour that looks short but costs more.
+    let mut graph = Graph::<f64, f64>::new();
+    let a = graph.add_node(0.0);
+    let b = graph.add_node(1.0);
+    let c = graph.add_node(2.0);
+    let d = graph.add_node(3.0);
+    graph.add_edge(a, b, 1.0);
+    graph.add_edge(b, d, 2.5);
+    graph.add_edge(a, c, 2.0);
+    graph.add_edge(c, d, 1.0);
+    let distance = |from: NodeIndex, to: NodeIndex| (graph[to] - graph[from]).abs();
+
+    // Act
+    let result = ida_star_graph(&graph, a, d, distance).unwrap();
+
+    // Assert
+    assert_eq!(result.path(), Some(&[a, c, d][..]));
+    assert_eq!(result.cost(), Some(&3.0));
+}
+
+#[test]
+fn test_ida_star_graph_rejects_invalid_start() {
+    let graph = Graph::<(), usize>::new();
+
+    let result = ida_star_graph(&graph, NodeIndex::new(0), NodeIndex::new(0), |_, _| 0);
+
+    assert_eq!(result, Err(SearchError::InvalidNode));
+}
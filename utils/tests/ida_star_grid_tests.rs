@@ -0,0 +1,85 @@
+mod pathfinding;
+use pathfinding::grid_generator::{generate_random_test_grid, generate_test_grid};
+use utils::pathfinding::a_star_grid::{a_star_grid, a_star_grid_with};
+use utils::pathfinding::grid_movement::{DistanceHeuristic, Movement};
+use utils::pathfinding::ida_star_grid::{ida_star_grid, ida_star_grid_with};
+use utils::search::outcome::SearchError;
+
+#[test]
+fn test_ida_star_grid_matches_a_star_grid() {
+    for seed in 0..10 {
+        // Arrange
+        let grid = generate_random_test_grid((6, 6), 25, seed);
+        let (start, goal) = ((0, 0), (5, 5));
+
+        // Act
+        let ida = ida_star_grid(&grid, start, goal).unwrap();
+
+        // Assert
+        let a_star = a_star_grid(&grid, start, goal).unwrap();
+        assert_eq!(ida.cost(), a_star.cost(), "seed {seed}");
+    }
+}
+
+#[test]
+fn test_ida_star_grid_with_eight_way_movement() {
+    // Arrange
+    let grid = generate_test_grid((6, 6), vec![(1, 1), (2, 2), (3, 3), (2, 4)]);
+    let (start, goal) = ((0, 5), (5, 0));
+    let movement = Movement::eight_way();
+
+    // Act
+    let ida = ida_star_grid_with(&grid, start, goal, movement, DistanceHeuristic::Octile).unwrap();
+
+    // Assert
+    let a_star = a_star_grid_with(&grid, start, goal, movement, DistanceHeuristic::Octile).unwrap();
+    assert_eq!(ida.cost(), a_star.cost());
+    assert_eq!(ida.path().unwrap().first(), Some(&start));
+    assert_eq!(ida.path().unwrap().last(), Some(&goal));
+}
+
+#[test]
+fn test_ida_star_grid_rejects_invalid_start() {
+    let grid = generate_test_grid((3, 3), vec![]);
+
+    assert_eq!(
+        ida_star_grid(&grid, (0, 3), (0, 0)),
+        Err(SearchError::StartOutOfBounds { row: 0, col: 3 })
+    );
+}
+
+#[test]
+fn test_ida_star_grid_unreachable_goal() {
+    // Arrange: the goal sits on a wall in one grid and is walled in by the other.
+    let walled = generate_test_grid((7, 7), vec![(0, 6), (1, 6), (2, 6), (3, 1), (3, 2), (3, 3)]);
+    let enclosed = generate_test_grid((3, 4), vec![(0, 2), (1, 3)]);
+
+    // Act
+    let on_wall = ida_star_grid(&walled, (5, 2), (0, 6)).unwrap();
+    let outside = ida_star_grid(&walled, (5, 2), (7, 0)).unwrap();
+    let walled_in = ida_star_grid(&enclosed, (2, 0), (0, 3)).unwrap();
+
+    // Assert: a goal that can never be entered is given up on without a pass.
+    assert!(!on_wall.is_found());
+    assert!(on_wall.visited().is_empty());
+    assert!(outside.visited().is_empty());
+    assert!(!walled_in.is_found());
+    assert!(!walled_in.visited().contains(&(0, 3)));
+}
+
+#[test]
+fn test_ida_star_grid_ragged_rows() {
+    // Arrange: the middle row holds a single cell, so the path has to go round.
+    let mut grid = generate_test_grid((3, 3), vec![]);
+    grid[1].truncate(1);
+
+    // Act
+    let result = ida_star_grid(&grid, (0, 2), (2, 2)).unwrap();
+
+    // Assert
+    assert_eq!(result.cost(), Some(&6));
+    assert_eq!(
+        result.cost(),
+        a_star_grid(&grid, (0, 2), (2, 2)).unwrap().cost()
+    );
+}
@@ -0,0 +1,92 @@
+mod search;
+use search::problem_generator::{KeyDoorMaze, SlidingPuzzle, SOLVED_PUZZLE};
+use utils::pathfinding::a_star::a_star;
+use utils::pathfinding::ida_star::{ida_star, ida_star_observed};
+use utils::search::budget::SearchBudget;
+use utils::search::observer::SearchTrace;
+
+#[test]
+fn test_ida_star_sliding_puzzle() {
+    // Arrange
+    let puzzle = SlidingPuzzle::scrambled("ULDLURDRULDLURDLURRDLU");
+
+    // Act
+    let result = ida_star(&puzzle);
+
+    // Assert
+    let path = result.path().expect("IDA* should solve the puzzle.");
+    assert_eq!(path.first(), Some(&puzzle.tiles));
+    assert_eq!(path.last(), Some(&SOLVED_PUZZLE));
+    assert_eq!(result.cost(), Some(&(path.len() - 1)));
+    assert_eq!(result.cost(), a_star(&puzzle).cost());
+}
+
+#[test]
+fn test_ida_star_matches_a_star() {
+    for scramble in ["", "U", "ULDR", "ULDLUR", "LLUURRDD", "ULURDDLLUR"] {
+        // Arrange
+        let puzzle = SlidingPuzzle::scrambled(scramble);
+
+        // Act
+        let ida = ida_star(&puzzle);
+
+        // Assert
+        assert_eq!(ida.cost(), a_star(&puzzle).cost(), "scramble {scramble:?}");
+    }
+}
+
+#[test]
+fn test_ida_star_key_and_door() {
+    let maze = KeyDoorMaze::parse(&["S.#E", "..D.", "K###"]);
+
+    let result = ida_star(&maze);
+
+    assert_eq!(result.cost(), Some(&7));
+    assert_eq!(result.goal(), Some(&((0, 3), true)));
+}
+
+#[test]
+fn test_ida_star_no_path() {
+    let maze = KeyDoorMaze::parse(&["S.#E", "..D.", ".###"]);
+
+    let result = ida_star(&maze);
+
+    assert!(!result.is_found());
+    assert!(result.visited().len() >= 5);
+}
+
+#[test]
+fn test_ida_star_raises_threshold_between_passes() {
+    // Arrange: the heuristic underestimates, so the first pass falls short.
+    let puzzle = SlidingPuzzle::scrambled("ULDLURDRULDLURDLURRDLU");
+
+    let mut trace = SearchTrace::new();
+
+    // Act
+    let result = ida_star_observed(&puzzle, &mut trace);
+
+    // Assert: every pass starts by expanding the initial state again, while
+    // `visited` only keeps the last pass.
+    let passes = trace
+        .expanded()
+        .into_iter()
+        .filter(|&&tiles| tiles == puzzle.tiles)
+        .count();
+    assert!(result.is_found());
+    assert!(passes > 1);
+    assert_eq!(result.visited().first(), Some(&puzzle.tiles));
+    assert!(result.visited().len() < trace.expanded().len());
+}
+
+#[test]
+fn test_ida_star_budget_keeps_best_path() {
+    let puzzle = SlidingPuzzle::scrambled("ULDLURDRULDLURDLURRDLU");
+    let mut budget = SearchBudget::unlimited().with_max_expansions(50);
+
+    let result = ida_star_observed(&puzzle, &mut budget);
+
+    assert!(result.is_budget_exhausted());
+    assert!(result.visited().len() <= 50);
+    assert_eq!(result.visited().first(), Some(&puzzle.tiles));
+    assert_eq!(result.best_path().unwrap().first(), Some(&puzzle.tiles));
+}
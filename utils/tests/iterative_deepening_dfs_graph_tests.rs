@@ -1,68 +1,197 @@
+mod pathfinding;
 mod search;
+use pathfinding::graph_generator::generate_random_weighted_graph;
+use petgraph::graph::Graph;
+use petgraph::graphmap::DiGraphMap;
+use petgraph::visit::EdgeFiltered;
+use petgraph::Undirected;
 use search::graph_generator::{
-    generate_balanced_graph, generate_balanced_graph_with_cycles, generate_unbalanced_graph,
+    generate_balanced_graph, generate_balanced_graph_with_cycles, generate_unbalanced_graph, labels,
 };
-use utils::search::iterative_deepening_dfs_graph::iterative_deepening_dfs_graph;
+use utils::search::bfs_graph::bfs_graph;
+use utils::search::iterative_deepening_dfs_graph::{
+    iterative_deepening_dfs_graph, iterative_deepening_dfs_graph_observed,
+    iterative_deepening_dfs_graph_where, iterative_deepening_dfs_graph_with_transpositions,
+    iterative_deepening_dfs_graph_with_transpositions_observed,
+    iterative_deepening_dfs_graph_with_transpositions_where,
+};
+use utils::search::observer::SearchTrace;
+use utils::search::outcome::SearchError;
 
 #[test]
 fn test_iterative_deepening_dfs_graph_target_found() {
     let (graph, start) = generate_balanced_graph(3);
-    let result = iterative_deepening_dfs_graph(&graph, start, Some(&'C'), 3);
-    assert_eq!(result, Err(Some('C')));
+    let result = iterative_deepening_dfs_graph(&graph, start, Some(&'C'), 3).unwrap();
+    assert_eq!(result.goal().map(|&node| graph[node]), Some('C'));
 }
 
 #[test]
 fn test_iterative_deepening_dfs_graph_target_not_found() {
     let (graph, start) = generate_balanced_graph(3);
-    let result = iterative_deepening_dfs_graph(&graph, start, Some(&'D'), 3);
-    assert_eq!(result, Err(None));
+    let result = iterative_deepening_dfs_graph(&graph, start, Some(&'D'), 3).unwrap();
+    assert!(!result.is_found());
 }
 
 #[test]
 fn test_iterative_deepening_dfs_graph_return_all_nodes() {
     let (graph, start) = generate_balanced_graph(3);
-    let result = iterative_deepening_dfs_graph(&graph, start, None, 3);
-    assert_eq!(result, Ok(vec!['A', 'A', 'C', 'B', 'A', 'C', 'B']));
+    let result = iterative_deepening_dfs_graph(&graph, start, None, 3).unwrap();
+    assert_eq!(labels(&graph, result.visited()), vec!['A', 'C', 'B']);
 }
 
 #[test]
 fn test_iterative_deepening_dfs_graph_unbalanced_with_target_found() {
     let (graph, start) = generate_unbalanced_graph(3);
-    let result = iterative_deepening_dfs_graph(&graph, start, Some(&'C'), 3);
-    assert_eq!(result, Err(Some('C')));
+    let result = iterative_deepening_dfs_graph(&graph, start, Some(&'C'), 3).unwrap();
+    assert_eq!(result.goal().map(|&node| graph[node]), Some('C'));
 }
 
 #[test]
 fn test_iterative_deepening_dfs_graph_unbalanced_with_target_not_found() {
     let (graph, start) = generate_unbalanced_graph(3);
-    let result = iterative_deepening_dfs_graph(&graph, start, Some(&'D'), 3);
-    assert_eq!(result, Err(None));
+    let result = iterative_deepening_dfs_graph(&graph, start, Some(&'D'), 3).unwrap();
+    assert!(!result.is_found());
 }
 
 #[test]
 fn test_iterative_deepening_dfs_graph_unbalanced_return_all_nodes() {
     let (graph, start) = generate_unbalanced_graph(3);
-    let result = iterative_deepening_dfs_graph(&graph, start, None, 3);
-    assert_eq!(result, Ok(vec!['A', 'A', 'B', 'A', 'B', 'C']));
+    let result = iterative_deepening_dfs_graph(&graph, start, None, 3).unwrap();
+    assert_eq!(labels(&graph, result.visited()), vec!['A', 'B', 'C']);
 }
 
 #[test]
 fn test_iterative_deepening_dfs_graph_balanced_with_cycles_with_target_found() {
     let (graph, start) = generate_balanced_graph_with_cycles(3);
-    let result = iterative_deepening_dfs_graph(&graph, start, Some(&'C'), 3);
-    assert_eq!(result, Err(Some('C')));
+    let result = iterative_deepening_dfs_graph(&graph, start, Some(&'C'), 3).unwrap();
+    assert_eq!(result.goal().map(|&node| graph[node]), Some('C'));
 }
 
 #[test]
 fn test_iterative_deepening_dfs_graph_balanced_with_cycles_with_target_not_found() {
     let (graph, start) = generate_balanced_graph_with_cycles(3);
-    let result = iterative_deepening_dfs_graph(&graph, start, Some(&'D'), 3);
-    assert_eq!(result, Err(None));
+    let result = iterative_deepening_dfs_graph(&graph, start, Some(&'D'), 3).unwrap();
+    assert!(!result.is_found());
 }
 
 #[test]
 fn test_iterative_deepening_dfs_graph_balanced_with_cycles_return_all_nodes() {
     let (graph, start) = generate_balanced_graph_with_cycles(3);
-    let result = iterative_deepening_dfs_graph(&graph, start, None, 3);
-    assert_eq!(result, Ok(vec!['A', 'A', 'C', 'B', 'A', 'C', 'A', 'B', 'A']));
+    let result = iterative_deepening_dfs_graph(&graph, start, None, 3).unwrap();
+    assert_eq!(labels(&graph, result.visited()), vec!['A', 'C', 'B']);
+}
+
+#[test]
+fn test_iterative_deepening_dfs_graph_undirected() {
+    // Arrange: 'B' and 'C' are siblings, two edges apart through the root.
+    let (graph, _) = generate_balanced_graph(3);
+    let undirected = graph.into_edge_type::<Undirected>();
+    let c = undirected
+        .node_indices()
+        .find(|&node| undirected[node] == 'C')
+        .unwrap();
+
+    // Act
+    let too_shallow = iterative_deepening_dfs_graph(&undirected, c, Some(&'B'), 2).unwrap();
+    let deep_enough = iterative_deepening_dfs_graph(&undirected, c, Some(&'B'), 3).unwrap();
+
+    // Assert
+    assert!(!too_shallow.is_found());
+    assert_eq!(deep_enough.goal().map(|&node| undirected[node]), Some('B'));
+}
+
+#[test]
+fn test_iterative_deepening_dfs_graph_reports_goal_depth() {
+    let (graph, start) = generate_balanced_graph_with_cycles(7);
+    let result = iterative_deepening_dfs_graph(&graph, start, Some(&'G'), 5).unwrap();
+    assert_eq!(labels(&graph, result.path().unwrap()), vec!['A', 'C', 'G']);
+    assert_eq!(result.depth(), Some(2));
+}
+
+#[test]
+fn test_iterative_deepening_dfs_graph_finds_shallowest_target_like_bfs() {
+    for seed in 0..30 {
+        // Arrange: dense random graphs full of cycles and parallel edges.
+        let (graph, nodes) = generate_random_weighted_graph(12, 40, 1, seed);
+        let target = 11;
+
+        // Act
+        let deepening = iterative_deepening_dfs_graph(&graph, nodes[0], Some(&target), 12).unwrap();
+        let transposed =
+            iterative_deepening_dfs_graph_with_transpositions(&graph, nodes[0], Some(&target), 12)
+                .unwrap();
+
+        // Assert
+        let breadth_first = bfs_graph(&graph, nodes[0], Some(&target)).unwrap();
+        assert_eq!(deepening.depth(), breadth_first.depth(), "seed {seed}");
+        assert_eq!(transposed.depth(), breadth_first.depth(), "seed {seed}");
+    }
+}
+
+#[test]
+fn test_iterative_deepening_dfs_graph_transpositions_expand_less() {
+    // Arrange
+    let (graph, nodes) = generate_random_weighted_graph(15, 60, 1, 7);
+
+    let mut plain = SearchTrace::new();
+    let mut transposed = SearchTrace::new();
+
+    // Act
+    iterative_deepening_dfs_graph_observed(&graph, nodes[0], |_| false, 6, &mut plain).unwrap();
+    iterative_deepening_dfs_graph_with_transpositions_observed(
+        &graph,
+        nodes[0],
+        |_| false,
+        6,
+        &mut transposed,
+    )
+    .unwrap();
+
+    // Assert: the traces hold every expansion of every iteration.
+    assert!(transposed.expanded().len() * 4 < plain.expanded().len());
+}
+
+#[test]
+fn test_iterative_deepening_dfs_graph_graph_map() {
+    // Arrange: a GraphMap whose nodes are their own names.
+    let graph = DiGraphMap::<&str, usize>::from_edges([
+        ("A", "B", 1),
+        ("B", "C", 1),
+        ("C", "D", 1),
+        ("A", "C", 1),
+    ]);
+
+    // Act
+    let plain = iterative_deepening_dfs_graph_where(&graph, "A", |node| node == "D", 5).unwrap();
+    let transposed =
+        iterative_deepening_dfs_graph_with_transpositions_where(&graph, "A", |node| node == "D", 5)
+            .unwrap();
+    let missing = iterative_deepening_dfs_graph_where(&graph, "Z", |node| node == "D", 5);
+
+    // Assert: the shallowest route skips B.
+    assert_eq!(plain.path(), Some(&["A", "C", "D"][..]));
+    assert_eq!(transposed.path(), plain.path());
+    assert_eq!(missing, Err(SearchError::InvalidNode));
+}
+
+#[test]
+fn test_iterative_deepening_dfs_graph_edge_filtered_view() {
+    // Arrange: hide the direct edge so the search has to go one level deeper.
+    let mut graph = Graph::<&str, usize>::new();
+    let a = graph.add_node("A");
+    let b = graph.add_node("B");
+    let c = graph.add_node("C");
+    graph.add_edge(a, c, 1);
+    graph.add_edge(a, b, 2);
+    graph.add_edge(b, c, 2);
+    let view = EdgeFiltered::from_fn(&graph, |edge| *edge.weight() > 1);
+
+    // Act
+    let unfiltered = iterative_deepening_dfs_graph_where(&graph, a, |node| node == c, 3).unwrap();
+    let filtered = iterative_deepening_dfs_graph_where(&view, a, |node| node == c, 3).unwrap();
+
+    // Assert
+    assert_eq!(unfiltered.depth(), Some(1));
+    assert_eq!(filtered.path(), Some(&[a, b, c][..]));
+    assert_eq!(filtered.depth(), Some(2));
 }
@@ -1,19 +1,105 @@
 mod search;
-use search::grid_generator::generate_balanced_grid;
-use utils::search::iterative_deepening_dfs_grid::iterative_deepening_dfs_grid;
+use search::grid_generator::{generate_balanced_grid, generate_maze_grid};
+use utils::search::iterative_deepening_dfs_grid::{
+    iterative_deepening_dfs_grid, iterative_deepening_dfs_grid_where,
+};
 
 #[test]
 fn test_iterative_deepening_dfs_grid_balanced_with_target_found() {
     let (graph, start) = generate_balanced_grid(3, 3);
-    let (found, position) = iterative_deepening_dfs_grid(&graph, start, &'C', 3);
-    assert_eq!(found, true);
-    assert_eq!(position, Some((0, 2)));
+    let path = iterative_deepening_dfs_grid(&graph, start, &'C', 3)
+        .unwrap()
+        .into_path();
+    assert_eq!(path, Some(vec![(0, 0), (0, 1), (0, 2)]));
 }
 
 #[test]
 fn test_iterative_deepening_dfs_grid_balanced_with_target_not_found() {
     let (graph, start) = generate_balanced_grid(3, 3);
-    let (found, position) = iterative_deepening_dfs_grid(&graph, start, &'Z', 3);
-    assert_eq!(found, false);
-    assert_eq!(position, None);
-}
\ No newline at end of file
+    let path = iterative_deepening_dfs_grid(&graph, start, &'Z', 3)
+        .unwrap()
+        .into_path();
+    assert_eq!(path, None);
+}
+
+#[test]
+fn test_iterative_deepening_dfs_grid_max_depth_limits_path() {
+    // Arrange: 'I' in the far corner needs a path of 5 cells.
+    let (grid, start) = generate_balanced_grid(3, 3);
+
+    // Act & Assert
+    assert_eq!(
+        iterative_deepening_dfs_grid(&grid, start, &'I', 4)
+            .unwrap()
+            .into_path(),
+        None
+    );
+    let path = iterative_deepening_dfs_grid(&grid, start, &'I', 5)
+        .unwrap()
+        .into_path()
+        .expect("Target within depth.");
+    assert_eq!(path.len(), 5);
+    assert_eq!(path.last(), Some(&(2, 2)));
+}
+
+#[test]
+fn test_iterative_deepening_dfs_grid_where_avoids_walls() {
+    // Arrange: Going around the wall takes 8 cells.
+    let (grid, start) = generate_maze_grid(&["S.#E", ".##.", "...."]);
+
+    // Act
+    let too_shallow =
+        iterative_deepening_dfs_grid_where(&grid, start, |&c| c != '#', |&c| c == 'E', 7)
+            .unwrap()
+            .into_path();
+    let path = iterative_deepening_dfs_grid_where(&grid, start, |&c| c != '#', |&c| c == 'E', 8)
+        .unwrap()
+        .into_path();
+
+    // Assert
+    assert_eq!(too_shallow, None);
+    assert_eq!(
+        path,
+        Some(vec![
+            (0, 0),
+            (1, 0),
+            (2, 0),
+            (2, 1),
+            (2, 2),
+            (2, 3),
+            (1, 3),
+            (0, 3)
+        ])
+    );
+}
+
+#[test]
+fn test_iterative_deepening_dfs_grid_ragged_rows() {
+    // Arrange: the middle row holds a single cell, so the path has to go round.
+    let (grid, start) = generate_maze_grid(&["..S", ".", "..E"]);
+
+    // Act
+    let result = iterative_deepening_dfs_grid(&grid, start, &'E', 10).unwrap();
+
+    // Assert
+    assert_eq!(result.goal(), Some(&(2, 2)));
+    assert_eq!(result.cost(), Some(&6));
+}
+
+#[test]
+fn test_iterative_deepening_dfs_grid_open_grid() {
+    // Arrange: an open 12x12 grid has far too many paths to walk one by one.
+    let mut rows = vec!["............"; 12];
+    rows[0] = "S...........";
+    rows[11] = "...........E";
+    let (grid, start) = generate_maze_grid(&rows);
+
+    // Act
+    let found = iterative_deepening_dfs_grid(&grid, start, &'E', 23).unwrap();
+    let missing = iterative_deepening_dfs_grid(&grid, start, &'Z', 30).unwrap();
+
+    // Assert: the far corner is 22 steps away, and every cell is reached.
+    assert_eq!(found.depth(), Some(22));
+    assert!(!missing.is_found());
+    assert_eq!(missing.visited().len(), 144);
+}
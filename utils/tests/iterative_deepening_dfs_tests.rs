@@ -0,0 +1,116 @@
+mod search;
+use search::problem_generator::{KeyDoorMaze, SlidingPuzzle, SOLVED_PUZZLE};
+use std::collections::HashSet;
+use utils::search::iterative_deepening_dfs::{
+    iterative_deepening_dfs, iterative_deepening_dfs_observed,
+    iterative_deepening_dfs_with_transpositions_observed,
+};
+use utils::search::observer::SearchTrace;
+
+#[test]
+fn test_iterative_deepening_dfs_sliding_puzzle() {
+    // Arrange
+    let puzzle = SlidingPuzzle::scrambled("ULDR");
+
+    // Act
+    let result = iterative_deepening_dfs(&puzzle, 10);
+
+    // Assert: The shallowest solution is found first.
+    let path = result.path().expect("IDDFS should solve the puzzle.");
+    assert_eq!(path.first(), Some(&puzzle.tiles));
+    assert_eq!(path.last(), Some(&SOLVED_PUZZLE));
+    assert_eq!(path.len() - 1, 4);
+}
+
+#[test]
+fn test_iterative_deepening_dfs_depth_limit() {
+    // Arrange: The exit is 7 steps away, so a path holds 8 states.
+    let maze = KeyDoorMaze::parse(&["S.#E", "..D.", "K###"]);
+
+    // Act & Assert
+    assert!(!iterative_deepening_dfs(&maze, 7).is_found());
+    assert_eq!(iterative_deepening_dfs(&maze, 8).cost(), Some(&7));
+}
+
+#[test]
+fn test_iterative_deepening_dfs_visits_each_depth() {
+    // Arrange
+    let maze = KeyDoorMaze::parse(&["S.E"]);
+    let mut trace = SearchTrace::new();
+
+    // Act
+    let result = iterative_deepening_dfs_observed(&maze, 3, &mut trace);
+
+    // Assert: Depth 1 visits the start, depth 2 its neighbor, depth 3 reaches
+    // the exit, and `visited` keeps only that last iteration.
+    assert_eq!(
+        trace.expanded(),
+        [
+            &((0, 0), false),
+            &((0, 0), false),
+            &((0, 1), false),
+            &((0, 0), false),
+            &((0, 1), false),
+            &((0, 2), false),
+        ]
+    );
+    assert_eq!(
+        result.visited(),
+        [((0, 0), false), ((0, 1), false), ((0, 2), false)]
+    );
+}
+
+#[test]
+fn test_iterative_deepening_dfs_visited_has_no_repeats() {
+    // Arrange: many paths lead to each state of a scrambled puzzle.
+    let puzzle = SlidingPuzzle::scrambled("ULDLURDR");
+    let mut trace = SearchTrace::new();
+
+    // Act
+    let result = iterative_deepening_dfs_observed(&puzzle, 12, &mut trace);
+
+    // Assert
+    let unique: HashSet<_> = result.visited().iter().collect();
+    assert!(result.is_found());
+    assert_eq!(unique.len(), result.visited().len());
+    assert!(result.visited().len() < trace.expanded().len());
+}
+
+#[test]
+fn test_iterative_deepening_dfs_stops_once_nothing_is_cut_off() {
+    // Arrange: the door is locked and there is no key, so only 5 cells can be
+    // walked.
+    let maze = KeyDoorMaze::parse(&["S.#E", "..D.", ".###"]);
+    let mut trace = SearchTrace::new();
+
+    // Act
+    let result = iterative_deepening_dfs_observed(&maze, 1_000_000, &mut trace);
+
+    // Assert: depth 5 walks every path without reaching the limit, so it is
+    // the last iteration to expand the start.
+    let iterations = trace
+        .expanded()
+        .into_iter()
+        .filter(|&&state| state == ((0, 0), false))
+        .count();
+    assert!(!result.is_found());
+    assert_eq!(iterations, 5);
+    assert_eq!(result.visited().len(), 5);
+}
+
+#[test]
+fn test_iterative_deepening_dfs_transpositions_find_same_depth() {
+    // Arrange
+    let puzzle = SlidingPuzzle::scrambled("ULDLURDR");
+    let mut plain_trace = SearchTrace::new();
+    let mut transposed_trace = SearchTrace::new();
+
+    // Act
+    let plain = iterative_deepening_dfs_observed(&puzzle, 12, &mut plain_trace);
+    let transposed =
+        iterative_deepening_dfs_with_transpositions_observed(&puzzle, 12, &mut transposed_trace);
+
+    // Assert
+    assert_eq!(transposed.depth(), plain.depth());
+    assert!(transposed_trace.expanded().len() < plain_trace.expanded().len());
+}
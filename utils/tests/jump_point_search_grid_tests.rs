@@ -0,0 +1,203 @@
+mod pathfinding;
+use pathfinding::grid_generator::{generate_random_test_grid, generate_test_grid, next_random};
+use utils::pathfinding::a_star_grid::a_star_grid_with;
+use utils::pathfinding::grid_movement::{DistanceHeuristic, Movement};
+use utils::pathfinding::jump_point_search_grid::{
+    expand_jump_points, jump_point_search_grid, jump_point_search_grid_observed, JumpPointTable,
+};
+use utils::search::budget::SearchBudget;
+use utils::search::outcome::SearchError;
+
+/// Sums the cost of a cell-by-cell path under `Movement::eight_way()`, checking
+/// that every step is legal on the way.
+fn eight_way_cost(grid: &[Vec<bool>], path: &[(usize, usize)]) -> usize {
+    let movement = Movement::eight_way();
+    path.windows(2)
+        .map(|pair| {
+            let moves = movement.moves(grid, pair[0], |&wall| (!wall).then_some(1));
+            let (_, cost) = moves
+                .into_iter()
+                .find(|&(next, _)| next == pair[1])
+                .expect("Each step should be a legal eight-way move.");
+            cost
+        })
+        .sum()
+}
+
+#[test]
+fn test_jump_point_search_matches_eight_way_a_star() {
+    for seed in 0..200 {
+        // Arrange: endpoints anywhere, walls included, and sometimes the same cell.
+        let grid = generate_random_test_grid((20, 15), 25, seed);
+        let mut random = seed;
+        let mut cell = || {
+            (
+                (next_random(&mut random) % 20) as usize,
+                (next_random(&mut random) % 15) as usize,
+            )
+        };
+        let start = cell();
+        let goal = if seed % 5 == 0 { start } else { cell() };
+        let table = JumpPointTable::new(&grid);
+
+        // Act
+        let a_star = a_star_grid_with(
+            &grid,
+            start,
+            goal,
+            Movement::eight_way(),
+            DistanceHeuristic::Octile,
+        )
+        .unwrap();
+        let jps = jump_point_search_grid(&grid, start, goal).unwrap();
+        let jps_plus = table.search(start, goal).unwrap();
+
+        // Assert
+        for outcome in [&jps, &jps_plus] {
+            assert_eq!(
+                outcome.cost(),
+                a_star.cost(),
+                "seed {seed}: {start:?} to {goal:?}"
+            );
+            if let Some(jump_points) = outcome.path() {
+                let path = expand_jump_points(jump_points);
+                assert_eq!(path.first(), Some(&start));
+                assert_eq!(path.last(), Some(&goal));
+                assert_eq!(Some(&eight_way_cost(&grid, &path)), a_star.cost());
+            }
+        }
+    }
+}
+
+#[test]
+fn test_jump_point_search_expands_fewer_nodes_on_open_map() {
+    // Arrange
+    let grid = generate_test_grid((40, 40), vec![(10, 10), (20, 25), (30, 5)]);
+    let (start, goal) = ((0, 3), (39, 30));
+
+    // Act
+    let a_star = a_star_grid_with(
+        &grid,
+        start,
+        goal,
+        Movement::eight_way(),
+        DistanceHeuristic::Octile,
+    )
+    .unwrap();
+    let jps = jump_point_search_grid(&grid, start, goal).unwrap();
+
+    // Assert
+    assert_eq!(jps.cost(), a_star.cost());
+    assert!(
+        jps.visited().len() * 4 < a_star.visited().len(),
+        "JPS expanded {} jump points, A* {} cells.",
+        jps.visited().len(),
+        a_star.visited().len()
+    );
+}
+
+#[test]
+fn test_jump_point_search_unreachable_goal() {
+    let grid = generate_test_grid((5, 5), vec![(0, 3), (1, 3), (2, 3), (3, 3), (4, 3)]);
+
+    let jps = jump_point_search_grid(&grid, (2, 0), (2, 4)).unwrap();
+    let jps_plus = JumpPointTable::new(&grid).search((2, 0), (2, 4)).unwrap();
+
+    assert!(!jps.is_found());
+    assert!(!jps_plus.is_found());
+}
+
+#[test]
+fn test_jump_point_search_does_not_cut_corners() {
+    // Arrange: the only gap is a diagonal squeeze between two walls.
+    let grid = generate_test_grid((2, 2), vec![(0, 1), (1, 0)]);
+
+    // Act
+    let jps = jump_point_search_grid(&grid, (0, 0), (1, 1)).unwrap();
+    let jps_plus = JumpPointTable::new(&grid).search((0, 0), (1, 1)).unwrap();
+
+    // Assert
+    assert!(!jps.is_found());
+    assert!(!jps_plus.is_found());
+}
+
+#[test]
+fn test_jump_point_search_start_is_goal() {
+    let grid = generate_test_grid((3, 3), vec![]);
+
+    let outcome = jump_point_search_grid(&grid, (1, 1), (1, 1)).unwrap();
+
+    assert_eq!(outcome.path(), Some(&[(1, 1)][..]));
+    assert_eq!(outcome.cost(), Some(&0));
+}
+
+#[test]
+fn test_expand_jump_points() {
+    let jump_points = [(0, 0), (3, 3), (3, 6), (1, 6)];
+
+    let path = expand_jump_points(&jump_points);
+
+    assert_eq!(
+        path,
+        vec![
+            (0, 0),
+            (1, 1),
+            (2, 2),
+            (3, 3),
+            (3, 4),
+            (3, 5),
+            (3, 6),
+            (2, 6),
+            (1, 6)
+        ]
+    );
+    assert!(expand_jump_points(&[]).is_empty());
+}
+
+#[test]
+fn test_jump_point_search_respects_budget() {
+    let grid = generate_random_test_grid((30, 30), 20, 7);
+    let mut budget = SearchBudget::unlimited().with_max_expansions(2);
+
+    let outcome = jump_point_search_grid_observed(&grid, (0, 0), (29, 29), &mut budget).unwrap();
+
+    assert!(outcome.is_budget_exhausted());
+    assert_eq!(outcome.visited().len(), 2);
+    assert_eq!(outcome.best_path().unwrap().first(), Some(&(0, 0)));
+}
+
+#[test]
+fn test_jump_point_search_rejects_invalid_start() {
+    let grid = generate_test_grid((3, 3), vec![]);
+
+    assert_eq!(
+        jump_point_search_grid(&grid, (3, 0), (0, 0)),
+        Err(SearchError::StartOutOfBounds { row: 3, col: 0 })
+    );
+    assert_eq!(
+        JumpPointTable::new(&grid).search((0, 5), (0, 0)),
+        Err(SearchError::StartOutOfBounds { row: 0, col: 5 })
+    );
+    assert_eq!(
+        JumpPointTable::new(&[]).search((0, 0), (0, 0)),
+        Err(SearchError::EmptyGrid)
+    );
+}
+
+#[test]
+fn test_jump_point_search_grid_ragged_rows() {
+    // Arrange: the middle row holds a single cell, so the path has to go round.
+    let mut grid = generate_test_grid((3, 3), vec![]);
+    grid[1].truncate(1);
+    let movement = Movement::eight_way();
+
+    // Act
+    let jps = jump_point_search_grid(&grid, (0, 2), (2, 2)).unwrap();
+    let jps_plus = JumpPointTable::new(&grid).search((0, 2), (2, 2)).unwrap();
+
+    // Assert
+    let a_star = a_star_grid_with(&grid, (0, 2), (2, 2), movement, DistanceHeuristic::Octile);
+    assert!(jps.is_found());
+    assert_eq!(jps.cost(), a_star.unwrap().cost());
+    assert_eq!(jps_plus.cost(), jps.cost());
+}
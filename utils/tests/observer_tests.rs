@@ -0,0 +1,157 @@
+mod pathfinding;
+mod search;
+use pathfinding::grid_generator::generate_test_grid;
+use search::grid_generator::generate_balanced_grid;
+use utils::pathfinding::a_star_grid::{a_star_grid, a_star_grid_observed};
+use utils::pathfinding::bidirectional_search_grid::bidirectional_search_grid_observed;
+use utils::search::bfs_grid::bfs_grid_observed;
+use utils::search::dfs_grid::dfs_grid_observed;
+use utils::search::observer::{SearchEvent, SearchObserver, SearchTrace};
+
+/// Counts events instead of recording them.
+#[derive(Default)]
+struct EventCounter {
+    pushed: usize,
+    expanded: usize,
+    closed: usize,
+    paths: usize,
+}
+
+impl<S> SearchObserver<S> for EventCounter {
+    fn on_push(&mut self, _state: &S) {
+        self.pushed += 1;
+    }
+
+    fn on_expand(&mut self, _state: &S) {
+        self.expanded += 1;
+    }
+
+    fn on_close(&mut self, _state: &S) {
+        self.closed += 1;
+    }
+
+    fn on_path_found(&mut self, _path: &[S]) {
+        self.paths += 1;
+    }
+}
+
+#[test]
+fn test_bfs_grid_expansion_order() {
+    // Arrange
+    let (grid, start) = generate_balanced_grid(3, 3);
+    let mut trace = SearchTrace::new();
+
+    // Act
+    let outcome =
+        bfs_grid_observed(&grid, start, |_| true, |&cell| cell == 'C', &mut trace).unwrap();
+
+    // Assert: cells are expanded layer by layer, Down before Right.
+    assert_eq!(
+        trace.expanded(),
+        vec![&(0, 0), &(1, 0), &(0, 1), &(2, 0), &(1, 1), &(0, 2)]
+    );
+    assert_eq!(trace.path(), outcome.path());
+}
+
+#[test]
+fn test_dfs_grid_trace_starts_with_push_and_expand() {
+    // Arrange
+    let (grid, start) = generate_balanced_grid(2, 2);
+    let mut trace = SearchTrace::new();
+
+    // Act
+    let outcome =
+        dfs_grid_observed(&grid, start, |_| true, |&cell| cell == 'D', &mut trace).unwrap();
+
+    // Assert
+    assert_eq!(
+        &trace.events()[..2],
+        &[SearchEvent::Pushed((0, 0)), SearchEvent::Expanded((0, 0))]
+    );
+    assert_eq!(
+        trace.events().last(),
+        Some(&SearchEvent::PathFound(outcome.into_path().unwrap()))
+    );
+}
+
+#[test]
+fn test_trace_without_path_has_no_path_found_event() {
+    // Arrange
+    let (grid, start) = generate_balanced_grid(3, 3);
+    let mut trace = SearchTrace::new();
+
+    // Act
+    let outcome =
+        bfs_grid_observed(&grid, start, |_| true, |&cell| cell == 'Z', &mut trace).unwrap();
+
+    // Assert: every cell is pushed, expanded and closed exactly once.
+    assert!(!outcome.is_found());
+    assert_eq!(trace.path(), None);
+    assert_eq!(trace.expanded().len(), 9);
+    let closed = trace
+        .events()
+        .iter()
+        .filter(|event| matches!(event, SearchEvent::Closed(_)))
+        .count();
+    assert_eq!(closed, 9);
+}
+
+#[test]
+fn test_observed_a_star_grid_matches_unobserved() {
+    // Arrange
+    let grid = generate_test_grid((5, 5), vec![(1, 1), (1, 2), (1, 3), (3, 1), (3, 2), (3, 3)]);
+    let (start, goal) = ((0, 0), (4, 4));
+    let mut trace = SearchTrace::new();
+
+    // Act
+    let observed = a_star_grid_observed(&grid, start, goal, &mut trace);
+    let plain = a_star_grid(&grid, start, goal).unwrap();
+
+    // Assert
+    assert_eq!(observed, Ok(plain.clone()));
+    assert_eq!(trace.path(), plain.path());
+    assert_eq!(trace.expanded(), plain.visited().iter().collect::<Vec<_>>());
+    assert_eq!(trace.expanded().first(), Some(&&start));
+    assert_eq!(trace.expanded().last(), Some(&&goal));
+}
+
+#[test]
+fn test_trace_replay_reproduces_events() {
+    // Arrange
+    let grid = generate_test_grid((5, 5), vec![(1, 1), (1, 2), (1, 3), (3, 1), (3, 2), (3, 3)]);
+    let (start, goal) = ((0, 0), (4, 4));
+    let mut trace = SearchTrace::new();
+    a_star_grid_observed(&grid, start, goal, &mut trace).unwrap();
+
+    // Act
+    let mut replayed = SearchTrace::new();
+    trace.replay(&mut replayed);
+    let mut counter = EventCounter::default();
+    trace.replay(&mut counter);
+
+    // Assert
+    assert_eq!(replayed, trace);
+    assert_eq!(counter.expanded, trace.expanded().len());
+    assert_eq!(counter.paths, 1);
+    assert_eq!(
+        counter.pushed + counter.expanded + counter.closed + counter.paths,
+        trace.events().len()
+    );
+}
+
+#[test]
+fn test_bidirectional_search_grid_reports_path() {
+    // Arrange
+    let grid = generate_test_grid((5, 5), vec![(1, 1), (1, 2), (1, 3), (3, 1), (3, 2), (3, 3)]);
+    let (start, goal) = ((0, 0), (4, 4));
+    let mut counter = EventCounter::default();
+
+    // Act
+    let outcome = bidirectional_search_grid_observed(&grid, start, goal, &mut counter).unwrap();
+
+    // Assert
+    assert!(outcome.is_found());
+    assert_eq!(counter.paths, 1);
+    assert_eq!(counter.expanded, outcome.visited().len());
+    assert!(counter.pushed >= counter.expanded);
+}
@@ -0,0 +1,99 @@
+mod search;
+use search::grid_generator::generate_maze_grid;
+use utils::search::bfs_grid::bfs_grid_where;
+use utils::search::outcome::{SearchError, SearchOutcome};
+
+fn exit_distance(grid: &[Vec<char>], start: (usize, usize)) -> Result<Option<usize>, SearchError> {
+    let outcome = bfs_grid_where(grid, start, |&cell| cell != '#', |&cell| cell == 'E')?;
+    Ok(outcome.cost().copied())
+}
+
+#[test]
+fn test_outcome_found_accessors() {
+    // Arrange
+    let outcome: SearchOutcome<char> = SearchOutcome::Found {
+        goal: 'C',
+        path: vec!['A', 'C'],
+        cost: 1,
+        visited: vec!['A', 'B', 'C'],
+    };
+
+    // Act & Assert
+    assert!(outcome.is_found());
+    assert_eq!(outcome.goal(), Some(&'C'));
+    assert_eq!(outcome.path(), Some(&['A', 'C'][..]));
+    assert_eq!(outcome.cost(), Some(&1));
+    assert_eq!(outcome.depth(), Some(1));
+    assert_eq!(outcome.visited(), ['A', 'B', 'C']);
+    assert_eq!(outcome.into_path(), Some(vec!['A', 'C']));
+}
+
+#[test]
+fn test_outcome_exhausted_accessors() {
+    // Arrange
+    let outcome: SearchOutcome<char> = SearchOutcome::Exhausted {
+        visited: vec!['A', 'B'],
+    };
+
+    // Act & Assert
+    assert!(!outcome.is_found());
+    assert_eq!(outcome.goal(), None);
+    assert_eq!(outcome.path(), None);
+    assert_eq!(outcome.depth(), None);
+    assert_eq!(outcome.cost(), None);
+    assert_eq!(outcome.visited(), ['A', 'B']);
+    assert_eq!(outcome.into_path(), None);
+}
+
+#[test]
+fn test_outcome_budget_exhausted_accessors() {
+    // Arrange
+    let outcome: SearchOutcome<char> = SearchOutcome::BudgetExhausted {
+        best_path: vec!['A', 'B'],
+        visited: vec!['A', 'B'],
+    };
+
+    // Act & Assert
+    assert!(!outcome.is_found());
+    assert!(outcome.is_budget_exhausted());
+    assert_eq!(outcome.path(), None);
+    assert_eq!(outcome.best_path(), Some(&['A', 'B'][..]));
+    assert_eq!(outcome.visited(), ['A', 'B']);
+    assert_eq!(outcome.into_path(), None);
+}
+
+#[test]
+fn test_search_error_propagates_with_question_mark() {
+    let (open, start) = generate_maze_grid(&["S.E"]);
+    let (walled, _) = generate_maze_grid(&["S#E"]);
+
+    assert_eq!(exit_distance(&open, start), Ok(Some(2)));
+    assert_eq!(exit_distance(&walled, start), Ok(None));
+    assert_eq!(exit_distance(&[], start), Err(SearchError::EmptyGrid));
+}
+
+#[test]
+fn test_search_error_for_start_past_end_of_short_row() {
+    // Arrange: the second row is shorter than the first.
+    let (ragged, _) = generate_maze_grid(&["S.E", "."]);
+
+    // Act & Assert
+    assert_eq!(exit_distance(&ragged, (1, 0)), Ok(Some(3)));
+    assert_eq!(
+        exit_distance(&ragged, (1, 2)),
+        Err(SearchError::StartOutOfBounds { row: 1, col: 2 })
+    );
+}
+
+#[test]
+fn test_search_error_display() {
+    assert_eq!(SearchError::EmptyGrid.to_string(), "the grid is empty");
+    assert_eq!(
+        SearchError::StartOutOfBounds { row: 2, col: 5 }.to_string(),
+        "start position (2, 5) is outside the grid"
+    );
+    assert_eq!(
+        SearchError::InvalidNode.to_string(),
+        "the node is not in the graph"
+    );
+}
@@ -0,0 +1,24 @@
+use super::grid_generator::next_random;
+use petgraph::graph::{Graph, NodeIndex};
+
+#[cfg(test)]
+#[allow(dead_code)]
+pub fn generate_random_weighted_graph(
+    node_count: usize,
+    edge_count: usize,
+    max_weight: usize,
+    seed: u64,
+) -> (Graph<usize, usize>, Vec<NodeIndex>) {
+    let mut seed = seed;
+    let mut graph = Graph::<usize, usize>::new();
+    let nodes: Vec<NodeIndex> = (0..node_count).map(|i| graph.add_node(i)).collect();
+
+    for _ in 0..edge_count {
+        let source = nodes[next_random(&mut seed) as usize % node_count];
+        let target = nodes[next_random(&mut seed) as usize % node_count];
+        let weight = 1 + next_random(&mut seed) as usize % max_weight;
+        graph.add_edge(source, target, weight);
+    }
+
+    (graph, nodes)
+}
@@ -22,4 +22,53 @@ pub fn generate_weighted_test_grid(
         }
     }
     grid
-}
\ No newline at end of file
+}
+
+/// Small linear congruential generator so randomized grids are reproducible.
+#[cfg(test)]
+#[allow(dead_code)]
+pub fn next_random(seed: &mut u64) -> u64 {
+    *seed = seed
+        .wrapping_mul(6364136223846793005)
+        .wrapping_add(1442695040888963407);
+    *seed >> 33
+}
+
+#[cfg(test)]
+#[allow(dead_code)]
+pub fn generate_random_test_grid(
+    size: (usize, usize),
+    wall_percent: u64,
+    seed: u64,
+) -> Vec<Vec<bool>> {
+    let mut seed = seed;
+    let mut grid = vec![vec![false; size.1]; size.0];
+    for row in grid.iter_mut() {
+        for cell in row.iter_mut() {
+            *cell = next_random(&mut seed) % 100 < wall_percent; // Place obstacles
+        }
+    }
+    grid
+}
+
+#[cfg(test)]
+#[allow(dead_code)]
+pub fn generate_random_weighted_test_grid(
+    size: (usize, usize),
+    max_weight: usize,
+    wall_percent: u64,
+    seed: u64,
+) -> Vec<Vec<usize>> {
+    let mut seed = seed;
+    let mut grid = vec![vec![1; size.1]; size.0];
+    for row in grid.iter_mut() {
+        for cell in row.iter_mut() {
+            *cell = if next_random(&mut seed) % 100 < wall_percent {
+                usize::MAX // Place obstacles with "infinite" weight
+            } else {
+                1 + next_random(&mut seed) as usize % max_weight
+            };
+        }
+    }
+    grid
+}
@@ -1 +1,2 @@
-pub mod grid_generator;
\ No newline at end of file
+pub mod graph_generator;
+pub mod grid_generator;
@@ -1,4 +1,5 @@
 use petgraph::graph::{Graph, NodeIndex};
+use std::ops::Index;
 
 #[cfg(test)]
 #[allow(dead_code)]
@@ -77,3 +78,12 @@ pub fn generate_unbalanced_graph(size: usize) -> (Graph<char, ()>, NodeIndex) {
 
     (graph, nodes[0]) // Return the graph and the first node
 }
+
+#[cfg(test)]
+#[allow(dead_code)]
+pub fn labels<G>(graph: &G, nodes: &[NodeIndex]) -> Vec<char>
+where
+    G: Index<NodeIndex, Output = char>,
+{
+    nodes.iter().map(|&node| graph[node]).collect()
+}
@@ -18,3 +18,17 @@ pub fn generate_balanced_grid(rows: usize, cols: usize) -> (Vec<Vec<char>>, (usi
 
     (grid, (0, 0)) // Return the grid and the starting point
 }
+
+/// Parses a character maze where `'#'` is a wall and `'S'` marks the start.
+#[cfg(test)]
+#[allow(dead_code)]
+pub fn generate_maze_grid(rows: &[&str]) -> (Vec<Vec<char>>, (usize, usize)) {
+    let grid: Vec<Vec<char>> = rows.iter().map(|row| row.chars().collect()).collect();
+    let start = grid
+        .iter()
+        .enumerate()
+        .find_map(|(row, line)| line.iter().position(|&c| c == 'S').map(|col| (row, col)))
+        .expect("Maze needs a start");
+
+    (grid, start)
+}
@@ -1,2 +1,3 @@
 pub mod graph_generator;
-pub mod grid_generator;
\ No newline at end of file
+pub mod grid_generator;
+pub mod problem_generator;
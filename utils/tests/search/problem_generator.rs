@@ -0,0 +1,141 @@
+use utils::search::search_problem::SearchProblem;
+
+/// The 3x3 sliding tile puzzle. `0` is the blank and the goal is `1..=8` in
+/// reading order with the blank last.
+#[cfg(test)]
+#[allow(dead_code)]
+pub struct SlidingPuzzle {
+    pub tiles: [u8; 9],
+}
+
+#[cfg(test)]
+#[allow(dead_code)]
+pub const SOLVED_PUZZLE: [u8; 9] = [1, 2, 3, 4, 5, 6, 7, 8, 0];
+
+#[cfg(test)]
+#[allow(dead_code)]
+impl SlidingPuzzle {
+    /// Scrambles the solved puzzle by sliding the blank through `moves`
+    /// (`'U'`, `'D'`, `'L'`, `'R'`), so the result is always solvable.
+    pub fn scrambled(moves: &str) -> Self {
+        let mut tiles = SOLVED_PUZZLE;
+        for direction in moves.chars() {
+            let blank = tiles.iter().position(|&tile| tile == 0).unwrap();
+            let target = match direction {
+                'U' => blank - 3,
+                'D' => blank + 3,
+                'L' => blank - 1,
+                'R' => blank + 1,
+                _ => panic!("Unknown move {direction}"),
+            };
+            tiles.swap(blank, target);
+        }
+        SlidingPuzzle { tiles }
+    }
+}
+
+impl SearchProblem for SlidingPuzzle {
+    type State = [u8; 9];
+    type Cost = usize;
+
+    fn initial_state(&self) -> [u8; 9] {
+        self.tiles
+    }
+
+    fn successors(&self, state: &[u8; 9]) -> Vec<([u8; 9], usize)> {
+        let blank = state.iter().position(|&tile| tile == 0).unwrap();
+        let (row, col) = (blank / 3, blank % 3);
+        let mut successors = Vec::new();
+
+        let moves = [
+            (row > 0, blank.wrapping_sub(3)), // Up
+            (row < 2, blank + 3),             // Down
+            (col > 0, blank.wrapping_sub(1)), // Left
+            (col < 2, blank + 1),             // Right
+        ];
+
+        for (allowed, target) in moves {
+            if allowed {
+                let mut next = *state;
+                next.swap(blank, target);
+                successors.push((next, 1));
+            }
+        }
+
+        successors
+    }
+
+    fn is_goal(&self, state: &[u8; 9]) -> bool {
+        *state == SOLVED_PUZZLE
+    }
+
+    /// Sum of Manhattan distances of every tile from its goal position.
+    fn heuristic(&self, state: &[u8; 9]) -> usize {
+        state
+            .iter()
+            .enumerate()
+            .filter(|(_, &tile)| tile != 0)
+            .map(|(index, &tile)| {
+                let goal = tile as usize - 1;
+                (index / 3).abs_diff(goal / 3) + (index % 3).abs_diff(goal % 3)
+            })
+            .sum()
+    }
+}
+
+/// A maze where `'#'` is a wall, `'D'` is a door that only opens once the key
+/// `'K'` has been picked up, and `'E'` is the exit. The state is the current
+/// cell plus whether the key is held, so the same cell can be visited twice.
+#[cfg(test)]
+#[allow(dead_code)]
+pub struct KeyDoorMaze {
+    pub cells: Vec<Vec<char>>,
+    pub start: (usize, usize),
+}
+
+#[cfg(test)]
+#[allow(dead_code)]
+impl KeyDoorMaze {
+    pub fn parse(rows: &[&str]) -> Self {
+        let cells: Vec<Vec<char>> = rows.iter().map(|row| row.chars().collect()).collect();
+        let start = cells
+            .iter()
+            .enumerate()
+            .find_map(|(row, line)| line.iter().position(|&c| c == 'S').map(|col| (row, col)))
+            .expect("Maze needs a start");
+        KeyDoorMaze { cells, start }
+    }
+}
+
+impl SearchProblem for KeyDoorMaze {
+    type State = ((usize, usize), bool);
+    type Cost = usize;
+
+    fn initial_state(&self) -> ((usize, usize), bool) {
+        (self.start, false)
+    }
+
+    fn successors(&self, &((row, col), has_key): &Self::State) -> Vec<(Self::State, usize)> {
+        let neighbors = [
+            (row.wrapping_sub(1), col),
+            (row + 1, col),
+            (row, col.wrapping_sub(1)),
+            (row, col + 1),
+        ];
+
+        neighbors
+            .into_iter()
+            .filter(|&(r, c)| r < self.cells.len() && c < self.cells[r].len())
+            .filter_map(|(r, c)| match self.cells[r][c] {
+                '#' => None,
+                'D' if !has_key => None,
+                'K' => Some((((r, c), true), 1)),
+                _ => Some((((r, c), has_key), 1)),
+            })
+            .collect()
+    }
+
+    fn is_goal(&self, &((row, col), _): &Self::State) -> bool {
+        self.cells[row][col] == 'E'
+    }
+}
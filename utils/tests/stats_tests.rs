@@ -0,0 +1,149 @@
+mod pathfinding;
+mod search;
+use pathfinding::graph_generator::generate_random_weighted_graph;
+use pathfinding::grid_generator::{generate_test_grid, generate_weighted_test_grid};
+use petgraph::graph::NodeIndex;
+use search::grid_generator::{generate_balanced_grid, generate_maze_grid};
+use utils::pathfinding::a_star_grid::a_star_grid_observed;
+use utils::pathfinding::uniform_cost_search_graph::uniform_cost_search_graph_observed;
+use utils::pathfinding::uniform_cost_search_grid::uniform_cost_search_grid_observed;
+use utils::search::bfs_grid::bfs_grid_observed;
+use utils::search::iterative_deepening_dfs_grid::iterative_deepening_dfs_grid_observed;
+use utils::search::observer::{SearchEvent, SearchTrace};
+use utils::search::stats::StatsObserver;
+
+#[test]
+fn test_stats_compare_a_star_uniform_cost_and_bfs() {
+    // Arrange: the same open 8x8 level in each search's grid format.
+    let walls = generate_test_grid((8, 8), vec![]);
+    let weights = generate_weighted_test_grid((8, 8), 1, vec![]);
+    let (maze, start) = generate_maze_grid(&[
+        "S.......", "........", "........", "........", "........", "........", "........",
+        ".......E",
+    ]);
+    let goal = (7, 7);
+
+    // Act
+    let mut a_star = StatsObserver::new();
+    let outcome = a_star_grid_observed(&walls, start, goal, &mut a_star).unwrap();
+    let a_star = a_star.finish(outcome.cost().copied());
+
+    let mut ucs = StatsObserver::new();
+    let outcome = uniform_cost_search_grid_observed(&weights, start, goal, &mut ucs).unwrap();
+    let ucs = ucs.finish(outcome.cost().copied());
+
+    let mut bfs = StatsObserver::new();
+    let outcome = bfs_grid_observed(
+        &maze,
+        start,
+        |&cell| cell != '#',
+        |&cell| cell == 'E',
+        &mut bfs,
+    )
+    .unwrap();
+    let bfs = bfs.finish(outcome.cost().copied());
+
+    // Assert
+    for stats in [&a_star, &ucs, &bfs] {
+        assert_eq!(stats.path_cost, Some(14));
+        assert_eq!(stats.reopened, 0);
+        assert!(stats.expanded <= stats.generated);
+        assert!(stats.max_frontier >= 1);
+    }
+    assert!(
+        a_star.expanded < ucs.expanded,
+        "The heuristic should save expansions on an open grid."
+    );
+    assert_eq!(bfs.expanded, 64);
+}
+
+#[test]
+fn test_stats_counts_match_trace() {
+    // Arrange
+    let (graph, nodes) = generate_random_weighted_graph(40, 160, 9, 1);
+    let mut trace = SearchTrace::new();
+    uniform_cost_search_graph_observed(&graph, nodes[0], nodes[39], &mut trace).unwrap();
+
+    // Act
+    let mut stats = StatsObserver::new();
+    trace.replay(&mut stats);
+    let stats = stats.finish::<usize>(None);
+
+    // Assert
+    let count = |wanted: fn(&SearchEvent<NodeIndex>) -> bool| {
+        trace.events().iter().filter(|event| wanted(event)).count()
+    };
+    assert_eq!(
+        stats.generated,
+        count(|e| matches!(e, SearchEvent::Pushed(_)))
+    );
+    assert_eq!(
+        stats.expanded,
+        count(|e| matches!(e, SearchEvent::Expanded(_)))
+    );
+    assert!(
+        count(|e| matches!(e, SearchEvent::Discarded(_))) > 0,
+        "Varied weights should leave stale entries behind."
+    );
+}
+
+#[test]
+fn test_stats_frontier_drains_when_exhausted() {
+    // Arrange: the goal is isolated, so every entry is eventually popped.
+    let (mut graph, nodes) = generate_random_weighted_graph(40, 160, 9, 2);
+    let goal = graph.add_node(40);
+    let mut trace = SearchTrace::new();
+
+    // Act
+    let outcome = uniform_cost_search_graph_observed(&graph, nodes[0], goal, &mut trace).unwrap();
+
+    // Assert: every push ends in an expansion or a discard.
+    assert!(!outcome.is_found());
+    let (mut pushed, mut popped) = (0, 0);
+    for event in trace.events() {
+        match event {
+            SearchEvent::Pushed(_) => pushed += 1,
+            SearchEvent::Expanded(_) | SearchEvent::Discarded(_) => popped += 1,
+            _ => {}
+        }
+    }
+    assert_eq!(pushed, popped);
+}
+
+#[test]
+fn test_stats_iterative_deepening_counts_reopenings() {
+    // Arrange
+    let (grid, start) = generate_balanced_grid(3, 3);
+    let mut stats = StatsObserver::new();
+
+    // Act
+    let outcome = iterative_deepening_dfs_grid_observed(
+        &grid,
+        start,
+        |_| true,
+        |&cell| cell == 'I',
+        10,
+        &mut stats,
+    )
+    .unwrap();
+    let stats = stats.finish(outcome.cost().copied());
+
+    // Assert
+    assert_eq!(stats.path_cost, Some(4));
+    assert!(stats.expanded > outcome.visited().len());
+    assert!(stats.reopened > 0);
+    assert_eq!(stats.expanded - stats.reopened, 9);
+}
+
+#[test]
+fn test_stats_without_path_has_no_cost() {
+    let grid = generate_test_grid((3, 3), vec![(0, 1), (1, 0)]);
+    let mut stats = StatsObserver::new();
+
+    let outcome = a_star_grid_observed(&grid, (0, 0), (2, 2), &mut stats).unwrap();
+    let stats = stats.finish(outcome.cost().copied());
+
+    assert_eq!(stats.path_cost, None);
+    assert_eq!(stats.expanded, 1);
+    assert_eq!(stats.generated, 1);
+}
@@ -1,5 +1,8 @@
-use petgraph::graph::{Graph};
-use utils::pathfinding::uniform_cost_search_graph::uniform_cost_search_graph;
+use petgraph::graph::{Graph, UnGraph};
+use petgraph::stable_graph::StableGraph;
+use utils::pathfinding::uniform_cost_search_graph::{
+    uniform_cost_search_graph, uniform_cost_search_graph_where,
+};
 
 // TODO: Update these tests to match the A* tests.
 #[test]
@@ -15,8 +18,9 @@ fn test_uniform_cost_search_graph_path_found() {
     graph.add_edge(a, c, 5);
     graph.add_edge(c, d, 1);
 
-    let result = uniform_cost_search_graph(&graph, a, d);
-    assert_eq!(result, Some((vec![a, b, c, d], 4)));
+    let result = uniform_cost_search_graph(&graph, a, d).unwrap();
+    assert_eq!(result.path(), Some(&[a, b, c, d][..]));
+    assert_eq!(result.cost(), Some(&4));
 }
 
 #[test]
@@ -28,6 +32,103 @@ fn test_uniform_cost_search_graph_no_path() {
 
     graph.add_edge(a, b, 1);
 
-    let result = uniform_cost_search_graph(&graph, a, c);
-    assert_eq!(result, None);
+    let result = uniform_cost_search_graph(&graph, a, c).unwrap();
+    assert!(!result.is_found());
+}
+
+#[test]
+fn test_uniform_cost_search_graph_float_weights() {
+    // Arrange: a road network with fractional distances.
+    let mut graph = Graph::<&str, f64>::new();
+    let a = graph.add_node("A");
+    let b = graph.add_node("B");
+    let c = graph.add_node("C");
+    let d = graph.add_node("D");
+
+    graph.add_edge(a, b, 0.4);
+    graph.add_edge(b, d, 0.4);
+    graph.add_edge(a, c, 0.3);
+    graph.add_edge(c, d, 0.6);
+
+    // Act
+    let result = uniform_cost_search_graph(&graph, a, d).unwrap();
+
+    // Assert
+    assert_eq!(result.path(), Some(&[a, b, d][..]));
+    assert!((result.cost().unwrap() - 0.8).abs() < 1e-9);
+}
+
+#[test]
+fn test_uniform_cost_search_graph_does_not_wrap() {
+    // Arrange: with wrapping addition A -> B -> C would cost 144 and look cheapest.
+    let mut graph = Graph::<&str, u8>::new();
+    let a = graph.add_node("A");
+    let b = graph.add_node("B");
+    let c = graph.add_node("C");
+
+    graph.add_edge(a, b, 200);
+    graph.add_edge(b, c, 200);
+    graph.add_edge(a, c, 250);
+
+    // Act
+    let result = uniform_cost_search_graph(&graph, a, c).unwrap();
+
+    // Assert
+    assert_eq!(result.path(), Some(&[a, c][..]));
+    assert_eq!(result.cost(), Some(&250));
+}
+
+#[test]
+fn test_uniform_cost_search_graph_stable_graph() {
+    // Arrange: removing 'B' must not invalidate the other node indices.
+    let mut graph = StableGraph::<&str, usize>::new();
+    let a = graph.add_node("A");
+    let b = graph.add_node("B");
+    let c = graph.add_node("C");
+    graph.add_edge(a, b, 1);
+    graph.add_edge(b, c, 1);
+    graph.add_edge(a, c, 5);
+    graph.remove_node(b);
+
+    // Act
+    let result = uniform_cost_search_graph(&graph, a, c).unwrap();
+
+    // Assert
+    assert_eq!(result.path(), Some(&[a, c][..]));
+    assert_eq!(result.cost(), Some(&5));
+}
+
+#[test]
+fn test_uniform_cost_search_graph_undirected() {
+    // Arrange: every edge points away from 'D', so only an undirected search reaches 'A'.
+    let graph = UnGraph::<(), usize>::from_edges([(0, 1, 2), (1, 3, 2), (0, 2, 1), (2, 3, 4)]);
+
+    // Act
+    let result = uniform_cost_search_graph(&graph, 3.into(), 0.into()).unwrap();
+
+    // Assert
+    assert_eq!(result.path(), Some(&[3.into(), 1.into(), 0.into()][..]));
+    assert_eq!(result.cost(), Some(&4));
+}
+
+#[test]
+fn test_uniform_cost_search_graph_where_reaches_cheapest_match() {
+    // Arrange: two "exit" nodes, the one fewer edges away costs more.
+    let mut graph = Graph::new();
+    let a = graph.add_node("A");
+    let b = graph.add_node("B");
+    let c = graph.add_node("C");
+    let far_exit = graph.add_node("exit");
+    let near_exit = graph.add_node("exit");
+    graph.add_edge(a, far_exit, 10);
+    graph.add_edge(a, b, 2);
+    graph.add_edge(b, c, 2);
+    graph.add_edge(c, near_exit, 2);
+
+    // Act
+    let result = uniform_cost_search_graph_where(&graph, a, |node| graph[node] == "exit").unwrap();
+
+    // Assert
+    assert_eq!(result.goal(), Some(&near_exit));
+    assert_eq!(result.cost(), Some(&6));
 }
@@ -1,6 +1,9 @@
 mod pathfinding;
 use pathfinding::grid_generator::generate_weighted_test_grid;
-use utils::pathfinding::uniform_cost_search_grid::uniform_cost_search_grid;
+use utils::pathfinding::uniform_cost_search_grid::{
+    uniform_cost_search_grid, uniform_cost_search_grid_where,
+};
+use utils::search::outcome::SearchError;
 
 #[test]
 fn test_uniform_cost_search_grid_no_obstacles() {
@@ -10,10 +13,10 @@ fn test_uniform_cost_search_grid_no_obstacles() {
     let goal = (4, 4);
 
     // Act
-    let path = uniform_cost_search_grid(&grid, start, goal);
+    let result = uniform_cost_search_grid(&grid, start, goal).unwrap();
 
     // Assert
-    assert!(!path.is_empty());
+    let path = result.path().unwrap();
     assert_eq!(path.first(), Some(&start));
     assert_eq!(path.last(), Some(&goal));
 }
@@ -31,10 +34,10 @@ fn test_uniform_cost_search_grid_with_cycles() {
     let goal = (4, 4);
 
     // Act
-    let path = uniform_cost_search_grid(&grid, start, goal);
+    let result = uniform_cost_search_grid(&grid, start, goal).unwrap();
 
     // Assert
-    assert!(!path.is_empty());
+    let path = result.path().unwrap();
     assert_eq!(path.first(), Some(&start));
     assert_eq!(path.last(), Some(&goal));
 }
@@ -49,10 +52,11 @@ fn test_uniform_cost_search_grid_no_solution() {
     let goal = (4, 4);
 
     // Act
-    let path = uniform_cost_search_grid(&grid, start, goal);
+    let result = uniform_cost_search_grid(&grid, start, goal).unwrap();
 
     // Assert
-    assert!(path.is_empty());
+    assert!(!result.is_found());
+    assert_eq!(result.visited().len(), 10);
 }
 
 #[test]
@@ -63,10 +67,54 @@ fn test_uniform_cost_search_grid_target_edge_case() {
     let goal = (0, 4); // Top-right corner.
 
     // Act
-    let path = uniform_cost_search_grid(&grid, start, goal);
+    let result = uniform_cost_search_grid(&grid, start, goal).unwrap();
 
     // Assert
-    assert!(!path.is_empty());
+    let path = result.path().unwrap();
     assert_eq!(path.first(), Some(&start));
     assert_eq!(path.last(), Some(&goal));
 }
+
+#[test]
+fn test_uniform_cost_search_grid_start_out_of_bounds() {
+    // Arrange
+    let grid = generate_weighted_test_grid((5, 5), 1, vec![]);
+
+    // Act
+    let result = uniform_cost_search_grid(&grid, (7, 0), (4, 4));
+
+    // Assert
+    assert_eq!(
+        result,
+        Err(SearchError::StartOutOfBounds { row: 7, col: 0 })
+    );
+}
+
+#[test]
+fn test_uniform_cost_search_grid_ragged_rows() {
+    // Arrange: the middle row holds a single cell, so the path has to go round.
+    let mut grid = generate_weighted_test_grid((3, 3), 1, vec![]);
+    grid[1].truncate(1);
+
+    // Act
+    let result = uniform_cost_search_grid(&grid, (0, 2), (2, 2)).unwrap();
+
+    // Assert
+    assert_eq!(result.cost(), Some(&6));
+}
+
+#[test]
+fn test_uniform_cost_search_grid_where_reaches_cheapest_match() {
+    // Arrange: the nearer exit is expensive to enter.
+    let mut grid = generate_weighted_test_grid((5, 5), 1, vec![]);
+    grid[0][2] = 20;
+    let exits = [(0, 2), (4, 4)];
+
+    // Act
+    let result =
+        uniform_cost_search_grid_where(&grid, (0, 0), |cell| exits.contains(&cell)).unwrap();
+
+    // Assert
+    assert_eq!(result.goal(), Some(&(4, 4)));
+    assert_eq!(result.cost(), Some(&8));
+}
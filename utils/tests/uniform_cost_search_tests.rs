@@ -0,0 +1,60 @@
+mod search;
+use search::problem_generator::{KeyDoorMaze, SlidingPuzzle, SOLVED_PUZZLE};
+use utils::pathfinding::uniform_cost_search::uniform_cost_search;
+use utils::search::search_problem::SearchProblem;
+
+/// Travelling along a number line where stepping right costs 1 and jumping
+/// to double the current value costs 3.
+struct NumberLine {
+    target: u32,
+}
+
+impl SearchProblem for NumberLine {
+    type State = u32;
+    type Cost = usize;
+
+    fn initial_state(&self) -> u32 {
+        1
+    }
+
+    fn successors(&self, state: &u32) -> Vec<(u32, usize)> {
+        vec![(state + 1, 1), (state * 2, 3)]
+            .into_iter()
+            .filter(|&(next, _)| next <= self.target)
+            .collect()
+    }
+
+    fn is_goal(&self, state: &u32) -> bool {
+        *state == self.target
+    }
+}
+
+#[test]
+fn test_uniform_cost_search_cheapest_path() {
+    // Arrange
+    let problem = NumberLine { target: 10 };
+
+    // Act
+    let result = uniform_cost_search(&problem);
+
+    // Assert: Walking to 5 and doubling (cost 7) beats doubling twice (cost 8).
+    assert_eq!(result.path(), Some(&[1, 2, 3, 4, 5, 10][..]));
+    assert_eq!(result.cost(), Some(&7));
+}
+
+#[test]
+fn test_uniform_cost_search_sliding_puzzle() {
+    let puzzle = SlidingPuzzle::scrambled("ULDLUR");
+
+    let result = uniform_cost_search(&puzzle);
+
+    assert_eq!(result.goal(), Some(&SOLVED_PUZZLE));
+    assert_eq!(result.cost(), Some(&6));
+}
+
+#[test]
+fn test_uniform_cost_search_no_path() {
+    let maze = KeyDoorMaze::parse(&["S.#E", "..D.", ".###"]);
+
+    assert!(!uniform_cost_search(&maze).is_found());
+}
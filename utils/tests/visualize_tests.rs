@@ -0,0 +1,148 @@
+mod pathfinding;
+use pathfinding::grid_generator::generate_test_grid;
+use utils::pathfinding::a_star::SearchProgress;
+use utils::pathfinding::a_star_grid::{a_star_grid_observed, a_star_grid_search};
+use utils::search::observer::SearchTrace;
+use utils::visualize::{snapshots, write_gif, write_png, CellState, RenderOptions};
+
+fn traced_a_star(grid: &[Vec<bool>]) -> SearchTrace<(usize, usize)> {
+    let mut trace = SearchTrace::new();
+    a_star_grid_observed(grid, (0, 0), (2, 2), &mut trace).unwrap();
+    trace
+}
+
+#[test]
+fn test_snapshots_end_with_path() {
+    // Arrange
+    let grid = generate_test_grid((3, 3), vec![(1, 1)]);
+    let trace = traced_a_star(&grid);
+
+    // Act
+    let frames = snapshots(&grid, |&wall| wall, &trace, 1);
+
+    // Assert: one frame per expansion after the first, plus the final frame.
+    assert_eq!(frames.len(), trace.expanded().len());
+    let last = frames.last().unwrap();
+    assert_eq!(last[1][1], CellState::Wall);
+    for &(row, col) in trace.path().unwrap() {
+        assert_eq!(last[row][col], CellState::Path);
+    }
+}
+
+#[test]
+fn test_snapshots_show_frontier_after_first_expansion() {
+    // Arrange
+    let grid = generate_test_grid((3, 3), vec![(1, 1)]);
+    let trace = traced_a_star(&grid);
+
+    // Act
+    let frames = snapshots(&grid, |&wall| wall, &trace, 1);
+
+    // Assert
+    assert_eq!(frames[0][0][0], CellState::Closed);
+    assert_eq!(frames[0][1][0], CellState::Frontier);
+    assert_eq!(frames[0][0][1], CellState::Frontier);
+    assert_eq!(frames[0][2][2], CellState::Empty);
+}
+
+#[test]
+fn test_expansions_per_frame_reduces_frame_count() {
+    // Arrange
+    let grid = generate_test_grid((5, 5), vec![]);
+    let mut trace = SearchTrace::new();
+    a_star_grid_observed(&grid, (0, 0), (4, 4), &mut trace).unwrap();
+
+    // Act
+    let every = snapshots(&grid, |&wall| wall, &trace, 1);
+    let batched = snapshots(&grid, |&wall| wall, &trace, 3);
+
+    // Assert
+    assert_eq!(batched.len(), trace.expanded().len().div_ceil(3));
+    assert_eq!(every.last(), batched.last());
+}
+
+#[test]
+fn test_stepped_search_gives_one_frame_per_step() {
+    // Arrange
+    let grid = generate_test_grid((6, 6), vec![(2, 1), (2, 2), (2, 3), (2, 4)]);
+    let mut search = a_star_grid_search(&grid, (0, 0), (5, 5)).unwrap();
+    let mut trace = SearchTrace::new();
+
+    // Act
+    let mut steps = 1;
+    while search.step_observed(2, &mut trace) == SearchProgress::Running {
+        steps += 1;
+    }
+
+    // Assert
+    assert_eq!(snapshots(&grid, |&wall| wall, &trace, 2).len(), steps);
+    let mut one_shot = SearchTrace::new();
+    a_star_grid_observed(&grid, (0, 0), (5, 5), &mut one_shot).unwrap();
+    assert_eq!(trace, one_shot);
+}
+
+#[test]
+fn test_write_gif_has_one_frame_per_snapshot() {
+    // Arrange
+    let grid = generate_test_grid((3, 3), vec![(1, 1)]);
+    let trace = traced_a_star(&grid);
+    let options = RenderOptions {
+        cell_size: 4,
+        ..RenderOptions::default()
+    };
+    let mut bytes = Vec::new();
+
+    // Act
+    write_gif(&mut bytes, &grid, |&wall| wall, &trace, &options).unwrap();
+
+    // Assert
+    let mut decoder = gif::DecodeOptions::new()
+        .read_info(bytes.as_slice())
+        .unwrap();
+    assert_eq!((decoder.width(), decoder.height()), (12, 12));
+    let mut frames = 0;
+    while decoder.read_next_frame().unwrap().is_some() {
+        frames += 1;
+    }
+    assert_eq!(frames, snapshots(&grid, |&wall| wall, &trace, 1).len());
+}
+
+#[test]
+fn test_write_png_uses_palette_colors() {
+    // Arrange
+    let grid = generate_test_grid((3, 3), vec![(1, 1)]);
+    let trace = traced_a_star(&grid);
+    let options = RenderOptions {
+        cell_size: 2,
+        ..RenderOptions::default()
+    };
+    let mut bytes = Vec::new();
+
+    // Act
+    write_png(&mut bytes, &grid, |&wall| wall, &trace, &options).unwrap();
+
+    // Assert
+    let mut reader = png::Decoder::new(std::io::Cursor::new(bytes))
+        .read_info()
+        .unwrap();
+    let mut pixels = vec![0; reader.output_buffer_size().unwrap()];
+    let info = reader.next_frame(&mut pixels).unwrap();
+    assert_eq!((info.width, info.height), (6, 6));
+    let pixel = |x: usize, y: usize| &pixels[(y * 6 + x) * 3..][..3];
+    assert_eq!(pixel(2, 2), options.palette.wall);
+    assert_eq!(pixel(0, 0), options.palette.path);
+    assert_eq!(pixel(5, 5), options.palette.path);
+}
+
+#[test]
+fn test_empty_grid_is_rejected() {
+    let grid: Vec<Vec<bool>> = Vec::new();
+    let result = write_png(
+        Vec::new(),
+        &grid,
+        |&wall| wall,
+        &SearchTrace::new(),
+        &RenderOptions::default(),
+    );
+    assert!(result.is_err());
+}